@@ -1,6 +1,7 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 mod print_keypair_to_terminal;
+mod save_keypair_to_encrypted_keystore;
 mod save_keypair_to_keychain;
 mod save_keypair_to_legacy_keychain;
 
@@ -57,17 +58,24 @@ impl GenerateKeypair {
 /// Save an access key for this account:
 pub enum SaveMode {
     #[strum_discriminants(strum(
-        message = "save-to-keychain         - Save automatically generated key pair to keychain"
+        message = "save-to-keychain            - Save automatically generated key pair to keychain"
     ))]
     /// Save automatically generated key pair to keychain
     SaveToKeychain(self::save_keypair_to_keychain::SaveKeypairToKeychain),
     #[strum_discriminants(strum(
-        message = "save-to-legacy-keychain  - Save automatically generated key pair to the legacy keychain (compatible with JS CLI)"
+        message = "save-to-legacy-keychain     - Save automatically generated key pair to the legacy keychain (compatible with JS CLI)"
     ))]
     /// Save automatically generated key pair to the legacy keychain (compatible with JS CLI)
     SaveToLegacyKeychain(self::save_keypair_to_legacy_keychain::SaveKeypairToLegacyKeychain),
     #[strum_discriminants(strum(
-        message = "print-to-terminal        - Print automatically generated key pair in terminal"
+        message = "save-to-encrypted-keystore  - Save automatically generated key pair to the passphrase-encrypted keystore"
+    ))]
+    /// Save automatically generated key pair to the passphrase-encrypted keystore
+    SaveToEncryptedKeystore(
+        self::save_keypair_to_encrypted_keystore::SaveKeypairToEncryptedKeystore,
+    ),
+    #[strum_discriminants(strum(
+        message = "print-to-terminal           - Print automatically generated key pair in terminal"
     ))]
     /// Print automatically generated key pair in terminal
     PrintToTerminal(self::print_keypair_to_terminal::PrintKeypairToTerminal),
@@ -42,7 +42,7 @@ impl AddKeyCommand {
         context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.config.credentials_home_dir,
+            &context.config,
             "Which account do you want to add an access key to?",
         )
     }
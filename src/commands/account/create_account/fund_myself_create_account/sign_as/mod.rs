@@ -173,7 +173,7 @@ impl SignerAccountId {
             Ok(Some(parent_account_id))
         } else {
             crate::common::input_signer_account_id_from_used_account_list(
-                &context.global_context.config.credentials_home_dir,
+                &context.global_context.config,
                 "What is the signer account ID?",
             )
         }
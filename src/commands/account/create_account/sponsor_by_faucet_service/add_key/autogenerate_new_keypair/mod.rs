@@ -56,17 +56,22 @@ impl GenerateKeypair {
 /// Save an access key for this account:
 pub enum SaveMode {
     #[strum_discriminants(strum(
-        message = "save-to-keychain         - Save automatically generated key pair to keychain"
+        message = "save-to-keychain            - Save automatically generated key pair to keychain"
     ))]
     /// Save automatically generated key pair to keychain
     SaveToKeychain(SaveKeyPair),
     #[strum_discriminants(strum(
-        message = "save-to-legacy-keychain  - Save automatically generated key pair to the legacy keychain (compatible with JS CLI)"
+        message = "save-to-legacy-keychain     - Save automatically generated key pair to the legacy keychain (compatible with JS CLI)"
     ))]
     /// Save automatically generated key pair to the legacy keychain (compatible with JS CLI)
     SaveToLegacyKeychain(SaveKeyPair),
     #[strum_discriminants(strum(
-        message = "print-to-terminal        - Print automatically generated key pair in terminal"
+        message = "save-to-encrypted-keystore  - Save automatically generated key pair to the passphrase-encrypted keystore"
+    ))]
+    /// Save automatically generated key pair to the passphrase-encrypted keystore
+    SaveToEncryptedKeystore(SaveKeyPair),
+    #[strum_discriminants(strum(
+        message = "print-to-terminal           - Print automatically generated key pair in terminal"
     ))]
     /// Print automatically generated key pair in terminal
     PrintToTerminal(SaveKeyPair),
@@ -108,6 +113,15 @@ impl SaveModeContext {
                                 &new_account_id_str,
                             )
                         }
+                        SaveModeDiscriminants::SaveToEncryptedKeystore => {
+                            crate::common::save_access_key_to_encrypted_keystore(
+                                network_config.clone(),
+                                credentials_home_dir.clone(),
+                                &generated_key_pair.keychain_json()?,
+                                &generated_key_pair.keychain_key_id()?,
+                                &new_account_id_str,
+                            )
+                        }
                         SaveModeDiscriminants::PrintToTerminal => {
                             Ok(generated_key_pair.terminal_info())
                         }
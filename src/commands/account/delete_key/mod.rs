@@ -35,7 +35,7 @@ impl DeleteKeysCommand {
         context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.config.credentials_home_dir,
+            &context.config,
             "Which account should you delete the access key for?",
         )
     }
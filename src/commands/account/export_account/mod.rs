@@ -42,7 +42,7 @@ impl ExportAccount {
         context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.config.credentials_home_dir,
+            &context.config,
             "Which account ID should be exported?",
         )
     }
@@ -124,6 +124,56 @@ pub fn get_password_from_keychain(
     Ok(password)
 }
 
+#[tracing::instrument(
+    name = "Receiving the account key pair from the encrypted keystore ...",
+    skip_all
+)]
+/// Returns `None` if there are no full access keys of the account in the encrypted keystore.
+pub fn get_password_from_encrypted_keystore(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    credentials_home_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<Option<String>> {
+    tracing::info!(target: "near_teach_me", "Receiving the account key pair from the encrypted keystore ...");
+    let encrypted_access_keys = crate::common::get_access_keys_from_encrypted_keystore(
+        credentials_home_dir,
+        &network_config.network_name,
+        account_id,
+    );
+    if encrypted_access_keys.is_empty() {
+        return Ok(None);
+    }
+    let access_key_list = network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key_list(
+            account_id,
+            near_primitives::types::Finality::Final.into(),
+        )
+        .wrap_err_with(|| format!("Failed to fetch access key list for {account_id}"))?
+        .access_key_list_view()?;
+    let Some((_, encrypted_keystore_file)) = access_key_list
+        .keys
+        .into_iter()
+        .filter(|key| {
+            matches!(
+                key.access_key.permission,
+                near_primitives::views::AccessKeyPermissionView::FullAccess
+            )
+        })
+        .find_map(|key| {
+            encrypted_access_keys
+                .iter()
+                .find(|(_, encrypted_keystore_file)| {
+                    encrypted_keystore_file.public_key == key.public_key.to_string()
+                })
+        })
+    else {
+        return Ok(None);
+    };
+    let passphrase = crate::common::input_encrypted_keystore_passphrase(false)?;
+    encrypted_keystore_file.decrypt(&passphrase).map(Some)
+}
+
 pub fn get_account_key_pair_from_legacy_keychain(
     network_config: &crate::config::NetworkConfig,
     account_id: &near_primitives::types::AccountId,
@@ -33,6 +33,20 @@ impl ExportAccountFromPrivateKeyContext {
                         return Ok(());
                     }
 
+                    if let Some(password) = super::get_password_from_encrypted_keystore(
+                        network_config,
+                        &account_id,
+                        &config.credentials_home_dir,
+                    )? {
+                        let account_key_pair: crate::transaction_signature_options::AccountKeyPair =
+                            serde_json::from_str(&password).wrap_err("Error reading data")?;
+                        println!(
+                            "Here is the private key for account <{}>: {}",
+                            account_id, account_key_pair.private_key,
+                        );
+                        return Ok(());
+                    }
+
                     let account_key_pair = super::get_account_key_pair_from_legacy_keychain(
                         network_config,
                         &account_id,
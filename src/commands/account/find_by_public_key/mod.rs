@@ -0,0 +1,394 @@
+use color_eyre::eyre::eyre;
+use color_eyre::owo_colors::OwoColorize;
+use inquire::{CustomType, Select};
+use prettytable::Table;
+
+use crate::common::JsonRpcClientExt;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = FindByPublicKeyContext)]
+pub struct FindByPublicKey {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Enter the public key to look up:
+    public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct FindByPublicKeyContext(crate::network::NetworkContext);
+
+impl FindByPublicKeyContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<FindByPublicKey as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.offline {
+            return Err(eyre!(
+                "Cannot find the accounts in offline mode: the key has to be looked up on the network"
+            ));
+        }
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let public_key: near_crypto::PublicKey = scope.public_key.clone().into();
+                let output_format = previous_context.output_format;
+
+                move |network_config| {
+                    let candidate_account_ids =
+                        find_candidate_account_ids(network_config, &public_key)?;
+                    let accounts = candidate_account_ids
+                        .into_iter()
+                        .map(|account_id| {
+                            let access_key_status =
+                                get_access_key_status(network_config, &account_id, &public_key);
+                            CandidateAccount {
+                                account_id,
+                                access_key_status,
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    display_candidate_accounts(
+                        &public_key,
+                        &accounts,
+                        output_format,
+                        network_config,
+                    )
+                }
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            config: previous_context.config,
+            interacting_with_account_ids: Vec::new(),
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<FindByPublicKeyContext> for crate::network::NetworkContext {
+    fn from(item: FindByPublicKeyContext) -> Self {
+        item.0
+    }
+}
+
+impl FindByPublicKey {
+    fn input_public_key(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::public_key::PublicKey>> {
+        // Only the public keys are needed, so the keychain is not read (it may prompt for
+        // access) and the keys are not checked on-chain.
+        let mut local_keys = super::list_local_keys::find_saved_keys(&context.config);
+        // The same key may be saved in several key stores.
+        local_keys.dedup_by(|a, b| {
+            (&a.network_name, &a.account_id, &a.public_key)
+                == (&b.network_name, &b.account_id, &b.public_key)
+        });
+        if local_keys.is_empty() {
+            return Ok(Some(
+                CustomType::new("Enter the public key to look up:").prompt()?,
+            ));
+        }
+
+        enum PublicKeySource {
+            LocalKey(super::list_local_keys::LocalKey),
+            Manual,
+        }
+        impl std::fmt::Display for PublicKeySource {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    Self::LocalKey(local_key) => write!(
+                        f,
+                        "{} ({} on {}, {})",
+                        local_key.public_key,
+                        local_key.account_id,
+                        local_key.network_name,
+                        local_key.store
+                    ),
+                    Self::Manual => write!(f, "Enter a public key manually"),
+                }
+            }
+        }
+        let mut options = local_keys
+            .into_iter()
+            .map(PublicKeySource::LocalKey)
+            .collect::<Vec<_>>();
+        options.push(PublicKeySource::Manual);
+        match Select::new("Which public key do you want to look up?", options).prompt()? {
+            PublicKeySource::LocalKey(local_key) => Ok(Some(local_key.public_key.parse()?)),
+            PublicKeySource::Manual => Ok(Some(
+                CustomType::new("Enter the public key to look up:").prompt()?,
+            )),
+        }
+    }
+}
+
+/// The accounts that an indexer lists for the public key: FastNEAR, or NearBlocks if FastNEAR
+/// is not configured or fails.
+fn find_candidate_account_ids(
+    network_config: &crate::config::NetworkConfig,
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<std::collections::BTreeSet<near_primitives::types::AccountId>> {
+    if let Some(fastnear_url) = &network_config.fastnear_url {
+        match crate::common::fetch_account_ids_by_public_key(fastnear_url, public_key) {
+            Ok(account_ids) => return Ok(account_ids),
+            Err(err) if network_config.nearblocks_url.is_some() => {
+                tracing::warn!("Failed to look up the key on FastNEAR, trying NearBlocks: {err}");
+            }
+            Err(err) => {
+                return Err(eyre!("Failed to look up the key on FastNEAR: {err}"));
+            }
+        }
+    }
+    let Some(nearblocks_url) = &network_config.nearblocks_url else {
+        return Err(eyre!(
+            "Neither `fastnear_url` nor `nearblocks_url` is configured for the network <{}>, so the accounts of the key can't be looked up",
+            network_config.network_name
+        ));
+    };
+    fetch_account_ids_from_nearblocks(nearblocks_url, public_key)
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NearblocksKeysResponse {
+    keys: Vec<NearblocksKey>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct NearblocksKey {
+    account_id: near_primitives::types::AccountId,
+}
+
+#[tracing::instrument(
+    name = "Getting the accounts of the public key from NearBlocks ...",
+    skip_all
+)]
+fn fetch_account_ids_from_nearblocks(
+    nearblocks_url: &url::Url,
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::eyre::Result<std::collections::BTreeSet<near_primitives::types::AccountId>> {
+    tracing::info!(target: "near_teach_me", "Getting the accounts of the public key <{public_key}> from NearBlocks ...");
+    let response = reqwest::blocking::get(nearblocks_url.join(&format!("v1/keys/{public_key}"))?)
+        .map_err(|err| eyre!("Failed to get response from nearblocks.io API: {err}"))?;
+    if !response.status().is_success() {
+        return Err(eyre!(
+            "HTTP error from nearblocks.io API: {}",
+            response.status()
+        ));
+    }
+    let response: NearblocksKeysResponse = response
+        .json()
+        .map_err(|err| eyre!("Failed to parse JSON response from nearblocks.io API: {err}"))?;
+    Ok(response
+        .keys
+        .into_iter()
+        .map(|key| key.account_id)
+        .collect())
+}
+
+#[derive(Debug, serde::Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum AccessKeyStatus {
+    Found {
+        permission: near_primitives::views::AccessKeyPermissionView,
+    },
+    /// The indexer may list a key that was already deleted.
+    NotFound,
+    Unknown {
+        error: String,
+    },
+}
+
+#[derive(Debug, serde::Serialize)]
+struct CandidateAccount {
+    account_id: near_primitives::types::AccountId,
+    #[serde(flatten)]
+    access_key_status: AccessKeyStatus,
+}
+
+fn get_access_key_status(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> AccessKeyStatus {
+    match network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key(
+            account_id,
+            public_key,
+            near_primitives::types::BlockReference::latest(),
+        )
+        .map_err(|err| *err)
+    {
+        Ok(rpc_query_response) => match rpc_query_response.kind {
+            near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key) => {
+                AccessKeyStatus::Found {
+                    permission: access_key.permission,
+                }
+            }
+            _ => AccessKeyStatus::Unknown {
+                error: "Error call result".to_string(),
+            },
+        },
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccessKey { .. }
+                | near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+            ),
+        )) => AccessKeyStatus::NotFound,
+        Err(err) => AccessKeyStatus::Unknown {
+            error: err.to_string(),
+        },
+    }
+}
+
+fn display_candidate_accounts(
+    public_key: &near_crypto::PublicKey,
+    accounts: &[CandidateAccount],
+    output_format: crate::common::OutputFormat,
+    network_config: &crate::config::NetworkConfig,
+) -> crate::CliResult {
+    if let crate::common::OutputFormat::Json = output_format {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "public_key": public_key,
+                "network": network_config.network_name,
+                "accounts": accounts,
+            }))?
+        );
+        return Ok(());
+    }
+
+    if accounts.is_empty() {
+        println!(
+            "No accounts with the key <{public_key}> were found on the <{}> network.",
+            network_config.network_name
+        );
+        return Ok(());
+    }
+    let mut table = Table::new();
+    table.set_titles(prettytable::row![Fg=>"#", "Account Id", "Permissions"]);
+    for (index, account) in accounts.iter().enumerate() {
+        let permissions_message = match &account.access_key_status {
+            AccessKeyStatus::Found { permission } => {
+                crate::common::access_key_permission_description(permission)
+            }
+            AccessKeyStatus::NotFound => "not found on-chain (deleted since it was indexed)"
+                .red()
+                .to_string(),
+            AccessKeyStatus::Unknown { error } => format!("unknown ({error})"),
+        };
+        table.add_row(prettytable::row![
+            Fg->index + 1,
+            account.account_id,
+            permissions_message
+        ]);
+    }
+    table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.printstd();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEYS_RESPONSE: &str = r#"{"keys": [
+        { "public_key": "ed25519:6DSjZ8mvsRZDvFqFxo8tCKePG96omXW7eVYVSySmDk8e", "account_id": "bob.near", "permission_kind": "FULL_ACCESS" },
+        { "public_key": "ed25519:6DSjZ8mvsRZDvFqFxo8tCKePG96omXW7eVYVSySmDk8e", "account_id": "alice.near", "permission_kind": "FUNCTION_CALL" }
+    ]}"#;
+
+    fn test_network_config(
+        fastnear_url: Option<url::Url>,
+        nearblocks_url: Option<url::Url>,
+    ) -> crate::config::NetworkConfig {
+        crate::config::NetworkConfig {
+            network_name: "mainnet".to_string(),
+            rpc_url: "http://127.0.0.1:1/".parse().unwrap(),
+            rpc_api_key: None,
+            wallet_url: "http://127.0.0.1:2/".parse().unwrap(),
+            explorer_transaction_url: "http://127.0.0.1:3/".parse().unwrap(),
+            linkdrop_account_id: None,
+            near_social_db_contract_account_id: None,
+            faucet_url: None,
+            meta_transaction_relayer_url: None,
+            fastnear_url,
+            staking_pools_factory_account_id: None,
+            coingecko_url: None,
+            mpc_contract_account_id: None,
+            tx_wait_until: None,
+            nearblocks_url,
+            rpc_fallback_endpoints: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn falls_back_to_nearblocks_when_fastnear_fails() {
+        let (nearblocks_url, server_handle) =
+            crate::types::ft_inventory::spawn_mock_nearblocks_server(KEYS_RESPONSE);
+        let public_key: near_crypto::PublicKey =
+            "ed25519:6DSjZ8mvsRZDvFqFxo8tCKePG96omXW7eVYVSySmDk8e"
+                .parse()
+                .unwrap();
+
+        // Nothing listens on port 1, so the FastNEAR request fails.
+        let network_config = test_network_config(
+            Some("http://127.0.0.1:1/".parse().unwrap()),
+            Some(nearblocks_url.parse().unwrap()),
+        );
+        let account_ids = find_candidate_account_ids(&network_config, &public_key).unwrap();
+        assert_eq!(
+            account_ids
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["alice.near", "bob.near"]
+        );
+        server_handle.join().unwrap();
+
+        let network_config =
+            test_network_config(Some("http://127.0.0.1:1/".parse().unwrap()), None);
+        assert!(find_candidate_account_ids(&network_config, &public_key).is_err());
+        let network_config = test_network_config(None, None);
+        assert!(find_candidate_account_ids(&network_config, &public_key).is_err());
+    }
+
+    #[test]
+    fn serializes_candidate_accounts() {
+        let accounts = [
+            CandidateAccount {
+                account_id: "alice.near".parse().unwrap(),
+                access_key_status: AccessKeyStatus::Found {
+                    permission: near_primitives::views::AccessKeyPermissionView::FullAccess,
+                },
+            },
+            CandidateAccount {
+                account_id: "bob.near".parse().unwrap(),
+                access_key_status: AccessKeyStatus::NotFound,
+            },
+        ];
+        assert_eq!(
+            serde_json::to_value(accounts).unwrap(),
+            serde_json::json!([
+                { "account_id": "alice.near", "status": "found", "permission": "FullAccess" },
+                { "account_id": "bob.near", "status": "not_found" },
+            ])
+        );
+    }
+
+    #[test]
+    fn parses_nearblocks_keys_response() {
+        let response: NearblocksKeysResponse = serde_json::from_value(serde_json::json!({
+            "keys": [
+                {
+                    "public_key": "ed25519:6DSjZ8mvsRZDvFqFxo8tCKePG96omXW7eVYVSySmDk8e",
+                    "account_id": "alice.near",
+                    "permission_kind": "FULL_ACCESS"
+                }
+            ]
+        }))
+        .unwrap();
+        assert_eq!(response.keys[0].account_id, "alice.near");
+    }
+}
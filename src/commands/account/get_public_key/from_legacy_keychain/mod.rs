@@ -121,7 +121,7 @@ impl PublicKeyFromKeychain {
         context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.config.credentials_home_dir,
+            &context.config,
             "For which account do you need to view the public key?",
         )
     }
@@ -110,7 +110,7 @@ fn input_account_id() -> color_eyre::eyre::Result<near_primitives::types::Accoun
     Ok(CustomType::new("Enter account ID:").prompt()?)
 }
 
-fn save_access_key(
+pub(super) fn save_access_key(
     account_id: near_primitives::types::AccountId,
     key_pair_properties_buf: &str,
     public_key_str: &str,
@@ -125,15 +125,32 @@ fn save_access_key(
             to_string = "Store the access key in my legacy keychain (compatible with the old near CLI)"
         )]
         SaveToLegacyKeychain,
+        #[strum(to_string = "Store the access key in the passphrase-encrypted keystore")]
+        SaveToEncryptedKeystore,
     }
     let selection = Select::new(
         "Select a keychain to save the access key to:",
         vec![
             SelectStorage::SaveToKeychain,
             SelectStorage::SaveToLegacyKeychain,
+            SelectStorage::SaveToEncryptedKeystore,
         ],
     )
     .prompt()?;
+    if let SelectStorage::SaveToEncryptedKeystore = selection {
+        let storage_message = crate::common::save_access_key_to_encrypted_keystore(
+            network_config,
+            credentials_home_dir,
+            key_pair_properties_buf,
+            public_key_str,
+            account_id.as_ref(),
+        )
+        .wrap_err_with(|| {
+            format!("Failed to save an encrypted file with access key: {public_key_str}")
+        })?;
+        eprintln!("{storage_message}");
+        return Ok(());
+    }
     if let SelectStorage::SaveToKeychain = selection {
         let storage_message =
             crate::common::save_access_key_to_keychain_or_save_to_legacy_keychain(
@@ -25,6 +25,7 @@ impl ViewListKeysContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+            let output_format = previous_context.output_format;
 
             move |network_config, block_reference| {
                 let access_key_list = network_config
@@ -41,8 +42,7 @@ impl ViewListKeysContext {
                     })?
                     .access_key_list_view()?;
 
-                crate::common::display_access_key_list(&access_key_list.keys);
-                Ok(())
+                crate::common::display_access_key_list(&access_key_list.keys, output_format)
             }
         });
 
@@ -0,0 +1,745 @@
+use color_eyre::eyre::WrapErr;
+use color_eyre::owo_colors::OwoColorize;
+use prettytable::Table;
+
+use crate::common::JsonRpcClientExt;
+use crate::common::RpcQueryResponseExt;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ListLocalKeysContext)]
+pub struct ListLocalKeys {
+    /// Show only the keys of this account
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    account_id: Option<crate::types::account_id::AccountId>,
+    /// Show only the keys for this network (e.g. testnet)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    network_name: Option<String>,
+    /// Remove the keys that were deleted on-chain from the local key stores (keychain keys saved by older versions of near CLI are only found while they exist on-chain)
+    #[interactive_clap(long)]
+    prune: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ListLocalKeysContext;
+
+impl ListLocalKeysContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ListLocalKeys as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if scope.prune && previous_context.offline {
+            return Err(color_eyre::eyre::eyre!(
+                "Cannot prune the local keys in offline mode: the keys have to be checked on-chain"
+            ));
+        }
+        let account_id: Option<near_primitives::types::AccountId> =
+            scope.account_id.clone().map(Into::into);
+        let local_keys = find_local_keys(
+            &previous_context.config,
+            previous_context.offline,
+            account_id.as_ref(),
+            scope.network_name.as_deref(),
+        );
+
+        let mut pruned_keys: Vec<&LocalKey> = Vec::new();
+        if scope.prune {
+            for local_key in local_keys
+                .iter()
+                .filter(|local_key| local_key.deleted_on_chain)
+            {
+                prune_local_key(&previous_context.config.credentials_home_dir, local_key)
+                    .wrap_err_with(|| {
+                        format!(
+                            "Failed to remove the access key <{}> of <{}> from the {}",
+                            local_key.public_key, local_key.account_id, local_key.store
+                        )
+                    })?;
+                pruned_keys.push(local_key);
+            }
+        }
+
+        if let crate::common::OutputFormat::Json = previous_context.output_format {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "keys": local_keys,
+                    "pruned": pruned_keys,
+                }))?
+            );
+            return Ok(Self);
+        }
+
+        if local_keys.is_empty() {
+            eprintln!("\nThere are no access keys saved locally.");
+            return Ok(Self);
+        }
+        let mut table = Table::new();
+        table.set_titles(prettytable::row![
+            Fg->"Network",
+            Fg->"Account",
+            Fg->"Public key",
+            Fg->"Stored in",
+            Fg->"On-chain status"
+        ]);
+        for local_key in &local_keys {
+            table.add_row(prettytable::row![
+                local_key.network_name,
+                local_key.account_id,
+                local_key.public_key,
+                match &local_key.path {
+                    Some(path) => format!("{}\n{}", local_key.store, path.display()),
+                    None => local_key.store.to_string(),
+                },
+                if local_key.deleted_on_chain {
+                    local_key.status.red().to_string()
+                } else {
+                    local_key.status.clone()
+                }
+            ]);
+        }
+        table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+        table.printstd();
+
+        let deleted_keys_count = local_keys
+            .iter()
+            .filter(|local_key| local_key.deleted_on_chain)
+            .count();
+        if scope.prune {
+            eprintln!(
+                "\n{} access keys deleted on-chain were removed from the local key stores.",
+                pruned_keys.len()
+            );
+        } else if deleted_keys_count > 0 {
+            eprintln!(
+                "\n{deleted_keys_count} access keys were deleted on-chain. Use `--prune` to remove them from the local key stores."
+            );
+        }
+        Ok(Self)
+    }
+}
+
+/// Finds the access keys saved in the keychain, the legacy keychain and the encrypted keystore
+/// for the accounts from the used account list, the list of the keychain keys and the file-based
+/// stores, and checks them on-chain.
+pub(super) fn find_local_keys(
+    config: &crate::config::Config,
+    offline: bool,
+    account_id: Option<&near_primitives::types::AccountId>,
+    network_name: Option<&str>,
+) -> Vec<LocalKey> {
+    let credentials_home_dir = &config.credentials_home_dir;
+    let used_account_ids = crate::common::get_used_account_list(credentials_home_dir)
+        .into_iter()
+        .map(|used_account| used_account.account_id)
+        .collect::<Vec<_>>();
+    let keychain_keys = crate::common::get_keychain_key_list(credentials_home_dir);
+
+    let mut local_keys: Vec<LocalKey> = Vec::new();
+    for network_config in network_configs(config, network_name) {
+        let mut network_local_keys = find_file_keys(credentials_home_dir, network_config);
+        let network_keychain_keys = keychain_keys
+            .iter()
+            .filter(|keychain_key| keychain_key.network_name == network_config.network_name)
+            .collect::<Vec<_>>();
+        let mut account_ids = used_account_ids.clone();
+        account_ids.extend(
+            network_local_keys
+                .iter()
+                .map(|local_key| local_key.account_id.clone()),
+        );
+        account_ids.extend(
+            network_keychain_keys
+                .iter()
+                .map(|keychain_key| keychain_key.account_id.clone()),
+        );
+        account_ids.sort();
+        account_ids.dedup();
+        account_ids.retain(|id| account_id.is_none_or(|account_id| account_id == id));
+        network_local_keys.retain(|local_key| account_ids.contains(&local_key.account_id));
+
+        for account_id in account_ids {
+            let on_chain_keys = if offline {
+                OnChainKeys::NotChecked
+            } else {
+                get_on_chain_keys(network_config, &account_id)
+            };
+            // Keyring entries cannot be enumerated, so look up the keys known from the chain,
+            // from the file-based stores and from the list of the saved keychain keys.
+            let mut candidate_public_keys = network_local_keys
+                .iter()
+                .filter(|local_key| local_key.account_id == account_id)
+                .map(|local_key| local_key.public_key.clone())
+                .collect::<Vec<_>>();
+            candidate_public_keys.extend(
+                network_keychain_keys
+                    .iter()
+                    .filter(|keychain_key| keychain_key.account_id == account_id)
+                    .map(|keychain_key| keychain_key.public_key.clone()),
+            );
+            if let OnChainKeys::Found(keys) = &on_chain_keys {
+                candidate_public_keys.extend(keys.keys().cloned());
+            }
+            candidate_public_keys.sort();
+            candidate_public_keys.dedup();
+            network_local_keys.extend(candidate_public_keys.into_iter().filter_map(|public_key| {
+                let entry = keychain_entry(&network_config.network_name, &account_id, &public_key)?;
+                entry.get_password().ok()?;
+                Some(LocalKey {
+                    network_name: network_config.network_name.clone(),
+                    account_id: account_id.clone(),
+                    public_key,
+                    store: KeyStore::Keychain,
+                    path: None,
+                    status: String::new(),
+                    deleted_on_chain: false,
+                    on_chain: false,
+                })
+            }));
+
+            for local_key in network_local_keys
+                .iter_mut()
+                .filter(|local_key| local_key.account_id == account_id)
+            {
+                (
+                    local_key.status,
+                    local_key.deleted_on_chain,
+                    local_key.on_chain,
+                ) = on_chain_key_status(&on_chain_keys, &local_key.public_key);
+            }
+        }
+        local_keys.extend(network_local_keys);
+    }
+    sort_local_keys(&mut local_keys);
+    local_keys
+}
+
+/// Finds the access keys saved in the legacy keychain and the encrypted keystore, and the keys
+/// from the list of the keychain keys, without reading the keychain or checking them on-chain.
+pub(super) fn find_saved_keys(config: &crate::config::Config) -> Vec<LocalKey> {
+    let credentials_home_dir = &config.credentials_home_dir;
+    let keychain_keys = crate::common::get_keychain_key_list(credentials_home_dir);
+
+    let mut local_keys: Vec<LocalKey> = Vec::new();
+    for network_config in network_configs(config, None) {
+        local_keys.extend(find_file_keys(credentials_home_dir, network_config));
+        local_keys.extend(
+            keychain_keys
+                .iter()
+                .filter(|keychain_key| keychain_key.network_name == network_config.network_name)
+                .map(|keychain_key| LocalKey {
+                    network_name: keychain_key.network_name.clone(),
+                    account_id: keychain_key.account_id.clone(),
+                    public_key: keychain_key.public_key.clone(),
+                    store: KeyStore::Keychain,
+                    path: None,
+                    status: String::new(),
+                    deleted_on_chain: false,
+                    on_chain: false,
+                }),
+        );
+    }
+    sort_local_keys(&mut local_keys);
+    local_keys
+}
+
+/// Several connections may point to the same network; the key stores are per network.
+fn network_configs<'a>(
+    config: &'a crate::config::Config,
+    network_name: Option<&str>,
+) -> Vec<&'a crate::config::NetworkConfig> {
+    let mut network_configs: Vec<&crate::config::NetworkConfig> = Vec::new();
+    for (connection_name, network_config) in &config.network_connection {
+        if network_name.is_some_and(|network_name| {
+            network_name != connection_name && network_name != network_config.network_name
+        }) {
+            continue;
+        }
+        if !network_configs
+            .iter()
+            .any(|known| known.network_name == network_config.network_name)
+        {
+            network_configs.push(network_config);
+        }
+    }
+    network_configs
+}
+
+fn sort_local_keys(local_keys: &mut [LocalKey]) {
+    local_keys.sort_by(|a, b| {
+        (&a.network_name, &a.account_id, &a.public_key, a.store).cmp(&(
+            &b.network_name,
+            &b.account_id,
+            &b.public_key,
+            b.store,
+        ))
+    });
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub(super) enum KeyStore {
+    Keychain,
+    LegacyKeychain,
+    EncryptedKeystore,
+}
+
+impl std::fmt::Display for KeyStore {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Keychain => write!(f, "keychain"),
+            Self::LegacyKeychain => write!(f, "legacy keychain"),
+            Self::EncryptedKeystore => write!(f, "encrypted keystore"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub(super) struct LocalKey {
+    pub(super) network_name: String,
+    pub(super) account_id: near_primitives::types::AccountId,
+    pub(super) public_key: String,
+    pub(super) store: KeyStore,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(super) path: Option<std::path::PathBuf>,
+    pub(super) status: String,
+    pub(super) deleted_on_chain: bool,
+    /// The key exists on-chain (checked online)
+    #[serde(skip)]
+    pub(super) on_chain: bool,
+}
+
+enum OnChainKeys {
+    /// Public key => permission
+    Found(std::collections::BTreeMap<String, String>),
+    AccountNotFound,
+    Unknown(String),
+    NotChecked,
+}
+
+/// The status of the local key, whether it was deleted on-chain and whether it exists on-chain.
+fn on_chain_key_status(on_chain_keys: &OnChainKeys, public_key: &str) -> (String, bool, bool) {
+    match on_chain_keys {
+        OnChainKeys::Found(keys) => match keys.get(public_key) {
+            Some(permission) => (permission.clone(), false, true),
+            None => ("deleted on-chain".to_string(), true, false),
+        },
+        OnChainKeys::AccountNotFound => ("account not found".to_string(), false, false),
+        OnChainKeys::Unknown(err) => (format!("unknown ({err})"), false, false),
+        OnChainKeys::NotChecked => ("not checked (offline)".to_string(), false, false),
+    }
+}
+
+fn get_on_chain_keys(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+) -> OnChainKeys {
+    let access_key_list = match network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key_list(
+            account_id,
+            near_primitives::types::Finality::Final.into(),
+        ) {
+        Ok(response) => response.access_key_list_view(),
+        Err(err) => match *err {
+            near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+                ),
+            ) => return OnChainKeys::AccountNotFound,
+            err => return OnChainKeys::Unknown(err.to_string()),
+        },
+    };
+    match access_key_list {
+        Ok(access_key_list) => OnChainKeys::Found(
+            access_key_list
+                .keys
+                .into_iter()
+                .map(|access_key_info| {
+                    let permission = match access_key_info.access_key.permission {
+                        near_primitives::views::AccessKeyPermissionView::FullAccess => {
+                            "full access"
+                        }
+                        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+                            ..
+                        } => "function call",
+                        near_primitives::views::AccessKeyPermissionView::GasKeyFullAccess {
+                            ..
+                        }
+                        | near_primitives::views::AccessKeyPermissionView::GasKeyFunctionCall {
+                            ..
+                        } => "gas key",
+                    };
+                    (
+                        access_key_info.public_key.to_string(),
+                        permission.to_string(),
+                    )
+                })
+                .collect(),
+        ),
+        Err(err) => OnChainKeys::Unknown(err.to_string()),
+    }
+}
+
+/// Finds the keys in the legacy keychain (`<credentials_home_dir>/<network>/<account>.json`
+/// and `<credentials_home_dir>/<network>/<account>/<public_key>.json`) and in the encrypted keystore.
+fn find_file_keys(
+    credentials_home_dir: &std::path::Path,
+    network_config: &crate::config::NetworkConfig,
+) -> Vec<LocalKey> {
+    let read_dir =
+        |dir: &std::path::Path| dir.read_dir().map(Iterator::flatten).into_iter().flatten();
+    let read_public_key = |path: &std::path::Path| -> Option<String> {
+        let data: serde_json::Value = serde_json::from_slice(&std::fs::read(path).ok()?).ok()?;
+        Some(data.get("public_key")?.as_str()?.to_string())
+    };
+    let new_local_key = |account_id, public_key, store, path| LocalKey {
+        network_name: network_config.network_name.clone(),
+        account_id,
+        public_key,
+        store,
+        path: Some(path),
+        status: String::new(),
+        deleted_on_chain: false,
+        on_chain: false,
+    };
+
+    let mut local_keys = Vec::new();
+    for entry in read_dir(&credentials_home_dir.join(&network_config.network_name)) {
+        let path = entry.path();
+        if path.is_dir() {
+            let Ok(account_id) = entry
+                .file_name()
+                .to_string_lossy()
+                .parse::<near_primitives::types::AccountId>()
+            else {
+                continue;
+            };
+            for key_entry in read_dir(&path) {
+                if let Some(public_key) = read_public_key(&key_entry.path()) {
+                    local_keys.push(new_local_key(
+                        account_id.clone(),
+                        public_key,
+                        KeyStore::LegacyKeychain,
+                        key_entry.path(),
+                    ));
+                }
+            }
+        } else if let (Some(file_stem), Some(public_key)) =
+            (path.file_stem(), read_public_key(&path))
+            && let Ok(account_id) = file_stem.to_string_lossy().parse()
+        {
+            local_keys.push(new_local_key(
+                account_id,
+                public_key,
+                KeyStore::LegacyKeychain,
+                path,
+            ));
+        }
+    }
+
+    for entry in read_dir(
+        &credentials_home_dir
+            .join("encrypted-keystore")
+            .join(&network_config.network_name),
+    ) {
+        let Ok(account_id) = entry
+            .file_name()
+            .to_string_lossy()
+            .parse::<near_primitives::types::AccountId>()
+        else {
+            continue;
+        };
+        for (path, encrypted_keystore_file) in
+            crate::common::get_access_keys_from_encrypted_keystore(
+                credentials_home_dir,
+                &network_config.network_name,
+                &account_id,
+            )
+        {
+            local_keys.push(new_local_key(
+                account_id.clone(),
+                encrypted_keystore_file.public_key,
+                KeyStore::EncryptedKeystore,
+                path,
+            ));
+        }
+    }
+    local_keys
+}
+
+pub(super) fn keychain_entry(
+    network_name: &str,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &str,
+) -> Option<keyring::Entry> {
+    keyring::Entry::new(
+        &format!("near-{network_name}-{account_id}"),
+        &format!("{account_id}:{public_key}"),
+    )
+    .ok()
+}
+
+fn prune_local_key(
+    credentials_home_dir: &std::path::Path,
+    local_key: &LocalKey,
+) -> crate::CliResult {
+    match (&local_key.store, &local_key.path) {
+        (KeyStore::LegacyKeychain | KeyStore::EncryptedKeystore, Some(path)) => {
+            std::fs::remove_file(path)
+                .wrap_err_with(|| format!("Failed to remove file: {path:?}"))?;
+        }
+        (KeyStore::Keychain, _) => {
+            keychain_entry(
+                &local_key.network_name,
+                &local_key.account_id,
+                &local_key.public_key,
+            )
+            .ok_or_else(|| color_eyre::eyre::eyre!("Failed to open keychain"))?
+            .delete_credential()
+            .wrap_err("Failed to delete the access key from keychain")?;
+            crate::common::remove_from_keychain_key_list(
+                credentials_home_dir,
+                &crate::common::KeychainKey {
+                    network_name: local_key.network_name.clone(),
+                    account_id: local_key.account_id.clone(),
+                    public_key: local_key.public_key.clone(),
+                },
+            )?;
+        }
+        (_, None) => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUBLIC_KEY: &str = "ed25519:6DSjZ8mvsRZDvFqFxo8tCKePG96omXW7eVYVSySmDk8e";
+
+    fn network_config(network_name: &str, rpc_url: &str) -> crate::config::NetworkConfig {
+        crate::config::NetworkConfig {
+            network_name: network_name.to_string(),
+            rpc_url: rpc_url.parse().unwrap(),
+            rpc_api_key: None,
+            wallet_url: rpc_url.parse().unwrap(),
+            explorer_transaction_url: rpc_url.parse().unwrap(),
+            linkdrop_account_id: None,
+            near_social_db_contract_account_id: None,
+            faucet_url: None,
+            meta_transaction_relayer_url: None,
+            fastnear_url: None,
+            staking_pools_factory_account_id: None,
+            coingecko_url: None,
+            mpc_contract_account_id: None,
+            tx_wait_until: None,
+            nearblocks_url: None,
+            rpc_fallback_endpoints: Vec::new(),
+        }
+    }
+
+    /// Two connections to testnet and one to mainnet, with legacy keychain keys of
+    /// alice.testnet and bob.testnet on testnet.
+    fn config_with_legacy_keys(credentials_home_dir: &std::path::Path) -> crate::config::Config {
+        for (path, public_key) in [
+            ("testnet/alice.testnet.json", PUBLIC_KEY),
+            (
+                "testnet/bob.testnet/ed25519_3WgwWTQ5NYyDMqm9qd8ovKaQ4rnVDNymr9HSNa5Apcnp.json",
+                "ed25519:3WgwWTQ5NYyDMqm9qd8ovKaQ4rnVDNymr9HSNa5Apcnp",
+            ),
+        ] {
+            let path = credentials_home_dir.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, format!(r#"{{"public_key": "{public_key}"}}"#)).unwrap();
+        }
+
+        let mut network_connection = linked_hash_map::LinkedHashMap::new();
+        network_connection.insert(
+            "testnet".to_string(),
+            network_config("testnet", "https://rpc.testnet.near.org/"),
+        );
+        network_connection.insert(
+            "testnet-fastnear".to_string(),
+            network_config("testnet", "https://test.rpc.fastnear.com/"),
+        );
+        network_connection.insert(
+            "mainnet".to_string(),
+            network_config("mainnet", "https://rpc.mainnet.near.org/"),
+        );
+        crate::config::Config {
+            credentials_home_dir: credentials_home_dir.to_path_buf(),
+            network_connection,
+            default_signer_account_id: None,
+        }
+    }
+
+    fn keys(local_keys: &[LocalKey]) -> Vec<(&str, &str, &str)> {
+        local_keys
+            .iter()
+            .map(|local_key| {
+                (
+                    local_key.network_name.as_str(),
+                    local_key.account_id.as_str(),
+                    local_key.status.as_str(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_local_keys_once_per_network() {
+        let credentials_home_dir = tempfile::tempdir().unwrap();
+        let config = config_with_legacy_keys(credentials_home_dir.path());
+
+        let local_keys = find_local_keys(&config, true, None, None);
+        assert_eq!(
+            keys(&local_keys),
+            [
+                ("testnet", "alice.testnet", "not checked (offline)"),
+                ("testnet", "bob.testnet", "not checked (offline)"),
+            ]
+        );
+        assert!(
+            local_keys
+                .iter()
+                .all(|local_key| local_key.store == KeyStore::LegacyKeychain)
+        );
+
+        // A connection name selects its network.
+        assert_eq!(
+            find_local_keys(&config, true, None, Some("testnet-fastnear")).len(),
+            2
+        );
+        assert!(find_local_keys(&config, true, None, Some("mainnet")).is_empty());
+    }
+
+    #[test]
+    fn filters_local_keys_by_account() {
+        let credentials_home_dir = tempfile::tempdir().unwrap();
+        let config = config_with_legacy_keys(credentials_home_dir.path());
+
+        let account_id: near_primitives::types::AccountId = "bob.testnet".parse().unwrap();
+        let local_keys = find_local_keys(&config, true, Some(&account_id), None);
+        assert_eq!(
+            keys(&local_keys),
+            [("testnet", "bob.testnet", "not checked (offline)")]
+        );
+        assert_eq!(
+            local_keys[0].public_key,
+            "ed25519:3WgwWTQ5NYyDMqm9qd8ovKaQ4rnVDNymr9HSNa5Apcnp"
+        );
+    }
+
+    #[test]
+    fn finds_saved_keys_without_the_keychain() {
+        let credentials_home_dir = tempfile::tempdir().unwrap();
+        let config = config_with_legacy_keys(credentials_home_dir.path());
+        crate::common::add_to_keychain_key_list(
+            credentials_home_dir.path(),
+            crate::common::KeychainKey {
+                network_name: "mainnet".to_string(),
+                account_id: "carol.near".parse().unwrap(),
+                public_key: PUBLIC_KEY.to_string(),
+            },
+        )
+        .unwrap();
+
+        let saved_keys = find_saved_keys(&config);
+        assert_eq!(
+            saved_keys
+                .iter()
+                .map(|local_key| (
+                    local_key.network_name.as_str(),
+                    local_key.account_id.as_str(),
+                    local_key.store
+                ))
+                .collect::<Vec<_>>(),
+            [
+                ("mainnet", "carol.near", KeyStore::Keychain),
+                ("testnet", "alice.testnet", KeyStore::LegacyKeychain),
+                ("testnet", "bob.testnet", KeyStore::LegacyKeychain),
+            ]
+        );
+    }
+
+    #[test]
+    fn maps_on_chain_keys_to_statuses() {
+        let on_chain_keys = OnChainKeys::Found(
+            [(PUBLIC_KEY.to_string(), "full access".to_string())]
+                .into_iter()
+                .collect(),
+        );
+        assert_eq!(
+            on_chain_key_status(&on_chain_keys, PUBLIC_KEY),
+            ("full access".to_string(), false, true)
+        );
+        assert_eq!(
+            on_chain_key_status(
+                &on_chain_keys,
+                "ed25519:3WgwWTQ5NYyDMqm9qd8ovKaQ4rnVDNymr9HSNa5Apcnp"
+            ),
+            ("deleted on-chain".to_string(), true, false)
+        );
+        assert_eq!(
+            on_chain_key_status(&OnChainKeys::AccountNotFound, PUBLIC_KEY),
+            ("account not found".to_string(), false, false)
+        );
+        assert_eq!(
+            on_chain_key_status(&OnChainKeys::Unknown("timeout".to_string()), PUBLIC_KEY),
+            ("unknown (timeout)".to_string(), false, false)
+        );
+        assert_eq!(
+            on_chain_key_status(&OnChainKeys::NotChecked, PUBLIC_KEY),
+            ("not checked (offline)".to_string(), false, false)
+        );
+    }
+
+    #[test]
+    fn records_keychain_keys() {
+        let credentials_home_dir = tempfile::tempdir().unwrap();
+        let keychain_key = crate::common::KeychainKey {
+            network_name: "testnet".to_string(),
+            account_id: "alice.testnet".parse().unwrap(),
+            public_key: PUBLIC_KEY.to_string(),
+        };
+        crate::common::add_to_keychain_key_list(credentials_home_dir.path(), keychain_key.clone())
+            .unwrap();
+        crate::common::add_to_keychain_key_list(credentials_home_dir.path(), keychain_key.clone())
+            .unwrap();
+        assert_eq!(
+            crate::common::get_keychain_key_list(credentials_home_dir.path()),
+            std::slice::from_ref(&keychain_key)
+        );
+
+        crate::common::remove_from_keychain_key_list(credentials_home_dir.path(), &keychain_key)
+            .unwrap();
+        assert!(crate::common::get_keychain_key_list(credentials_home_dir.path()).is_empty());
+    }
+
+    #[test]
+    fn prunes_legacy_keychain_key() {
+        let credentials_home_dir = tempfile::tempdir().unwrap();
+        let path = credentials_home_dir
+            .path()
+            .join("testnet")
+            .join("alice.testnet.json");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, format!(r#"{{"public_key": "{PUBLIC_KEY}"}}"#)).unwrap();
+
+        let local_key = LocalKey {
+            network_name: "testnet".to_string(),
+            account_id: "alice.testnet".parse().unwrap(),
+            public_key: PUBLIC_KEY.to_string(),
+            store: KeyStore::LegacyKeychain,
+            path: Some(path.clone()),
+            status: "deleted on-chain".to_string(),
+            deleted_on_chain: true,
+            on_chain: false,
+        };
+        prune_local_key(credentials_home_dir.path(), &local_key).unwrap();
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,343 @@
+use std::io::Write;
+
+use color_eyre::eyre::WrapErr;
+use color_eyre::owo_colors::OwoColorize;
+use prettytable::Table;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+use super::list_local_keys::{KeyStore, LocalKey};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+pub struct MigrateKeys {
+    #[interactive_clap(subcommand)]
+    migrate_keys_direction: MigrateKeysDirection,
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// Where do you want to migrate the access keys to?
+pub enum MigrateKeysDirection {
+    #[strum_discriminants(strum(
+        message = "to-keychain          - Migrate the keys from the legacy keychain to the keychain"
+    ))]
+    /// Migrate the keys from the legacy keychain (~/.near-credentials) to the keychain
+    ToKeychain(ToKeychain),
+    #[strum_discriminants(strum(
+        message = "to-legacy-keychain   - Migrate the keys from the keychain to the legacy keychain"
+    ))]
+    /// Migrate the keys from the keychain to the legacy keychain (e.g. to copy them to another machine)
+    ToLegacyKeychain(ToLegacyKeychain),
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ToKeychainContext)]
+pub struct ToKeychain {
+    /// Migrate only the keys of this account
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    account_id: Option<crate::types::account_id::AccountId>,
+    /// Migrate only the keys for this network (e.g. testnet)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    network_name: Option<String>,
+    /// Overwrite and remove the plaintext key files once the keys are in the keychain
+    #[interactive_clap(long)]
+    shred: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct ToKeychainContext;
+
+impl ToKeychainContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ToKeychain as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.offline {
+            return Err(color_eyre::eyre::eyre!(
+                "Cannot migrate the keys in offline mode: the keys have to be checked on-chain"
+            ));
+        }
+        let credentials_home_dir = &previous_context.config.credentials_home_dir;
+        if !crate::common::is_used_account_list_exist(credentials_home_dir) {
+            crate::common::create_used_account_list_from_legacy_keychain(credentials_home_dir)?;
+        }
+
+        let account_id: Option<near_primitives::types::AccountId> =
+            scope.account_id.clone().map(Into::into);
+        let local_keys = super::list_local_keys::find_local_keys(
+            &previous_context.config,
+            previous_context.offline,
+            account_id.as_ref(),
+            scope.network_name.as_deref(),
+        );
+
+        let mut migrated_keys = Vec::new();
+        for (legacy_key, key_files) in group_key_files(&local_keys, KeyStore::LegacyKeychain) {
+            let mut migrated_key = MigratedKey::new(legacy_key);
+            if !legacy_key.on_chain {
+                migrated_key.result = format!("skipped: {}", legacy_key.status);
+                migrated_keys.push(migrated_key);
+                continue;
+            }
+            if has_key_in(&local_keys, legacy_key, KeyStore::Keychain) {
+                migrated_key.result = "already in the keychain".to_string();
+            } else {
+                let key_pair_properties_buf = std::fs::read_to_string(&key_files[0])
+                    .wrap_err_with(|| format!("Failed to read file: {:?}", key_files[0]))?;
+                crate::common::save_access_key_to_keychain(
+                    network_config(&previous_context.config, &legacy_key.network_name)?,
+                    credentials_home_dir,
+                    &key_pair_properties_buf,
+                    &legacy_key.public_key,
+                    legacy_key.account_id.as_str(),
+                )
+                .wrap_err_with(|| {
+                    format!(
+                        "Failed to migrate the access key <{}> of <{}> to the keychain",
+                        legacy_key.public_key, legacy_key.account_id
+                    )
+                })?;
+                crate::common::update_used_account_list_as_signer(
+                    credentials_home_dir,
+                    &legacy_key.account_id,
+                );
+                migrated_key.result = "migrated".to_string();
+            }
+            if scope.shred {
+                for key_file in &key_files {
+                    shred_file(key_file)?;
+                }
+                migrated_key.shredded = key_files;
+            }
+            migrated_keys.push(migrated_key);
+        }
+
+        print_migrated_keys(&previous_context, &migrated_keys, "keychain")?;
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ToLegacyKeychainContext)]
+pub struct ToLegacyKeychain {
+    /// Migrate only the keys of this account
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    account_id: Option<crate::types::account_id::AccountId>,
+    /// Migrate only the keys for this network (e.g. testnet)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    network_name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ToLegacyKeychainContext;
+
+impl ToLegacyKeychainContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ToLegacyKeychain as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.offline {
+            return Err(color_eyre::eyre::eyre!(
+                "Cannot migrate the keys in offline mode: the keys have to be checked on-chain"
+            ));
+        }
+        let account_id: Option<near_primitives::types::AccountId> =
+            scope.account_id.clone().map(Into::into);
+        let local_keys = super::list_local_keys::find_local_keys(
+            &previous_context.config,
+            previous_context.offline,
+            account_id.as_ref(),
+            scope.network_name.as_deref(),
+        );
+
+        let mut migrated_keys = Vec::new();
+        for keychain_key in local_keys
+            .iter()
+            .filter(|local_key| local_key.store == KeyStore::Keychain)
+        {
+            let mut migrated_key = MigratedKey::new(keychain_key);
+            if !keychain_key.on_chain {
+                migrated_key.result = format!("skipped: {}", keychain_key.status);
+            } else if has_key_in(&local_keys, keychain_key, KeyStore::LegacyKeychain) {
+                migrated_key.result = "already in the legacy keychain".to_string();
+            } else {
+                let key_pair_properties_buf = super::list_local_keys::keychain_entry(
+                    &keychain_key.network_name,
+                    &keychain_key.account_id,
+                    &keychain_key.public_key,
+                )
+                .ok_or_else(|| color_eyre::eyre::eyre!("Failed to open keychain"))?
+                .get_password()
+                .wrap_err("Failed to get the access key from keychain")?;
+                crate::common::save_access_key_to_legacy_keychain(
+                    network_config(&previous_context.config, &keychain_key.network_name)?,
+                    previous_context.config.credentials_home_dir.clone(),
+                    &key_pair_properties_buf,
+                    &keychain_key.public_key,
+                    keychain_key.account_id.as_str(),
+                )
+                .wrap_err_with(|| {
+                    format!(
+                        "Failed to migrate the access key <{}> of <{}> to the legacy keychain",
+                        keychain_key.public_key, keychain_key.account_id
+                    )
+                })?;
+                migrated_key.result = "migrated".to_string();
+            }
+            migrated_keys.push(migrated_key);
+        }
+
+        print_migrated_keys(&previous_context, &migrated_keys, "legacy keychain")?;
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct MigratedKey {
+    network_name: String,
+    account_id: near_primitives::types::AccountId,
+    public_key: String,
+    /// `migrated`, `already in the ...` or `skipped: <on-chain status>`
+    result: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    shredded: Vec<std::path::PathBuf>,
+}
+
+impl MigratedKey {
+    fn new(local_key: &LocalKey) -> Self {
+        Self {
+            network_name: local_key.network_name.clone(),
+            account_id: local_key.account_id.clone(),
+            public_key: local_key.public_key.clone(),
+            result: String::new(),
+            shredded: Vec::new(),
+        }
+    }
+}
+
+/// The same key may be saved in several files of the legacy keychain
+/// (`<account_id>.json` and `<account_id>/<public_key>.json`).
+fn group_key_files(
+    local_keys: &[LocalKey],
+    store: KeyStore,
+) -> Vec<(&LocalKey, Vec<std::path::PathBuf>)> {
+    let mut groups: Vec<(&LocalKey, Vec<std::path::PathBuf>)> = Vec::new();
+    for local_key in local_keys
+        .iter()
+        .filter(|local_key| local_key.store == store)
+    {
+        let Some(path) = local_key.path.clone() else {
+            continue;
+        };
+        match groups
+            .iter_mut()
+            .find(|(known, _)| is_same_key(known, local_key))
+        {
+            Some((_, paths)) => paths.push(path),
+            None => groups.push((local_key, vec![path])),
+        }
+    }
+    groups
+}
+
+fn has_key_in(local_keys: &[LocalKey], key: &LocalKey, store: KeyStore) -> bool {
+    local_keys
+        .iter()
+        .any(|local_key| local_key.store == store && is_same_key(local_key, key))
+}
+
+fn is_same_key(a: &LocalKey, b: &LocalKey) -> bool {
+    a.network_name == b.network_name && a.account_id == b.account_id && a.public_key == b.public_key
+}
+
+fn network_config(
+    config: &crate::config::Config,
+    network_name: &str,
+) -> color_eyre::eyre::Result<crate::config::NetworkConfig> {
+    config
+        .network_connection
+        .values()
+        .find(|network_config| network_config.network_name == network_name)
+        .cloned()
+        .ok_or_else(|| {
+            color_eyre::eyre::eyre!("Network connection for \"{network_name}\" not found")
+        })
+}
+
+/// Overwrites the file with zeros before removing it, so the plaintext key does not stay on disk.
+fn shred_file(path: &std::path::Path) -> crate::CliResult {
+    let len = std::fs::metadata(path)
+        .wrap_err_with(|| format!("Failed to read metadata of file: {path:?}"))?
+        .len();
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(path)
+        .wrap_err_with(|| format!("Failed to open file: {path:?}"))?;
+    file.write_all(&vec![0u8; len.try_into()?])
+        .and_then(|()| file.sync_all())
+        .wrap_err_with(|| format!("Failed to overwrite file: {path:?}"))?;
+    drop(file);
+    std::fs::remove_file(path).wrap_err_with(|| format!("Failed to remove file: {path:?}"))
+}
+
+fn print_migrated_keys(
+    previous_context: &crate::GlobalContext,
+    migrated_keys: &[MigratedKey],
+    destination: &str,
+) -> crate::CliResult {
+    if let crate::common::OutputFormat::Json = previous_context.output_format {
+        println!("{}", serde_json::to_string_pretty(migrated_keys)?);
+        return Ok(());
+    }
+
+    if migrated_keys.is_empty() {
+        eprintln!("\nThere are no access keys to migrate.");
+        return Ok(());
+    }
+    let mut table = Table::new();
+    table.set_titles(prettytable::row![
+        Fg->"Network",
+        Fg->"Account",
+        Fg->"Public key",
+        Fg->"Result"
+    ]);
+    for migrated_key in migrated_keys {
+        table.add_row(prettytable::row![
+            migrated_key.network_name,
+            migrated_key.account_id,
+            migrated_key.public_key,
+            if migrated_key.result.starts_with("skipped") {
+                migrated_key.result.yellow().to_string()
+            } else if migrated_key.shredded.is_empty() {
+                migrated_key.result.clone()
+            } else {
+                format!(
+                    "{}\nshredded: {}",
+                    migrated_key.result,
+                    migrated_key
+                        .shredded
+                        .iter()
+                        .map(|path| path.display().to_string())
+                        .collect::<Vec<_>>()
+                        .join("\n")
+                )
+            }
+        ]);
+    }
+    table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+    table.printstd();
+
+    let migrated_keys_count = migrated_keys
+        .iter()
+        .filter(|migrated_key| migrated_key.result == "migrated")
+        .count();
+    eprintln!("\n{migrated_keys_count} access keys were migrated to the {destination}.");
+    Ok(())
+}
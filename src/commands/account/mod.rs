@@ -5,10 +5,15 @@ pub mod create_account;
 pub mod delete_account;
 pub mod delete_key;
 pub mod export_account;
+mod find_by_public_key;
 mod fund_gas_key;
 mod get_public_key;
 mod import_account;
 mod list_keys;
+mod list_local_keys;
+mod migrate_keys;
+mod multisig;
+mod recover_from_seed_phrase;
 pub mod storage_management;
 pub mod update_social_profile;
 pub mod view_account_summary;
@@ -40,6 +45,11 @@ pub enum AccountActions {
     ))]
     /// Import existing account (a.k.a. "sign in")
     ImportAccount(self::import_account::ImportAccountCommand),
+    #[strum_discriminants(strum(
+        message = "recover-from-seed-phrase - Find the accounts of a seed phrase across HD paths and import them"
+    ))]
+    /// Find the accounts of a seed phrase across HD paths and import them
+    RecoverFromSeedPhrase(self::recover_from_seed_phrase::RecoverFromSeedPhrase),
     #[strum_discriminants(strum(message = "export-account          - Export existing account"))]
     /// Export existing account
     ExportAccount(self::export_account::ExportAccount),
@@ -57,6 +67,21 @@ pub enum AccountActions {
     ))]
     /// View a list of access keys of an account
     ListKeys(self::list_keys::ViewListKeys),
+    #[strum_discriminants(strum(
+        message = "find-by-public-key      - Find the accounts that have an access key"
+    ))]
+    /// Find the accounts that have an access key (looked up on the indexer, confirmed on RPC)
+    FindByPublicKey(self::find_by_public_key::FindByPublicKey),
+    #[strum_discriminants(strum(
+        message = "list-local-keys         - View the access keys saved locally and check them on-chain"
+    ))]
+    /// View the access keys saved locally (keychain, legacy keychain, encrypted keystore) and check them on-chain
+    ListLocalKeys(self::list_local_keys::ListLocalKeys),
+    #[strum_discriminants(strum(
+        message = "migrate-keys            - Migrate the access keys between the legacy keychain and the keychain"
+    ))]
+    /// Migrate the access keys between the legacy keychain and the keychain (the keys are checked on-chain first)
+    MigrateKeys(self::migrate_keys::MigrateKeys),
     #[strum_discriminants(strum(
         message = "view-gas-key-nonces     - View the parallel nonces of a gas key"
     ))]
@@ -87,6 +112,11 @@ pub enum AccountActions {
     ))]
     /// Delete access keys from an account
     DeleteKeys(self::delete_key::DeleteKeysCommand),
+    #[strum_discriminants(strum(
+        message = "multisig                - Deploy a multisig contract, add and confirm requests"
+    ))]
+    /// Deploy a multisig contract, view, add and confirm its requests
+    Multisig(self::multisig::Multisig),
     #[strum_discriminants(strum(
         message = "manage-storage-deposit  - Storage management: deposit, withdrawal, balance review"
     ))]
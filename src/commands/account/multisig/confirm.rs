@@ -0,0 +1,113 @@
+/// Confirming the last missing confirmation executes the request, so its function calls need the gas.
+const DEFAULT_CONFIRM_GAS_TGAS: u64 = 300;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ConfirmRequestContext)]
+pub struct ConfirmRequest {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the multisig account ID?
+    multisig_account_id: crate::types::account_id::AccountId,
+    /// What is the ID of the request to confirm?
+    request_id: u64,
+    /// Gas for the confirmation (default: 300 Tgas)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    prepaid_gas: Option<crate::common::NearGas>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Clone)]
+pub struct ConfirmRequestContext(crate::commands::ActionContext);
+
+impl ConfirmRequestContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ConfirmRequest as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let multisig_account_id: near_primitives::types::AccountId =
+            scope.multisig_account_id.clone().into();
+        let request_id =
+            crate::transaction_signature_options::submit_multisig_request::multisig_request::RequestId::try_from(
+                scope.request_id,
+            )?;
+        let gas = scope
+            .prepaid_gas
+            .map(|gas| near_primitives::gas::Gas::from_gas(gas.as_gas()))
+            .unwrap_or_else(|| near_primitives::gas::Gas::from_teragas(DEFAULT_CONFIRM_GAS_TGAS));
+
+        let get_prepopulated_transaction_after_getting_network_callback: crate::commands::GetPrepopulatedTransactionAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let multisig_account_id = multisig_account_id.clone();
+
+                move |_network_config| {
+                    // The multisig contract is called by the multisig account itself
+                    // with one of the member keys.
+                    Ok(crate::commands::PrepopulatedTransaction {
+                        signer_id: multisig_account_id.clone(),
+                        receiver_id: multisig_account_id.clone(),
+                        actions: vec![near_primitives::transaction::Action::FunctionCall(
+                            Box::new(near_primitives::transaction::FunctionCallAction {
+                                method_name: "confirm".to_string(),
+                                args: serde_json::to_vec(&serde_json::json!({
+                                    "request_id": request_id,
+                                }))?,
+                                gas,
+                                deposit: near_token::NearToken::from_yoctonear(0),
+                            }),
+                        )],
+                    })
+                }
+            });
+
+        let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback = std::sync::Arc::new({
+            let verbosity = previous_context.verbosity;
+
+            move |outcome_view, _network_config| {
+                if let near_primitives::views::FinalExecutionStatus::SuccessValue(_) = outcome_view.status
+                    && let crate::Verbosity::Interactive | crate::Verbosity::TeachMe = verbosity {
+                        tracing_indicatif::suspend_tracing_indicatif(|| {
+                            eprintln!(
+                                "Request #{request_id} of the multisig account <{multisig_account_id}> was confirmed."
+                            );
+                        });
+                    }
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::commands::ActionContext {
+            global_context: previous_context,
+            interacting_with_account_ids: vec![scope.multisig_account_id.clone().into()],
+            get_prepopulated_transaction_after_getting_network_callback,
+            on_before_signing_callback: std::sync::Arc::new(
+                |_prepopulated_unsigned_transaction, _network_config| Ok(()),
+            ),
+            on_before_sending_transaction_callback: std::sync::Arc::new(
+                |_signed_transaction, _network_config| Ok(String::new()),
+            ),
+            on_after_sending_transaction_callback,
+            sign_as_delegate_action: false,
+            on_sending_delegate_action_callback: None,
+        }))
+    }
+}
+
+impl From<ConfirmRequestContext> for crate::commands::ActionContext {
+    fn from(item: ConfirmRequestContext) -> Self {
+        item.0
+    }
+}
+
+impl ConfirmRequest {
+    pub fn input_multisig_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id_from_used_account_list(
+            &context.config,
+            "What is the multisig account ID?",
+        )
+    }
+}
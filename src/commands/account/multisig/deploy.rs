@@ -0,0 +1,144 @@
+use color_eyre::eyre::Context;
+
+use crate::transaction_signature_options::submit_multisig_request::multisig_request::MULTISIG_MEMBER_METHOD_NAMES;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = DeployMultisigContext)]
+pub struct DeployMultisig {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Which account ID do you want to turn into a multisig account?
+    account_id: crate::types::account_id::AccountId,
+    /// What is the file location of the multisig contract (multisig.wasm)?
+    file_path: crate::types::path_buf::PathBuf,
+    /// Enter a comma-separated list of the public keys of the members:
+    member_public_keys: crate::types::public_key_list::PublicKeyList,
+    /// How many member confirmations does a request need?
+    num_confirmations: u64,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Clone)]
+pub struct DeployMultisigContext(crate::commands::ActionContext);
+
+impl DeployMultisigContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<DeployMultisig as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let member_public_keys: Vec<near_crypto::PublicKey> =
+            scope.member_public_keys.clone().into();
+        if scope.num_confirmations == 0
+            || scope.num_confirmations > u64::try_from(member_public_keys.len())?
+        {
+            return Err(color_eyre::eyre::eyre!(
+                "The number of confirmations must be between 1 and the number of members ({})",
+                member_public_keys.len()
+            ));
+        }
+        let code = std::fs::read(&scope.file_path).wrap_err_with(|| {
+            format!("Failed to open or read the file: {:?}.", scope.file_path.0)
+        })?;
+        let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+
+        let mut actions = vec![
+            near_primitives::transaction::Action::DeployContract(
+                near_primitives::action::DeployContractAction { code },
+            ),
+            near_primitives::transaction::Action::FunctionCall(Box::new(
+                near_primitives::transaction::FunctionCallAction {
+                    method_name: "new".to_string(),
+                    args: serde_json::to_vec(&serde_json::json!({
+                        "num_confirmations": scope.num_confirmations,
+                    }))?,
+                    gas: near_primitives::gas::Gas::from_teragas(30),
+                    deposit: near_token::NearToken::from_yoctonear(0),
+                },
+            )),
+        ];
+        // The members are the access keys of the multisig account that may only call the multisig methods.
+        actions.extend(member_public_keys.into_iter().map(|public_key| {
+            near_primitives::transaction::Action::AddKey(Box::new(
+                near_primitives::transaction::AddKeyAction {
+                    public_key,
+                    access_key: near_primitives::account::AccessKey {
+                        nonce: 0,
+                        permission: near_primitives::account::AccessKeyPermission::FunctionCall(
+                            near_primitives::account::FunctionCallPermission {
+                                allowance: None,
+                                receiver_id: account_id.to_string(),
+                                method_names: MULTISIG_MEMBER_METHOD_NAMES
+                                    .iter()
+                                    .map(ToString::to_string)
+                                    .collect(),
+                            },
+                        ),
+                    },
+                },
+            ))
+        }));
+
+        let get_prepopulated_transaction_after_getting_network_callback: crate::commands::GetPrepopulatedTransactionAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let account_id = account_id.clone();
+
+                move |_network_config| {
+                    Ok(crate::commands::PrepopulatedTransaction {
+                        signer_id: account_id.clone(),
+                        receiver_id: account_id.clone(),
+                        actions: actions.clone(),
+                    })
+                }
+            });
+
+        let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback = std::sync::Arc::new({
+            let verbosity = previous_context.verbosity;
+
+            move |outcome_view, _network_config| {
+                if let near_primitives::views::FinalExecutionStatus::SuccessValue(_) = outcome_view.status
+                    && let crate::Verbosity::Interactive | crate::Verbosity::TeachMe = verbosity {
+                        tracing_indicatif::suspend_tracing_indicatif(|| {
+                            eprintln!(
+                                "The multisig contract is deployed to <{account_id}>.\nThe full access key you signed with can still bypass the multisig: delete it (`near account delete-keys`) once the members have checked their keys."
+                            );
+                        });
+                    }
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::commands::ActionContext {
+            global_context: previous_context,
+            interacting_with_account_ids: vec![scope.account_id.clone().into()],
+            get_prepopulated_transaction_after_getting_network_callback,
+            on_before_signing_callback: std::sync::Arc::new(
+                |_prepopulated_unsigned_transaction, _network_config| Ok(()),
+            ),
+            on_before_sending_transaction_callback: std::sync::Arc::new(
+                |_signed_transaction, _network_config| Ok(String::new()),
+            ),
+            on_after_sending_transaction_callback,
+            sign_as_delegate_action: false,
+            on_sending_delegate_action_callback: None,
+        }))
+    }
+}
+
+impl From<DeployMultisigContext> for crate::commands::ActionContext {
+    fn from(item: DeployMultisigContext) -> Self {
+        item.0
+    }
+}
+
+impl DeployMultisig {
+    pub fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id_from_used_account_list(
+            &context.config,
+            "Which account ID do you want to turn into a multisig account?",
+        )
+    }
+}
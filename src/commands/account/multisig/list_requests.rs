@@ -0,0 +1,145 @@
+use color_eyre::eyre::Context;
+use prettytable::Table;
+
+use crate::common::{CallResultExt, JsonRpcClientExt};
+use crate::transaction_signature_options::submit_multisig_request::multisig_request::{
+    MultisigRequest, RequestId,
+};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ListRequestsContext)]
+pub struct ListRequests {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the multisig account ID?
+    multisig_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct ListRequestsContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl ListRequestsContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<ListRequests as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let multisig_account_id: near_primitives::types::AccountId = scope.multisig_account_id.clone().into();
+            let output_format = previous_context.output_format;
+
+            move |network_config, block_reference| {
+                let view_multisig = |method_name: &str, args: serde_json::Value| {
+                    network_config
+                        .json_rpc_client()
+                        .blocking_call_view_function(
+                            &multisig_account_id,
+                            method_name,
+                            serde_json::to_vec(&args)?,
+                            block_reference.clone(),
+                        )
+                        .wrap_err_with(|| {
+                            format!("Failed to call `{method_name}` on the multisig account <{multisig_account_id}>")
+                        })
+                };
+
+                let num_confirmations: u32 = view_multisig("get_num_confirmations", serde_json::json!({}))?
+                    .parse_result_from_json()?;
+                let mut request_ids: Vec<RequestId> = view_multisig("list_request_ids", serde_json::json!({}))?
+                    .parse_result_from_json()?;
+                request_ids.sort_unstable();
+
+                let mut requests = Vec::new();
+                for request_id in request_ids {
+                    let request: MultisigRequest = view_multisig("get_request", serde_json::json!({ "request_id": request_id }))?
+                        .parse_result_from_json()?;
+                    let confirmations: Vec<String> = view_multisig("get_confirmations", serde_json::json!({ "request_id": request_id }))?
+                        .parse_result_from_json()?;
+                    requests.push(PendingRequest {
+                        request_id,
+                        request,
+                        confirmations,
+                    });
+                }
+
+                if let crate::common::OutputFormat::Json = output_format {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "num_confirmations": num_confirmations,
+                            "requests": requests,
+                        }))?
+                    );
+                    return Ok(());
+                }
+
+                if requests.is_empty() {
+                    eprintln!("\nThere are no pending requests on <{multisig_account_id}> (each request needs {num_confirmations} confirmations).");
+                    return Ok(());
+                }
+                let mut table = Table::new();
+                table.set_titles(prettytable::row![
+                    Fg->"Request",
+                    Fg->"Receiver",
+                    Fg->"Actions",
+                    Fg->"Confirmations"
+                ]);
+                for pending_request in &requests {
+                    table.add_row(prettytable::row![
+                        format!("#{}", pending_request.request_id),
+                        pending_request.request.receiver_id,
+                        pending_request
+                            .request
+                            .actions
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join("\n"),
+                        format!(
+                            "{}/{num_confirmations}\n{}",
+                            pending_request.confirmations.len(),
+                            pending_request.confirmations.join("\n")
+                        )
+                    ]);
+                }
+                table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+                table.printstd();
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.config,
+            interacting_with_account_ids: vec![scope.multisig_account_id.clone().into()],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<ListRequestsContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: ListRequestsContext) -> Self {
+        item.0
+    }
+}
+
+impl ListRequests {
+    pub fn input_multisig_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_non_signer_account_id_from_used_account_list(
+            &context.config.credentials_home_dir,
+            "What is the multisig account ID?",
+        )
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct PendingRequest {
+    request_id: RequestId,
+    #[serde(flatten)]
+    request: MultisigRequest,
+    /// The public keys of the members who confirmed the request
+    confirmations: Vec<String>,
+}
@@ -0,0 +1,39 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod add_request;
+mod confirm;
+mod deploy;
+mod list_requests;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+pub struct Multisig {
+    #[interactive_clap(subcommand)]
+    multisig_actions: MultisigActions,
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[non_exhaustive]
+/// What do you want to do with the multisig account?
+pub enum MultisigActions {
+    #[strum_discriminants(strum(
+        message = "deploy          - Deploy and initialize the multisig contract on an account"
+    ))]
+    /// Deploy and initialize the multisig contract on an account
+    Deploy(self::deploy::DeployMultisig),
+    #[strum_discriminants(strum(
+        message = "list-requests   - View the pending requests and their confirmations"
+    ))]
+    /// View the pending requests and their confirmations
+    ListRequests(self::list_requests::ListRequests),
+    #[strum_discriminants(strum(
+        message = "add-request     - Add a request built from transaction actions"
+    ))]
+    /// Add a request built from transaction actions (sign it with `submit-as-multisig-request`)
+    AddRequest(self::add_request::AddRequest),
+    #[strum_discriminants(strum(message = "confirm         - Confirm a pending request"))]
+    /// Confirm a pending request
+    Confirm(self::confirm::ConfirmRequest),
+}
@@ -0,0 +1,336 @@
+use std::io::IsTerminal;
+
+use color_eyre::eyre::eyre;
+use color_eyre::owo_colors::OwoColorize;
+use inquire::MultiSelect;
+use prettytable::Table;
+
+use crate::common::JsonRpcClientExt;
+
+/// The paths used by near CLI and most wallets (`m/44'/397'/0'` is the default one) and by Ledger.
+const DEFAULT_HD_PATH_TEMPLATES: [&str; 2] = ["m/44'/397'/{index}'", "m/44'/397'/0'/0'/{index}'"];
+const DEFAULT_INDEX_COUNT: u64 = 10;
+/// Every derived key is looked up on the network, so the scan is limited.
+const MAX_INDEX_COUNT: u64 = 1000;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = RecoverFromSeedPhraseContext)]
+pub struct RecoverFromSeedPhrase {
+    /// Enter the seed-phrase:
+    master_seed_phrase: String,
+    /// Comma-separated HD path templates to scan, `{index}` is replaced with the account index (default: "m/44'/397'/{index}',m/44'/397'/0'/0'/{index}'")
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    hd_path_templates: Option<crate::types::vec_string::VecString>,
+    /// The first account index to scan (default: 0)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    start_index: Option<u64>,
+    /// How many account indexes to scan for every HD path template (default: 10, at most 1000)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    index_count: Option<u64>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network::Network,
+}
+
+#[derive(Clone)]
+pub struct RecoverFromSeedPhraseContext(crate::network::NetworkContext);
+
+impl RecoverFromSeedPhraseContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<RecoverFromSeedPhrase as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.offline {
+            return Err(eyre!(
+                "Cannot recover the accounts in offline mode: the keys have to be looked up on the network"
+            ));
+        }
+        let hd_path_templates = scope
+            .hd_path_templates
+            .clone()
+            .map(Vec::from)
+            .unwrap_or_else(|| {
+                DEFAULT_HD_PATH_TEMPLATES
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            });
+        let hd_paths = hd_paths_to_scan(
+            &hd_path_templates,
+            scope.start_index.unwrap_or(0),
+            scope.index_count.unwrap_or(DEFAULT_INDEX_COUNT),
+        )?;
+        let key_pairs = hd_paths
+            .into_iter()
+            .map(|hd_path| {
+                crate::common::get_key_pair_properties_from_seed_phrase(
+                    hd_path,
+                    scope.master_seed_phrase.clone(),
+                )
+            })
+            .collect::<color_eyre::eyre::Result<Vec<_>>>()?;
+
+        let on_after_getting_network_callback: crate::network::OnAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let credentials_home_dir = previous_context.config.credentials_home_dir.clone();
+                let output_format = previous_context.output_format;
+
+                move |network_config| {
+                    let recovered_accounts = find_recovered_accounts(network_config, &key_pairs)?;
+                    display_recovered_accounts(&recovered_accounts, output_format, network_config)?;
+
+                    if recovered_accounts.is_empty()
+                        || output_format == crate::common::OutputFormat::Json
+                        || !std::io::stdin().is_terminal()
+                    {
+                        return Ok(());
+                    }
+                    let selected_accounts = MultiSelect::new(
+                        "Which accounts do you want to import? (press Space to select, Enter to confirm)",
+                        recovered_accounts,
+                    )
+                    .prompt()?;
+                    for recovered_account in selected_accounts {
+                        crate::common::update_used_account_list_as_signer(
+                            &credentials_home_dir,
+                            &recovered_account.account_id,
+                        );
+                        super::import_account::save_access_key(
+                            recovered_account.account_id,
+                            &serde_json::to_string(&recovered_account.key_pair_properties)?,
+                            &recovered_account.key_pair_properties.public_key_str,
+                            network_config.clone(),
+                            credentials_home_dir.clone(),
+                        )?;
+                    }
+                    Ok(())
+                }
+            });
+
+        Ok(Self(crate::network::NetworkContext {
+            config: previous_context.config,
+            interacting_with_account_ids: Vec::new(),
+            on_after_getting_network_callback,
+        }))
+    }
+}
+
+impl From<RecoverFromSeedPhraseContext> for crate::network::NetworkContext {
+    fn from(item: RecoverFromSeedPhraseContext) -> Self {
+        item.0
+    }
+}
+
+fn hd_paths_to_scan(
+    hd_path_templates: &[String],
+    start_index: u64,
+    index_count: u64,
+) -> color_eyre::eyre::Result<Vec<crate::types::slip10::BIP32Path>> {
+    if index_count == 0 {
+        return Err(eyre!(
+            "The number of account indexes to scan must be positive"
+        ));
+    }
+    if index_count > MAX_INDEX_COUNT {
+        return Err(eyre!(
+            "At most {MAX_INDEX_COUNT} account indexes can be scanned, but {index_count} were requested"
+        ));
+    }
+    let mut hd_paths: Vec<crate::types::slip10::BIP32Path> = Vec::new();
+    let mut known_hd_paths = std::collections::HashSet::new();
+    for hd_path_template in hd_path_templates {
+        if !hd_path_template.contains("{index}") {
+            return Err(eyre!(
+                "The HD path template <{hd_path_template}> has no `{{index}}` placeholder"
+            ));
+        }
+        for index in start_index..start_index.saturating_add(index_count) {
+            let hd_path: crate::types::slip10::BIP32Path = hd_path_template
+                .replace("{index}", &index.to_string())
+                .parse()
+                .map_err(|err| {
+                    eyre!("The HD path template <{hd_path_template}> is not valid for the account index {index}: {err}")
+                })?;
+            if known_hd_paths.insert(hd_path.to_string()) {
+                hd_paths.push(hd_path);
+            }
+        }
+    }
+    Ok(hd_paths)
+}
+
+#[derive(Debug, Clone)]
+struct RecoveredAccount {
+    account_id: near_primitives::types::AccountId,
+    key_pair_properties: crate::common::KeyPairProperties,
+}
+
+impl std::fmt::Display for RecoveredAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({})",
+            self.account_id, self.key_pair_properties.seed_phrase_hd_path
+        )
+    }
+}
+
+#[tracing::instrument(name = "Looking up the accounts of the derived keys ...", skip_all)]
+fn find_recovered_accounts(
+    network_config: &crate::config::NetworkConfig,
+    key_pairs: &[crate::common::KeyPairProperties],
+) -> color_eyre::eyre::Result<Vec<RecoveredAccount>> {
+    tracing::info!(target: "near_teach_me", "Looking up the accounts of the derived keys ...");
+    if network_config.fastnear_url.is_none() {
+        tracing::warn!(
+            "The <{}> network has no `fastnear_url`, so only the implicit accounts are checked",
+            network_config.network_name
+        );
+    }
+    let mut recovered_accounts = Vec::new();
+    for key_pair_properties in key_pairs {
+        let mut account_ids = std::collections::BTreeSet::new();
+        if let Some(fastnear_url) = &network_config.fastnear_url {
+            let public_key: near_crypto::PublicKey = key_pair_properties.public_key_str.parse()?;
+            match crate::common::fetch_account_ids_by_public_key(fastnear_url, &public_key) {
+                Ok(indexed_account_ids) => account_ids.extend(indexed_account_ids),
+                Err(err) => tracing::warn!(
+                    "Failed to look up the accounts of the key <{public_key}> ({}): {err}",
+                    key_pair_properties.seed_phrase_hd_path
+                ),
+            }
+        }
+        match is_implicit_account_exist(network_config, &key_pair_properties.implicit_account_id) {
+            Ok(true) => {
+                account_ids.insert(key_pair_properties.implicit_account_id.clone());
+            }
+            Ok(false) => {}
+            Err(err) => tracing::warn!("{err} ({})", key_pair_properties.seed_phrase_hd_path),
+        }
+        recovered_accounts.extend(account_ids.into_iter().map(|account_id| RecoveredAccount {
+            account_id,
+            key_pair_properties: key_pair_properties.clone(),
+        }));
+    }
+    Ok(recovered_accounts)
+}
+
+fn is_implicit_account_exist(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+) -> color_eyre::eyre::Result<bool> {
+    match network_config
+        .json_rpc_client()
+        .blocking_call_view_account(account_id, near_primitives::types::BlockReference::latest())
+        .map_err(|err| *err)
+    {
+        Ok(_) => Ok(true),
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+            ),
+        )) => Ok(false),
+        Err(err) => Err(eyre!(
+            "Failed to check the implicit account <{account_id}> on the <{}> network: {err}",
+            network_config.network_name
+        )),
+    }
+}
+
+fn display_recovered_accounts(
+    recovered_accounts: &[RecoveredAccount],
+    output_format: crate::common::OutputFormat,
+    network_config: &crate::config::NetworkConfig,
+) -> crate::CliResult {
+    if let crate::common::OutputFormat::Json = output_format {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "network": network_config.network_name,
+                "accounts": recovered_accounts
+                    .iter()
+                    .map(|recovered_account| serde_json::json!({
+                        "account_id": recovered_account.account_id,
+                        "seed_phrase_hd_path": recovered_account.key_pair_properties.seed_phrase_hd_path,
+                        "public_key": recovered_account.key_pair_properties.public_key_str,
+                    }))
+                    .collect::<Vec<_>>(),
+            }))?
+        );
+        return Ok(());
+    }
+
+    if recovered_accounts.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "No accounts were found on the <{}> network for the scanned HD paths.",
+                network_config.network_name
+            )
+            .yellow()
+        );
+        return Ok(());
+    }
+    let mut table = Table::new();
+    table.set_titles(prettytable::row![Fg=>"#", "Account Id", "HD Path", "Public Key"]);
+    for (index, recovered_account) in recovered_accounts.iter().enumerate() {
+        table.add_row(prettytable::row![
+            Fg->index + 1,
+            recovered_account.account_id,
+            recovered_account.key_pair_properties.seed_phrase_hd_path,
+            recovered_account.key_pair_properties.public_key_str
+        ]);
+    }
+    table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
+    table.printstd();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hd_paths_to_scan_expands_the_templates() {
+        let hd_path_templates = DEFAULT_HD_PATH_TEMPLATES
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let hd_paths = hd_paths_to_scan(&hd_path_templates, 0, 2)
+            .unwrap()
+            .into_iter()
+            .map(|hd_path| hd_path.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            hd_paths,
+            [
+                "m/44'/397'/0'",
+                "m/44'/397'/1'",
+                "m/44'/397'/0'/0'/0'",
+                "m/44'/397'/0'/0'/1'"
+            ]
+        );
+
+        assert!(hd_paths_to_scan(&["m/44'/397'/0'".to_string()], 0, 2).is_err());
+        assert!(hd_paths_to_scan(&hd_path_templates, 0, 0).is_err());
+        assert!(hd_paths_to_scan(&hd_path_templates, 0, MAX_INDEX_COUNT + 1).is_err());
+    }
+
+    #[test]
+    fn hd_paths_to_scan_skips_duplicates() {
+        let hd_path_templates = [
+            "m/44'/397'/{index}'".to_string(),
+            "m/44'/397'/{index}'".to_string(),
+        ];
+        let hd_paths = hd_paths_to_scan(&hd_path_templates, 5, 2)
+            .unwrap()
+            .into_iter()
+            .map(|hd_path| hd_path.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(hd_paths, ["m/44'/397'/5'", "m/44'/397'/6'"]);
+    }
+}
@@ -44,7 +44,7 @@ impl DepositArgs {
         loop {
             let receiver_account_id = if let Some(account_id) =
                 crate::common::input_signer_account_id_from_used_account_list(
-                    &context.global_context.config.credentials_home_dir,
+                    &context.global_context.config,
                     "Which account ID do you want to add a deposit to?",
                 )? {
                 account_id
@@ -188,7 +188,7 @@ impl SignerAccountId {
         context: &DepositArgsContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.global_context.config.credentials_home_dir,
+            &context.global_context.config,
             "What is the signer account ID?",
         )
     }
@@ -28,11 +28,24 @@ impl AccountContext {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback =
             std::sync::Arc::new({
                 let account_id = scope.account_id.clone();
+                let output_format = previous_context.global_context.output_format;
 
                 move |network_config, block_reference| {
                     let contract_account_id = (previous_context.get_contract_account_id)(network_config)?;
 
                     let storage_balance = get_storage_balance(network_config, &contract_account_id, &account_id, block_reference)?;
+                    if let crate::common::OutputFormat::Json = output_format {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&serde_json::json!({
+                                "account_id": account_id.to_string(),
+                                "contract_account_id": contract_account_id,
+                                "available": near_token::NearToken::from_yoctonear(storage_balance.available),
+                                "total": near_token::NearToken::from_yoctonear(storage_balance.total),
+                            }))?
+                        );
+                        return Ok(());
+                    }
                     eprintln!("storage balance for <{account_id}>:");
                     eprintln!(" {:<13} {:>10}   ({} [{:>28} yoctoNEAR])",
                         "available:",
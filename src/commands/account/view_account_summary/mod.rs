@@ -26,9 +26,10 @@ impl ViewAccountSummaryContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
+            let output_format = previous_context.output_format;
 
             move |network_config, block_reference| {
-                get_account_inquiry(&account_id, network_config, block_reference)
+                get_account_inquiry(&account_id, network_config, block_reference, output_format)
             }
         });
         Ok(Self(crate::network_view_at_block::ArgsForViewContext {
@@ -61,6 +62,7 @@ pub fn get_account_inquiry(
     account_id: &near_primitives::types::AccountId,
     network_config: &crate::config::NetworkConfig,
     block_reference: &near_primitives::types::BlockReference,
+    output_format: crate::common::OutputFormat,
 ) -> crate::CliResult {
     tracing::info!(target: "near_teach_me", "Receiving an inquiry about your account ...");
 
@@ -204,9 +206,8 @@ pub fn get_account_inquiry(
         &account_view,
         access_key_list.as_ref(),
         optional_account_profile.as_ref(),
-    );
-
-    Ok(())
+        output_format,
+    )
 }
 
 #[tracing::instrument(
@@ -28,6 +28,7 @@ impl ViewGasKeyNoncesContext {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let account_id: near_primitives::types::AccountId = scope.account_id.clone().into();
             let public_key: near_crypto::PublicKey = scope.public_key.clone().into();
+            let output_format = previous_context.output_format;
 
             move |network_config, block_reference| {
                 let gas_key_nonces = network_config
@@ -45,8 +46,11 @@ impl ViewGasKeyNoncesContext {
                     })?
                     .gas_key_nonces_view()?;
 
-                crate::common::display_gas_key_nonces(&public_key, &gas_key_nonces.nonces);
-                Ok(())
+                crate::common::display_gas_key_nonces(
+                    &public_key,
+                    &gas_key_nonces.nonces,
+                    output_format,
+                )
             }
         });
 
@@ -37,7 +37,7 @@ impl WithdrawFromGasKeyCommand {
         context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.config.credentials_home_dir,
+            &context.config,
             "Which account owns the gas key you want to withdraw from?",
         )
     }
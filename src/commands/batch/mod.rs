@@ -0,0 +1,39 @@
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+mod run;
+
+thread_local! {
+    static STEP_OUTPUTS: std::cell::RefCell<Option<std::collections::BTreeMap<String, String>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+/// Records a value produced by the currently running command (e.g. a transaction hash or
+/// a generated public key), so `near batch run` can substitute it into the following steps.
+/// Does nothing when the command is not executed as a batch step.
+pub fn record_step_output(name: &str, value: impl std::fmt::Display) {
+    STEP_OUTPUTS.with(|step_outputs| {
+        if let Some(step_outputs) = step_outputs.borrow_mut().as_mut() {
+            step_outputs.insert(name.to_string(), value.to_string());
+        }
+    });
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+pub struct BatchCommands {
+    #[interactive_clap(subcommand)]
+    batch_actions: BatchActions,
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = crate::GlobalContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+#[non_exhaustive]
+/// What do you want to do with a batch of commands?
+pub enum BatchActions {
+    #[strum_discriminants(strum(
+        message = "run     - Run the commands listed in a manifest file (TOML or YAML) one by one"
+    ))]
+    /// Run the commands listed in a manifest file (TOML or YAML) one by one
+    Run(self::run::RunBatch),
+}
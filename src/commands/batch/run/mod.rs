@@ -0,0 +1,354 @@
+use color_eyre::eyre::{ContextCompat, WrapErr};
+use color_eyre::owo_colors::OwoColorize;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = RunBatchContext)]
+pub struct RunBatch {
+    /// What is the location of the batch manifest file (path/to/batch.toml or path/to/batch.yaml)?
+    file_path: crate::types::path_buf::PathBuf,
+}
+
+#[derive(Debug, Clone)]
+pub struct RunBatchContext;
+
+impl RunBatchContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<RunBatch as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let manifest = BatchManifest::from_file(&scope.file_path.0)?;
+        let near_cli_exec_path = crate::common::get_near_exec_path();
+
+        let mut variables = manifest.vars.clone();
+        let mut failed_steps: Vec<String> = Vec::new();
+        let steps_count = manifest.steps.len();
+
+        for (index, step) in manifest.steps.iter().enumerate() {
+            let step_id = step
+                .id
+                .clone()
+                .unwrap_or_else(|| format!("step{}", index + 1));
+            let args = step
+                .args
+                .iter()
+                .map(|arg| substitute_variables(arg, &variables))
+                .collect::<color_eyre::eyre::Result<Vec<String>>>()
+                .wrap_err_with(|| format!("Failed to prepare the arguments of step <{step_id}>"));
+
+            // A step whose arguments cannot be prepared fails like a step whose command fails
+            let (result, step_outputs) = match args {
+                Ok(args) => {
+                    if let crate::Verbosity::Interactive | crate::Verbosity::TeachMe =
+                        previous_context.verbosity
+                    {
+                        eprintln!(
+                            "\n[{}/{steps_count}] {step_id}: {}",
+                            index + 1,
+                            shell_words::join(std::iter::once(&near_cli_exec_path).chain(&args))
+                                .yellow()
+                        );
+                    }
+                    run_step(&args, &previous_context)
+                }
+                Err(err) => (Err(err), std::collections::BTreeMap::new()),
+            };
+            for (name, value) in step_outputs {
+                variables.insert(format!("{step_id}.{name}"), value);
+            }
+            variables.insert(
+                format!("{step_id}.status"),
+                if result.is_ok() { "success" } else { "failure" }.to_string(),
+            );
+
+            if let Err(err) = result {
+                if !step.continue_on_error {
+                    return Err(err.wrap_err(format!(
+                        "Batch step <{step_id}> ({}/{steps_count}) failed",
+                        index + 1
+                    )));
+                }
+                tracing::warn!(
+                    parent: &tracing::Span::none(),
+                    "Batch step <{step_id}> failed, continuing because `continue_on_error` is set:{}",
+                    crate::common::indent_payload(&format!("\n{err:#}"))
+                );
+                failed_steps.push(step_id);
+            }
+        }
+
+        if let crate::Verbosity::Interactive | crate::Verbosity::TeachMe =
+            previous_context.verbosity
+        {
+            if failed_steps.is_empty() {
+                eprintln!("\nAll {steps_count} batch steps completed successfully.");
+            } else {
+                eprintln!(
+                    "\n{} of {steps_count} batch steps failed: {}",
+                    failed_steps.len(),
+                    failed_steps.join(", ")
+                );
+            }
+        }
+        Ok(Self)
+    }
+}
+
+/// A batch manifest, e.g.:
+///
+/// ```toml
+/// [vars]
+/// network = "testnet"
+///
+/// [[steps]]
+/// id = "create"
+/// args = ["account", "create-account", "sponsor-by-faucet-service", "sub.example.testnet", "autogenerate-new-keypair", "save-to-keychain", "network-config", "${network}", "create"]
+///
+/// [[steps]]
+/// args = ["account", "view-account-summary", "sub.example.testnet", "network-config", "${network}", "now"]
+/// continue_on_error = true
+/// ```
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BatchManifest {
+    #[serde(default)]
+    vars: std::collections::BTreeMap<String, String>,
+    steps: Vec<BatchStep>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct BatchStep {
+    /// Used to refer to the outputs of this step as `${<id>.<output>}` (default: `step<N>`)
+    id: Option<String>,
+    /// The command line arguments, the same as after `near` on the command line
+    args: Vec<String>,
+    #[serde(default)]
+    continue_on_error: bool,
+}
+
+impl BatchManifest {
+    fn from_file(file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read_to_string(file_path)
+            .wrap_err_with(|| format!("Error reading data from file: {}", file_path.display()))?;
+        match file_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some("yaml" | "yml") => serde_yaml::from_str(&data).wrap_err_with(|| {
+                format!(
+                    "Error parsing the batch manifest file: {}",
+                    file_path.display()
+                )
+            }),
+            _ => toml::from_str(&data).wrap_err_with(|| {
+                format!(
+                    "Error parsing the batch manifest file: {}",
+                    file_path.display()
+                )
+            }),
+        }
+    }
+}
+
+/// Replaces every `${name}` in the argument with the value of the variable.
+fn substitute_variables(
+    arg: &str,
+    variables: &std::collections::BTreeMap<String, String>,
+) -> color_eyre::eyre::Result<String> {
+    let mut result = String::with_capacity(arg.len());
+    let mut rest = arg;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let end = rest[start..]
+            .find('}')
+            .wrap_err_with(|| format!("Unterminated variable reference in argument <{arg}>"))?;
+        let name = &rest[start + 2..start + end];
+        let value = variables.get(name).wrap_err_with(|| {
+            format!(
+                "Unknown variable <{name}> in argument <{arg}>. Available variables: {}",
+                variables
+                    .keys()
+                    .map(String::as_str)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })?;
+        result.push_str(value);
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Runs a single step with the shared global context and returns its result together with
+/// the outputs recorded while it was running (see [`super::record_step_output`]).
+fn run_step(
+    args: &[String],
+    global_context: &crate::GlobalContext,
+) -> (crate::CliResult, std::collections::BTreeMap<String, String>) {
+    let previous_step_outputs = super::STEP_OUTPUTS.with(|step_outputs| {
+        step_outputs
+            .borrow_mut()
+            .replace(std::collections::BTreeMap::new())
+    });
+
+    let result = (|| {
+        let cli_top_level_command =
+            <crate::commands::CliTopLevelCommand as clap::Parser>::try_parse_from(
+                std::iter::once(crate::common::get_near_exec_path()).chain(args.iter().cloned()),
+            )
+            .map_err(|err| color_eyre::eyre::eyre!("{}", err.render()))?;
+        if let crate::commands::CliTopLevelCommand::Batch(_) = cli_top_level_command {
+            return Err(color_eyre::eyre::eyre!(
+                "A batch step cannot run another batch of commands"
+            ));
+        }
+
+        match <crate::commands::TopLevelCommand as interactive_clap::FromCli>::from_cli(
+            Some(cli_top_level_command),
+            global_context.clone(),
+        ) {
+            interactive_clap::ResultFromCli::Ok(_) => Ok(()),
+            interactive_clap::ResultFromCli::Back => Err(color_eyre::eyre::eyre!(
+                "The step went back to a previous command instead of completing"
+            )),
+            interactive_clap::ResultFromCli::Cancel(_) => {
+                Err(color_eyre::eyre::eyre!("The step was canceled"))
+            }
+            interactive_clap::ResultFromCli::Err(_, err) => Err(err),
+        }
+    })();
+
+    let step_outputs = super::STEP_OUTPUTS
+        .with(|step_outputs| {
+            std::mem::replace(&mut *step_outputs.borrow_mut(), previous_step_outputs)
+        })
+        .unwrap_or_default();
+
+    (result, step_outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substitute_variables_replaces_all_references() {
+        let variables = std::collections::BTreeMap::from([
+            ("network".to_string(), "testnet".to_string()),
+            ("create.transaction_hash".to_string(), "9fN4".to_string()),
+        ]);
+        assert_eq!(
+            substitute_variables("${network}-${create.transaction_hash}!", &variables).unwrap(),
+            "testnet-9fN4!"
+        );
+        assert_eq!(
+            substitute_variables("no variables", &variables).unwrap(),
+            "no variables"
+        );
+    }
+
+    #[test]
+    fn substitute_variables_fails_on_unknown_or_unterminated_references() {
+        let variables = std::collections::BTreeMap::new();
+        assert!(substitute_variables("${missing}", &variables).is_err());
+        assert!(substitute_variables("${unterminated", &variables).is_err());
+    }
+
+    #[test]
+    fn batch_manifest_is_parsed_from_toml() {
+        let manifest: BatchManifest = toml::from_str(
+            r#"
+            [vars]
+            network = "testnet"
+
+            [[steps]]
+            id = "view"
+            args = ["account", "view-account-summary", "example.testnet", "network-config", "${network}", "now"]
+
+            [[steps]]
+            args = ["tokens", "example.testnet", "view-near-balance", "network-config", "${network}", "now"]
+            continue_on_error = true
+            "#,
+        )
+        .unwrap();
+        assert_eq!(manifest.vars["network"], "testnet");
+        assert_eq!(manifest.steps.len(), 2);
+        assert_eq!(manifest.steps[0].id.as_deref(), Some("view"));
+        assert!(!manifest.steps[0].continue_on_error);
+        assert!(manifest.steps[1].continue_on_error);
+    }
+
+    fn global_context() -> crate::GlobalContext {
+        crate::GlobalContext {
+            config: crate::config::Config::default(),
+            offline: true,
+            verbosity: crate::Verbosity::Quiet,
+            output_format: crate::common::OutputFormat::Plaintext,
+            decode_with_abi: false,
+        }
+    }
+
+    fn run_batch(manifest: &str) -> color_eyre::eyre::Result<RunBatchContext> {
+        let manifest_dir = tempfile::tempdir().unwrap();
+        let manifest_path = manifest_dir.path().join("batch.toml");
+        std::fs::write(&manifest_path, manifest).unwrap();
+        RunBatchContext::from_previous_context(
+            global_context(),
+            &InteractiveClapContextScopeForRunBatch {
+                file_path: crate::types::path_buf::PathBuf(manifest_path),
+            },
+        )
+    }
+
+    #[test]
+    fn substitution_errors_fail_the_step() {
+        let err = run_batch(
+            r#"
+            [[steps]]
+            args = ["account", "view-account-summary", "${missing}"]
+            "#,
+        )
+        .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("Batch step <step1> (1/1) failed"),
+            "{err:#}"
+        );
+
+        // The failure is recorded in the status of the step and the batch goes on
+        let err = run_batch(
+            r#"
+            [[steps]]
+            args = ["account", "view-account-summary", "${missing}"]
+            continue_on_error = true
+
+            [[steps]]
+            args = ["batch", "run", "${step1.status}.toml"]
+            "#,
+        )
+        .unwrap_err();
+        let err = format!("{err:#}");
+        assert!(err.contains("Batch step <step2> (2/2) failed"), "{err}");
+        assert!(err.contains("cannot run another batch"), "{err}");
+    }
+
+    #[test]
+    fn run_step_rejects_nested_batches() {
+        let (result, step_outputs) = run_step(
+            &[
+                "batch".to_string(),
+                "run".to_string(),
+                "batch.toml".to_string(),
+            ],
+            &global_context(),
+        );
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("cannot run another batch")
+        );
+        assert!(step_outputs.is_empty());
+    }
+}
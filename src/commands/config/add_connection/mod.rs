@@ -52,6 +52,10 @@ pub struct AddNetworkConnection {
     #[interactive_clap(long)]
     #[interactive_clap(skip_default_input_arg)]
     nearblocks_url: Option<crate::types::url::Url>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// Comma-separated RPC endpoints to fail over to, in the order of priority
+    rpc_fallback_urls: Option<crate::types::vec_string::VecString>,
 }
 
 #[derive(Debug, Clone)]
@@ -59,10 +63,10 @@ pub struct AddNetworkConnectionContext;
 
 impl AddNetworkConnectionContext {
     pub fn from_previous_context(
-        previous_context: crate::GlobalContext,
+        _previous_context: crate::GlobalContext,
         scope: &<AddNetworkConnection as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let mut config = previous_context.config;
+        let mut config = crate::config::Config::get_user_config_toml()?;
         config.network_connection.insert(
             scope.connection_name.clone(),
             crate::config::NetworkConfig {
@@ -109,6 +113,21 @@ impl AddNetworkConnectionContext {
                     .nearblocks_url
                     .clone()
                     .map(|nearblocks_url| nearblocks_url.into()),
+                rpc_fallback_endpoints: scope
+                    .rpc_fallback_urls
+                    .clone()
+                    .map(|rpc_fallback_urls| rpc_fallback_urls.0)
+                    .unwrap_or_default()
+                    .iter()
+                    .zip(1..)
+                    .map(|(url, priority)| {
+                        Ok(crate::config::RpcEndpoint {
+                            url: url.parse()?,
+                            api_key: None,
+                            priority,
+                        })
+                    })
+                    .collect::<color_eyre::eyre::Result<_>>()?,
             },
         );
         eprintln!();
@@ -0,0 +1,158 @@
+use color_eyre::owo_colors::OwoColorize;
+use prettytable::Table;
+
+const PROBE_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = CheckConnectionsContext)]
+pub struct CheckConnections {
+    /// Check only this network connection (default: all network connections)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    connection_name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckConnectionsContext;
+
+impl CheckConnectionsContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<CheckConnections as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.offline {
+            return Err(color_eyre::eyre::eyre!(
+                "Cannot check network connections in offline mode"
+            ));
+        }
+        if let Some(connection_name) = &scope.connection_name
+            && !previous_context
+                .config
+                .network_connection
+                .contains_key(connection_name)
+        {
+            return Err(color_eyre::eyre::eyre!(
+                "Network connection \"{connection_name}\" not found"
+            ));
+        }
+
+        let probes = previous_context
+            .config
+            .network_connection
+            .iter()
+            .filter(|(connection_name, _)| {
+                scope
+                    .connection_name
+                    .as_ref()
+                    .is_none_or(|selected| selected == *connection_name)
+            })
+            .flat_map(|(connection_name, network_config)| {
+                network_config
+                    .rpc_endpoints()
+                    .into_iter()
+                    .map(|rpc_endpoint| {
+                        probe_rpc_endpoint(connection_name, network_config, &rpc_endpoint)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if let crate::common::OutputFormat::Json = previous_context.output_format {
+            println!("{}", serde_json::to_string_pretty(&probes)?);
+            return Ok(Self);
+        }
+
+        let mut table = Table::new();
+        table.set_titles(prettytable::row![
+            Fg->"Connection",
+            Fg->"RPC endpoint",
+            Fg->"Priority",
+            Fg->"Status",
+            Fg->"Latency",
+            Fg->"Latest block"
+        ]);
+        for probe in &probes {
+            table.add_row(prettytable::row![
+                probe.connection_name,
+                probe.url,
+                probe.priority,
+                match probe.status {
+                    "ok" => probe.status.green().to_string(),
+                    "stale" | "syncing" => probe.status.yellow().to_string(),
+                    _ => format!(
+                        "{}\n{}",
+                        probe.status.red(),
+                        textwrap::fill(probe.error.as_deref().unwrap_or_default(), 50)
+                    ),
+                },
+                probe
+                    .latency_ms
+                    .map(|latency_ms| format!("{latency_ms} ms"))
+                    .unwrap_or_default(),
+                match (probe.latest_block_height, probe.latest_block_age_secs) {
+                    (Some(height), Some(age)) => format!("#{height} ({age} s ago)"),
+                    _ => String::new(),
+                }
+            ]);
+        }
+        table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+        table.printstd();
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct RpcEndpointProbe {
+    connection_name: String,
+    network_name: String,
+    url: url::Url,
+    priority: u32,
+    /// `ok`, `stale`, `syncing` or `error`
+    status: &'static str,
+    latency_ms: Option<u128>,
+    latest_block_height: Option<near_primitives::types::BlockHeight>,
+    latest_block_age_secs: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+#[tracing::instrument(name = "Checking RPC endpoint", skip_all)]
+fn probe_rpc_endpoint(
+    connection_name: &str,
+    network_config: &crate::config::NetworkConfig,
+    rpc_endpoint: &crate::config::RpcEndpoint,
+) -> RpcEndpointProbe {
+    use tracing_indicatif::span_ext::IndicatifSpanExt;
+    tracing::Span::current().pb_set_message(rpc_endpoint.url.as_str());
+
+    let mut probe = RpcEndpointProbe {
+        connection_name: connection_name.to_string(),
+        network_name: network_config.network_name.clone(),
+        url: rpc_endpoint.url.clone(),
+        priority: rpc_endpoint.priority,
+        status: "error",
+        latency_ms: None,
+        latest_block_height: None,
+        latest_block_age_secs: None,
+        error: None,
+    };
+
+    let started_at = std::time::Instant::now();
+    match crate::config::rpc_failover::get_sync_status(rpc_endpoint, PROBE_TIMEOUT) {
+        Ok(sync_status) => {
+            probe.latency_ms = Some(started_at.elapsed().as_millis());
+            probe.latest_block_height = Some(sync_status.latest_block_height);
+            probe.latest_block_age_secs = Some(sync_status.latest_block_age_secs);
+            probe.status = if sync_status.syncing {
+                "syncing"
+            } else if sync_status.lag_reason().is_some() {
+                "stale"
+            } else {
+                "ok"
+            };
+        }
+        Err(err) => probe.error = Some(err.to_string()),
+    }
+    probe
+}
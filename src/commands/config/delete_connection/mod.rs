@@ -12,10 +12,10 @@ pub struct DeleteNetworkConnectionContext;
 
 impl DeleteNetworkConnectionContext {
     pub fn from_previous_context(
-        previous_context: crate::GlobalContext,
+        _previous_context: crate::GlobalContext,
         scope: &<DeleteNetworkConnection as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let mut config = previous_context.config;
+        let mut config = crate::config::Config::get_user_config_toml()?;
         config.network_connection.remove(&scope.connection_name);
         eprintln!();
         config.write_config_toml()?;
@@ -13,7 +13,6 @@ pub struct EditConnection {
 
 #[derive(Debug, Clone)]
 pub struct EditConnectionContext {
-    global_context: crate::GlobalContext,
     connection_name: String,
     network_config: crate::config::NetworkConfig,
 }
@@ -31,7 +30,6 @@ impl EditConnectionContext {
             .clone();
 
         Ok(Self {
-            global_context: previous_context,
             connection_name: scope.connection_name.clone(),
             network_config,
         })
@@ -68,13 +66,13 @@ impl ParameterContext {
         previous_context: EditConnectionContext,
         scope: &<Parameter as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let mut config = previous_context.global_context.config;
+        let mut config = crate::config::Config::get_user_config_toml()?;
         let Some(network_config) = config
             .network_connection
             .get_mut(&previous_context.connection_name)
         else {
             return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!(
-                "Network connection \"{}\" not found",
+                "Network connection \"{}\" not found in the user config (connections from a project-local near.toml have to be edited in that file)",
                 &previous_context.connection_name
             ));
         };
@@ -169,6 +167,17 @@ impl ParameterContext {
                     Some(scope.value.parse()?)
                 };
             }
+            "rpc_fallback_endpoints" => {
+                network_config.rpc_fallback_endpoints = if &scope.value == "null" {
+                    Vec::new()
+                } else {
+                    serde_json::from_str(&scope.value).map_err(|err| {
+                        color_eyre::eyre::eyre!(
+                            "Expected a JSON array like [{{\"url\": \"https://rpc.example.com/\", \"priority\": 1}}]: {err}"
+                        )
+                    })?
+                };
+            }
             _ => {
                 return color_eyre::eyre::Result::Err(color_eyre::eyre::eyre!(
                     "Configuration key <{}> not found",
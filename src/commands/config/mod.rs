@@ -2,6 +2,7 @@ use color_eyre::eyre::ContextCompat;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 mod add_connection;
+mod check_connections;
 mod delete_connection;
 mod edit_connection;
 
@@ -23,6 +24,11 @@ pub enum ConfigActions {
     ))]
     /// Show a list of network connections
     ShowConnections(ShowConnections),
+    #[strum_discriminants(strum(
+        message = "check-connections      - Check the latency and the latest block of every RPC endpoint"
+    ))]
+    /// Check the latency and the latest block of every RPC endpoint
+    CheckConnections(self::check_connections::CheckConnections),
     #[strum_discriminants(strum(message = "add-connection         - Add a network connection"))]
     /// Add a network connection
     AddConnection(self::add_connection::AddNetworkConnection),
@@ -47,13 +53,18 @@ impl ShowConnectionsContext {
         previous_context: crate::GlobalContext,
         _scope: &<ShowConnections as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let mut path_config_toml =
-            dirs::config_dir().wrap_err("Impossible to get your config dir!")?;
-        path_config_toml.push("near-cli/config.toml");
+        let path_config_toml =
+            crate::config::config_toml_path().wrap_err("Impossible to get your config dir!")?;
         eprintln!(
             "\nConfiguration data is stored in a file {:?}",
             path_config_toml
         );
+        if let Some(path_project_config_toml) = crate::config::project_config_toml_path() {
+            eprintln!(
+                "Project-local configuration is merged from a file {:?}",
+                path_project_config_toml
+            );
+        }
         let config_toml = toml::to_string(&previous_context.config)?;
         eprintln!("{}", config_toml);
         Ok(Self)
@@ -0,0 +1,161 @@
+//! Decoding of function return values with the result types declared in the contract ABI.
+
+use color_eyre::eyre::eyre;
+
+/// A return value decoded with the contract ABI.
+#[derive(Debug, Clone)]
+pub struct DecodedReturnValue {
+    /// The name of the declared result type (e.g. `U128` or `Option<Token>`).
+    pub type_name: String,
+    pub value: serde_json::Value,
+}
+
+/// Decodes the return value of the function with the result type declared in the ABI.
+/// Returns `None` if the ABI declares no result for the function.
+pub fn decode_return_value(
+    abi: &near_abi::AbiRoot,
+    function_name: &str,
+    return_value: &[u8],
+) -> color_eyre::eyre::Result<Option<DecodedReturnValue>> {
+    let Some(result_type) = abi
+        .body
+        .functions
+        .iter()
+        .find(|function| function.name == function_name)
+        .and_then(|function| function.result.as_ref())
+    else {
+        return Ok(None);
+    };
+    match result_type {
+        near_abi::AbiType::Json { type_schema } => {
+            let type_schema = serde_json::to_value(type_schema)?;
+            let root_schema = serde_json::to_value(&abi.body.root_schema)?;
+            let type_name = super::abi_schema::type_name(&type_schema);
+            let value = serde_json::from_slice::<serde_json::Value>(return_value)
+                .map_err(|_| {
+                    eyre!(
+                        "The return value of \"{function_name}\" is not JSON, but the contract ABI declares the JSON type `{type_name}`"
+                    )
+                })?;
+            let errors = super::abi_schema::validate(&value, &type_schema, &root_schema, "result");
+            if !errors.is_empty() {
+                return Err(eyre!(
+                    "The return value of \"{function_name}\" does not match the type `{type_name}` declared in the contract ABI:\n  {}",
+                    errors.join("\n  ")
+                ));
+            }
+            Ok(Some(DecodedReturnValue { type_name, value }))
+        }
+        #[cfg(feature = "decode_borsh")]
+        near_abi::AbiType::Borsh { type_schema } => {
+            use color_eyre::eyre::WrapErr;
+
+            let value = super::borsh_schema::decode(type_schema, return_value).wrap_err_with(|| {
+                format!(
+                    "The return value of \"{function_name}\" does not match the Borsh type declared in the contract ABI"
+                )
+            })?;
+            Ok(Some(DecodedReturnValue {
+                type_name: type_schema.declaration().clone(),
+                value,
+            }))
+        }
+        #[cfg(not(feature = "decode_borsh"))]
+        near_abi::AbiType::Borsh { .. } => Ok(None),
+    }
+}
+
+/// Fetches the contract ABI and decodes the return value of the function with it.
+/// Returns `None` if the contract has no ABI or the ABI declares no result for the function.
+pub fn decode_function_return_value(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+    block_reference: &near_primitives::types::BlockReference,
+    return_value: &[u8],
+) -> color_eyre::eyre::Result<Option<DecodedReturnValue>> {
+    let abi = match tokio::runtime::Runtime::new()?.block_on(super::get_contract_abi(
+        &network_config.json_rpc_client(),
+        block_reference,
+        contract_account_id,
+    )) {
+        Ok(abi) => abi,
+        Err(super::FetchAbiError::AbiNotSupported) => return Ok(None),
+        Err(err) => {
+            tracing::warn!("The return value was not decoded with the contract ABI: {err}");
+            return Ok(None);
+        }
+    };
+    decode_return_value(&abi, function_name, return_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abi() -> near_abi::AbiRoot {
+        serde_json::from_value(serde_json::json!({
+            "schema_version": "0.4.0",
+            "metadata": {},
+            "body": {
+                "functions": [
+                    {
+                        "name": "ft_balance_of",
+                        "kind": "view",
+                        "result": {
+                            "serialization_type": "json",
+                            "type_schema": {"$ref": "#/definitions/U128"}
+                        }
+                    },
+                    {
+                        "name": "get_counter",
+                        "kind": "view",
+                        "result": {
+                            "serialization_type": "borsh",
+                            "type_schema": {
+                                "declaration": "u64",
+                                "definitions": {}
+                            }
+                        }
+                    },
+                    {"name": "ping", "kind": "call"}
+                ],
+                "root_schema": {
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "title": "String",
+                    "type": "string",
+                    "definitions": {"U128": {"type": "string"}}
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn decodes_return_values_with_the_abi() {
+        let decoded = decode_return_value(&abi(), "ft_balance_of", b"\"100\"")
+            .unwrap()
+            .unwrap();
+        assert_eq!(decoded.type_name, "U128");
+        assert_eq!(decoded.value, serde_json::json!("100"));
+
+        #[cfg(feature = "decode_borsh")]
+        {
+            let decoded = decode_return_value(&abi(), "get_counter", &7_u64.to_le_bytes())
+                .unwrap()
+                .unwrap();
+            assert_eq!(decoded.type_name, "u64");
+            assert_eq!(decoded.value, serde_json::json!(7));
+        }
+
+        assert!(decode_return_value(&abi(), "ping", b"").unwrap().is_none());
+    }
+
+    #[test]
+    fn fails_on_mismatching_return_values() {
+        assert!(decode_return_value(&abi(), "ft_balance_of", b"100").is_err());
+        assert!(decode_return_value(&abi(), "ft_balance_of", b"not json").is_err());
+        #[cfg(feature = "decode_borsh")]
+        assert!(decode_return_value(&abi(), "get_counter", &[7, 0, 0]).is_err());
+    }
+}
@@ -0,0 +1,566 @@
+//! A JSON Schema validator for the schemas found in contract ABIs.
+//!
+//! Only the subset of JSON Schema that `schemars` (and thus `near-sdk`) generates is supported:
+//! `$ref` to `#/definitions/...`, `type`, `format`, `enum`, `const`, `allOf`/`anyOf`/`oneOf`,
+//! object `properties`/`required`/`additionalProperties`, array `items` and numeric/length limits.
+
+/// The `near-sdk` JSON types that are (de)serialized as decimal strings.
+const DECIMAL_STRING_DEFINITIONS: [&str; 4] = ["U128", "U64", "I128", "I64"];
+
+/// Resolves a `$ref` to the root schema definitions. Returns the schema itself otherwise.
+pub fn resolve<'a>(
+    schema: &'a serde_json::Value,
+    root_schema: &'a serde_json::Value,
+) -> color_eyre::eyre::Result<&'a serde_json::Value> {
+    let mut schema = schema;
+    // The definitions may refer to each other; a limit guards against reference cycles.
+    for _ in 0..32 {
+        match definition_name(schema) {
+            Some(name) => {
+                schema = root_schema
+                    .get("definitions")
+                    .and_then(|definitions| definitions.get(name))
+                    .ok_or_else(|| {
+                        color_eyre::eyre::eyre!("The ABI has no definition for \"{name}\"")
+                    })?;
+            }
+            None => return Ok(schema),
+        }
+    }
+    Err(color_eyre::eyre::eyre!(
+        "The ABI definitions refer to each other in a cycle"
+    ))
+}
+
+/// The name of the definition the schema refers to (e.g. `U128` for `#/definitions/U128`).
+pub fn definition_name(schema: &serde_json::Value) -> Option<&str> {
+    schema
+        .get("$ref")
+        .and_then(serde_json::Value::as_str)
+        .and_then(|reference| reference.strip_prefix("#/definitions/"))
+}
+
+/// Whether the schema is a `near-sdk` integer type that is passed as a decimal string (e.g. `U128`).
+pub fn is_decimal_string(schema: &serde_json::Value) -> bool {
+    definition_name(schema).is_some_and(|name| DECIMAL_STRING_DEFINITIONS.contains(&name))
+}
+
+/// `Option<T>` is described either as `"type": [T, "null"]` or as `"anyOf": [T, {"type": "null"}]`.
+pub fn nullable_inner_schema(schema: &serde_json::Value) -> Option<serde_json::Value> {
+    if let Some(types) = schema.get("type").and_then(serde_json::Value::as_array)
+        && types.iter().any(|value_type| value_type == "null")
+    {
+        let non_null_types = types
+            .iter()
+            .filter(|value_type| *value_type != "null")
+            .cloned()
+            .collect::<Vec<_>>();
+        let mut inner_schema = schema.clone();
+        inner_schema["type"] = match non_null_types.as_slice() {
+            [value_type] => value_type.clone(),
+            _ => serde_json::Value::Array(non_null_types),
+        };
+        return Some(inner_schema);
+    }
+    let any_of = schema.get("anyOf").and_then(serde_json::Value::as_array)?;
+    match any_of.as_slice() {
+        [inner_schema, null_schema] | [null_schema, inner_schema]
+            if null_schema.get("type").and_then(serde_json::Value::as_str) == Some("null") =>
+        {
+            Some(inner_schema.clone())
+        }
+        _ => None,
+    }
+}
+
+/// A short Rust-like name of the type the schema describes (e.g. `Option<U128>` or `Vec<Token>`).
+pub fn type_name(schema: &serde_json::Value) -> String {
+    if let Some(name) = definition_name(schema) {
+        return name.to_string();
+    }
+    if let Some(inner_schema) = nullable_inner_schema(schema) {
+        return format!("Option<{}>", type_name(&inner_schema));
+    }
+    match schema.get("type").and_then(serde_json::Value::as_str) {
+        Some("array") => match schema.get("items") {
+            Some(serde_json::Value::Array(items)) => format!(
+                "({})",
+                items.iter().map(type_name).collect::<Vec<_>>().join(", ")
+            ),
+            Some(items) => format!("Vec<{}>", type_name(items)),
+            None => "Vec".to_string(),
+        },
+        Some("object") => match schema.get("additionalProperties") {
+            Some(values) if values.is_object() => {
+                format!("HashMap<String, {}>", type_name(values))
+            }
+            _ => "object".to_string(),
+        },
+        Some("integer") => integer_format_name(schema).unwrap_or("integer").to_string(),
+        Some(value_type) => value_type.to_string(),
+        None if schema.as_bool() == Some(true)
+            || schema.as_object().is_some_and(|schema| schema.is_empty()) =>
+        {
+            "any".to_string()
+        }
+        None => "value".to_string(),
+    }
+}
+
+fn integer_format_name(schema: &serde_json::Value) -> Option<&'static str> {
+    match schema.get("format").and_then(serde_json::Value::as_str)? {
+        "uint8" => Some("u8"),
+        "uint16" => Some("u16"),
+        "uint32" => Some("u32"),
+        "uint64" => Some("u64"),
+        "uint" => Some("usize"),
+        "int8" => Some("i8"),
+        "int16" => Some("i16"),
+        "int32" => Some("i32"),
+        "int64" => Some("i64"),
+        "int" => Some("isize"),
+        _ => None,
+    }
+}
+
+/// An optional single `-` followed by decimal digits.
+fn is_decimal_integer(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// Validates the value against the schema. Returns the list of problems (empty if the value is valid).
+pub fn validate(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    root_schema: &serde_json::Value,
+    path: &str,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    validate_into(value, schema, root_schema, path, &mut errors);
+    errors
+}
+
+fn validate_into(
+    value: &serde_json::Value,
+    schema: &serde_json::Value,
+    root_schema: &serde_json::Value,
+    path: &str,
+    errors: &mut Vec<String>,
+) {
+    if let Some(definition) = definition_name(schema)
+        && DECIMAL_STRING_DEFINITIONS.contains(&definition)
+    {
+        let Some(decimal) = value.as_str().filter(|value| is_decimal_integer(value)) else {
+            errors.push(format!(
+                "{path}: expected an integer as a decimal string (e.g. \"1000\"), found {}",
+                describe(value)
+            ));
+            return;
+        };
+        let is_in_range = match definition {
+            "U128" => decimal.parse::<u128>().is_ok(),
+            "U64" => decimal.parse::<u64>().is_ok(),
+            "I128" => decimal.parse::<i128>().is_ok(),
+            _ => decimal.parse::<i64>().is_ok(),
+        };
+        if !is_in_range {
+            errors.push(format!("{path}: {decimal} does not fit into {definition}"));
+            return;
+        }
+    }
+    let schema = match resolve(schema, root_schema) {
+        Ok(schema) => schema,
+        Err(err) => {
+            errors.push(format!("{path}: {err}"));
+            return;
+        }
+    };
+    let schema = match schema {
+        serde_json::Value::Bool(true) => return,
+        serde_json::Value::Bool(false) => {
+            errors.push(format!("{path}: no value is allowed here"));
+            return;
+        }
+        serde_json::Value::Object(schema) => schema,
+        _ => return,
+    };
+
+    if let Some(all_of) = schema.get("allOf").and_then(serde_json::Value::as_array) {
+        for subschema in all_of {
+            validate_into(value, subschema, root_schema, path, errors);
+        }
+    }
+    if let Some(any_of) = schema.get("anyOf").and_then(serde_json::Value::as_array)
+        && !any_of
+            .iter()
+            .any(|subschema| validate(value, subschema, root_schema, path).is_empty())
+    {
+        errors.push(format!(
+            "{path}: {} does not match any of the allowed types",
+            describe(value)
+        ));
+    }
+    if let Some(one_of) = schema.get("oneOf").and_then(serde_json::Value::as_array) {
+        let matching_count = one_of
+            .iter()
+            .filter(|subschema| validate(value, subschema, root_schema, path).is_empty())
+            .count();
+        if matching_count != 1 {
+            errors.push(format!(
+                "{path}: {} does not match exactly one of the variants",
+                describe(value)
+            ));
+        }
+    }
+    if let Some(allowed_values) = schema.get("enum").and_then(serde_json::Value::as_array)
+        && !allowed_values.contains(value)
+    {
+        errors.push(format!(
+            "{path}: {value} is not one of {}",
+            allowed_values
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    if let Some(constant) = schema.get("const")
+        && constant != value
+    {
+        errors.push(format!("{path}: expected {constant}, found {value}"));
+    }
+
+    if let Some(types) = schema.get("type") {
+        let types: Vec<&str> = match types {
+            serde_json::Value::String(value_type) => vec![value_type.as_str()],
+            serde_json::Value::Array(types) => {
+                types.iter().filter_map(serde_json::Value::as_str).collect()
+            }
+            _ => vec![],
+        };
+        if !types.is_empty() && !types.iter().any(|value_type| has_type(value, value_type)) {
+            errors.push(format!(
+                "{path}: expected {}, found {}",
+                types.join(" or "),
+                describe(value)
+            ));
+            return;
+        }
+    }
+
+    match value {
+        serde_json::Value::Number(number) => {
+            if let Some(format) = schema.get("format").and_then(serde_json::Value::as_str)
+                && let Some((min, max)) = integer_format_range(format)
+                && !number
+                    .as_i64()
+                    .map(i128::from)
+                    .or_else(|| number.as_u64().map(i128::from))
+                    .is_some_and(|number| (min..=max).contains(&number))
+            {
+                errors.push(format!("{path}: {number} does not fit into {format}"));
+            }
+            if let (Some(minimum), Some(number)) = (
+                schema.get("minimum").and_then(serde_json::Value::as_f64),
+                number.as_f64(),
+            ) && number < minimum
+            {
+                errors.push(format!("{path}: {number} is less than {minimum}"));
+            }
+            if let (Some(maximum), Some(number)) = (
+                schema.get("maximum").and_then(serde_json::Value::as_f64),
+                number.as_f64(),
+            ) && number > maximum
+            {
+                errors.push(format!("{path}: {number} is greater than {maximum}"));
+            }
+        }
+        serde_json::Value::String(string) => {
+            let length = string.chars().count() as u64;
+            if schema
+                .get("minLength")
+                .and_then(serde_json::Value::as_u64)
+                .is_some_and(|min_length| length < min_length)
+                || schema
+                    .get("maxLength")
+                    .and_then(serde_json::Value::as_u64)
+                    .is_some_and(|max_length| length > max_length)
+            {
+                errors.push(format!("{path}: the length of {value} is out of range"));
+            }
+        }
+        serde_json::Value::Array(items) => {
+            let length = items.len() as u64;
+            if schema
+                .get("minItems")
+                .and_then(serde_json::Value::as_u64)
+                .is_some_and(|min_items| length < min_items)
+                || schema
+                    .get("maxItems")
+                    .and_then(serde_json::Value::as_u64)
+                    .is_some_and(|max_items| length > max_items)
+            {
+                errors.push(format!(
+                    "{path}: the number of items ({length}) is out of range"
+                ));
+            }
+            match schema.get("items") {
+                Some(serde_json::Value::Array(item_schemas)) => {
+                    for (index, (item, item_schema)) in items.iter().zip(item_schemas).enumerate() {
+                        validate_into(
+                            item,
+                            item_schema,
+                            root_schema,
+                            &format!("{path}[{index}]"),
+                            errors,
+                        );
+                    }
+                }
+                Some(item_schema) => {
+                    for (index, item) in items.iter().enumerate() {
+                        validate_into(
+                            item,
+                            item_schema,
+                            root_schema,
+                            &format!("{path}[{index}]"),
+                            errors,
+                        );
+                    }
+                }
+                None => {}
+            }
+        }
+        serde_json::Value::Object(object) => {
+            let properties = schema
+                .get("properties")
+                .and_then(serde_json::Value::as_object);
+            if let Some(required) = schema.get("required").and_then(serde_json::Value::as_array) {
+                for name in required.iter().filter_map(serde_json::Value::as_str) {
+                    if !object.contains_key(name) {
+                        errors.push(format!("{path}.{name}: the field is required"));
+                    }
+                }
+            }
+            for (name, field_value) in object {
+                let field_path = format!("{path}.{name}");
+                match (
+                    properties.and_then(|properties| properties.get(name)),
+                    schema.get("additionalProperties"),
+                ) {
+                    (Some(field_schema), _) => {
+                        validate_into(field_value, field_schema, root_schema, &field_path, errors)
+                    }
+                    (None, Some(serde_json::Value::Bool(false))) => {
+                        errors.push(format!("{field_path}: unknown field"))
+                    }
+                    (None, Some(additional_schema)) => validate_into(
+                        field_value,
+                        additional_schema,
+                        root_schema,
+                        &field_path,
+                        errors,
+                    ),
+                    (None, None) => {}
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn has_type(value: &serde_json::Value, value_type: &str) -> bool {
+    match value_type {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => true,
+    }
+}
+
+fn integer_format_range(format: &str) -> Option<(i128, i128)> {
+    match format {
+        "uint8" => Some((0, u8::MAX.into())),
+        "uint16" => Some((0, u16::MAX.into())),
+        "uint32" => Some((0, u32::MAX.into())),
+        "uint64" | "uint" => Some((0, u64::MAX.into())),
+        "int8" => Some((i8::MIN.into(), i8::MAX.into())),
+        "int16" => Some((i16::MIN.into(), i16::MAX.into())),
+        "int32" => Some((i32::MIN.into(), i32::MAX.into())),
+        "int64" | "int" => Some((i64::MIN.into(), i64::MAX.into())),
+        _ => None,
+    }
+}
+
+fn describe(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "null".to_string(),
+        serde_json::Value::Bool(_) => format!("boolean {value}"),
+        serde_json::Value::Number(_) => format!("number {value}"),
+        serde_json::Value::String(_) => format!("string {value}"),
+        serde_json::Value::Array(_) => "an array".to_string(),
+        serde_json::Value::Object(_) => "an object".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root_schema() -> serde_json::Value {
+        serde_json::json!({
+            "definitions": {
+                "U128": { "type": "string" },
+                "Metadata": {
+                    "type": "object",
+                    "required": ["title"],
+                    "properties": {
+                        "title": { "type": "string" },
+                        "copies": { "type": ["integer", "null"], "format": "uint32", "minimum": 0.0 }
+                    }
+                },
+                "Color": { "type": "string", "enum": ["Red", "Green"] }
+            }
+        })
+    }
+
+    #[test]
+    fn accepts_valid_values() {
+        let root_schema = root_schema();
+        assert!(
+            validate(
+                &serde_json::json!({ "title": "NFT", "copies": null }),
+                &serde_json::json!({ "$ref": "#/definitions/Metadata" }),
+                &root_schema,
+                "metadata",
+            )
+            .is_empty()
+        );
+        assert!(
+            validate(
+                &serde_json::json!("1000000000000000000000000"),
+                &serde_json::json!({ "$ref": "#/definitions/U128" }),
+                &root_schema,
+                "amount",
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn reports_invalid_values() {
+        let root_schema = root_schema();
+        assert_eq!(
+            validate(
+                &serde_json::json!(1000),
+                &serde_json::json!({ "$ref": "#/definitions/U128" }),
+                &root_schema,
+                "amount",
+            ),
+            vec![
+                "amount: expected an integer as a decimal string (e.g. \"1000\"), found number 1000"
+            ]
+        );
+        assert_eq!(
+            validate(
+                &serde_json::json!({ "copies": 5000000000u64 }),
+                &serde_json::json!({ "$ref": "#/definitions/Metadata" }),
+                &root_schema,
+                "metadata",
+            ),
+            vec![
+                "metadata.title: the field is required",
+                "metadata.copies: 5000000000 does not fit into uint32"
+            ]
+        );
+        assert_eq!(
+            validate(
+                &serde_json::json!("Blue"),
+                &serde_json::json!({ "$ref": "#/definitions/Color" }),
+                &root_schema,
+                "color",
+            ),
+            vec!["color: \"Blue\" is not one of \"Red\", \"Green\""]
+        );
+    }
+
+    #[test]
+    fn reports_invalid_decimal_strings() {
+        let root_schema = serde_json::json!({
+            "definitions": {
+                "U128": { "type": "string" },
+                "U64": { "type": "string" },
+                "I64": { "type": "string" }
+            }
+        });
+        let validate_decimal = |value: &str, definition: &str| {
+            validate(
+                &serde_json::json!(value),
+                &serde_json::json!({ "$ref": format!("#/definitions/{definition}") }),
+                &root_schema,
+                "amount",
+            )
+        };
+        assert_eq!(
+            validate_decimal("-1", "U128"),
+            vec!["amount: -1 does not fit into U128"]
+        );
+        assert_eq!(
+            validate_decimal("-1", "U64"),
+            vec!["amount: -1 does not fit into U64"]
+        );
+        assert_eq!(
+            validate_decimal("18446744073709551616", "U64"),
+            vec!["amount: 18446744073709551616 does not fit into U64"]
+        );
+        assert!(validate_decimal("18446744073709551615", "U64").is_empty());
+        assert_eq!(
+            validate_decimal("--5", "I64"),
+            vec![
+                "amount: expected an integer as a decimal string (e.g. \"1000\"), found string \"--5\""
+            ]
+        );
+        assert!(validate_decimal("-5", "I64").is_empty());
+    }
+
+    #[test]
+    fn finds_the_inner_schema_of_optional_values() {
+        assert_eq!(
+            nullable_inner_schema(&serde_json::json!({"type": ["integer", "null"]})),
+            Some(serde_json::json!({"type": "integer"}))
+        );
+        assert_eq!(
+            nullable_inner_schema(&serde_json::json!({
+                "anyOf": [{"$ref": "#/definitions/U128"}, {"type": "null"}]
+            })),
+            Some(serde_json::json!({"$ref": "#/definitions/U128"}))
+        );
+        assert_eq!(
+            nullable_inner_schema(&serde_json::json!({"type": "string"})),
+            None
+        );
+    }
+
+    #[test]
+    fn names_types() {
+        assert_eq!(
+            type_name(&serde_json::json!({"$ref": "#/definitions/U128"})),
+            "U128"
+        );
+        assert_eq!(
+            type_name(&serde_json::json!({"type": ["integer", "null"], "format": "uint32"})),
+            "Option<u32>"
+        );
+        assert_eq!(
+            type_name(&serde_json::json!({
+                "type": "array",
+                "items": {"$ref": "#/definitions/Token"}
+            })),
+            "Vec<Token>"
+        );
+    }
+}
@@ -0,0 +1,321 @@
+//! Decodes Borsh-serialized data into JSON with the help of a Borsh schema
+//! (as found in contract ABIs for functions with Borsh parameters or results).
+
+use borsh::schema::{BorshSchemaContainer, Declaration, Definition, Fields};
+use color_eyre::eyre::eyre;
+
+/// Nested types deeper than this are considered a broken (recursive) schema.
+const MAX_DEPTH: usize = 128;
+
+/// Decodes the whole `data` as a value of the schema type. Trailing bytes are an error.
+pub fn decode(
+    schema: &BorshSchemaContainer,
+    data: &[u8],
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let mut input = data;
+    let value = decode_declaration(schema, schema.declaration(), &mut input, 0)?;
+    if !input.is_empty() {
+        return Err(eyre!(
+            "The data does not match the Borsh type `{}`: {} bytes are left after decoding",
+            schema.declaration(),
+            input.len()
+        ));
+    }
+    Ok(value)
+}
+
+fn decode_declaration(
+    schema: &BorshSchemaContainer,
+    declaration: &Declaration,
+    input: &mut &[u8],
+    depth: usize,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    if depth > MAX_DEPTH {
+        return Err(eyre!(
+            "The Borsh type `{}` is nested too deeply",
+            schema.declaration()
+        ));
+    }
+    let value = match declaration.as_str() {
+        "()" => serde_json::Value::Null,
+        "bool" => match read_bytes::<1>(input, declaration)? {
+            [0] => serde_json::Value::Bool(false),
+            [1] => serde_json::Value::Bool(true),
+            [byte] => return Err(eyre!("Invalid `bool` value: {byte}")),
+        },
+        "u8" => u8::from_le_bytes(read_bytes(input, declaration)?).into(),
+        "u16" => u16::from_le_bytes(read_bytes(input, declaration)?).into(),
+        "u32" => u32::from_le_bytes(read_bytes(input, declaration)?).into(),
+        "u64" => u64::from_le_bytes(read_bytes(input, declaration)?).into(),
+        "i8" => i8::from_le_bytes(read_bytes(input, declaration)?).into(),
+        "i16" => i16::from_le_bytes(read_bytes(input, declaration)?).into(),
+        "i32" => i32::from_le_bytes(read_bytes(input, declaration)?).into(),
+        "i64" => i64::from_le_bytes(read_bytes(input, declaration)?).into(),
+        // 128-bit integers do not fit into JSON numbers, `near-sdk` passes them as strings too.
+        "u128" => u128::from_le_bytes(read_bytes(input, declaration)?)
+            .to_string()
+            .into(),
+        "i128" => i128::from_le_bytes(read_bytes(input, declaration)?)
+            .to_string()
+            .into(),
+        "f32" => float_to_json(f32::from_le_bytes(read_bytes(input, declaration)?).into())?,
+        "f64" => float_to_json(f64::from_le_bytes(read_bytes(input, declaration)?))?,
+        _ => {
+            let definition = schema.get_definition(declaration).ok_or_else(|| {
+                eyre!("The Borsh schema has no definition for the type `{declaration}`")
+            })?;
+            decode_definition(schema, declaration, definition, input, depth)?
+        }
+    };
+    Ok(value)
+}
+
+fn decode_definition(
+    schema: &BorshSchemaContainer,
+    declaration: &Declaration,
+    definition: &Definition,
+    input: &mut &[u8],
+    depth: usize,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    match definition {
+        // Primitives unknown to Borsh itself (e.g. `NonZeroU32`) are shown as hex.
+        Definition::Primitive(size) => {
+            Ok(hex::encode(take(input, usize::from(*size), declaration)?).into())
+        }
+        Definition::Sequence {
+            length_width,
+            length_range,
+            elements,
+        } => {
+            let length = match length_width {
+                0 if length_range.start() == length_range.end() => *length_range.start(),
+                0 => {
+                    return Err(eyre!(
+                        "The length of the Borsh type `{declaration}` is not defined by the schema"
+                    ));
+                }
+                width @ 1..=8 => {
+                    let mut length_bytes = [0; 8];
+                    length_bytes[..usize::from(*width)].copy_from_slice(take(
+                        input,
+                        usize::from(*width),
+                        declaration,
+                    )?);
+                    u64::from_le_bytes(length_bytes)
+                }
+                width => {
+                    return Err(eyre!(
+                        "Invalid length width {width} of the Borsh type `{declaration}`"
+                    ));
+                }
+            };
+            if !length_range.contains(&length) {
+                return Err(eyre!(
+                    "The length {length} of `{declaration}` is out of the allowed range {length_range:?}"
+                ));
+            }
+            if declaration == "String" {
+                let bytes = take(input, usize::try_from(length)?, declaration)?;
+                return Ok(String::from_utf8(bytes.to_vec())
+                    .map_err(|_| eyre!("The `String` value is not valid UTF-8"))?
+                    .into());
+            }
+            // Every element takes at least one byte (zero-size elements are rejected below),
+            // so a length that exceeds the remaining data is invalid.
+            if length > input.len() as u64 {
+                return Err(eyre!(
+                    "The length {length} of `{declaration}` exceeds the {} bytes of the remaining data",
+                    input.len()
+                ));
+            }
+            let mut values = Vec::new();
+            for _ in 0..length {
+                let input_len = input.len();
+                values.push(decode_declaration(schema, elements, input, depth + 1)?);
+                if input.len() == input_len {
+                    return Err(eyre!(
+                        "The Borsh type `{declaration}` is a sequence of zero-size elements, which cannot be decoded"
+                    ));
+                }
+            }
+            Ok(serde_json::Value::Array(values))
+        }
+        Definition::Tuple { elements } => elements
+            .iter()
+            .map(|element| decode_declaration(schema, element, input, depth + 1))
+            .collect::<color_eyre::eyre::Result<Vec<_>>>()
+            .map(serde_json::Value::Array),
+        Definition::Enum {
+            tag_width,
+            variants,
+        } => {
+            let width = usize::from(*tag_width);
+            if !(1..=8).contains(&width) {
+                return Err(eyre!(
+                    "The Borsh type `{declaration}` is an untagged union, which cannot be decoded"
+                ));
+            }
+            let mut tag_bytes = [0; 8];
+            tag_bytes[..width].copy_from_slice(take(input, width, declaration)?);
+            let tag = i64::from_le_bytes(tag_bytes);
+            let (_, variant_name, variant_declaration) = variants
+                .iter()
+                .find(|(discriminant, _, _)| *discriminant == tag)
+                .ok_or_else(|| eyre!("Invalid `{declaration}` variant: {tag}"))?;
+            let value = decode_declaration(schema, variant_declaration, input, depth + 1)?;
+            if declaration.starts_with("Option<") {
+                // `None` is `()`, which is decoded as `null`.
+                return Ok(value);
+            }
+            let is_unit_variant = variant_declaration == "()"
+                || matches!(
+                    schema.get_definition(variant_declaration),
+                    Some(Definition::Struct {
+                        fields: Fields::Empty
+                    })
+                );
+            if is_unit_variant {
+                Ok(serde_json::Value::String(variant_name.clone()))
+            } else {
+                Ok(serde_json::json!({ variant_name: value }))
+            }
+        }
+        Definition::Struct { fields } => match fields {
+            Fields::NamedFields(fields) => fields
+                .iter()
+                .map(|(name, field)| {
+                    Ok((
+                        name.clone(),
+                        decode_declaration(schema, field, input, depth + 1)?,
+                    ))
+                })
+                .collect::<color_eyre::eyre::Result<serde_json::Map<_, _>>>()
+                .map(serde_json::Value::Object),
+            Fields::UnnamedFields(fields) => {
+                let mut values = fields
+                    .iter()
+                    .map(|field| decode_declaration(schema, field, input, depth + 1))
+                    .collect::<color_eyre::eyre::Result<Vec<_>>>()?;
+                // Newtypes are shown as the wrapped value, the way serde does it.
+                if values.len() == 1 {
+                    Ok(values.remove(0))
+                } else {
+                    Ok(serde_json::Value::Array(values))
+                }
+            }
+            Fields::Empty => Ok(serde_json::Value::Null),
+        },
+    }
+}
+
+fn take<'a>(
+    input: &mut &'a [u8],
+    length: usize,
+    declaration: &str,
+) -> color_eyre::eyre::Result<&'a [u8]> {
+    if input.len() < length {
+        return Err(eyre!(
+            "Unexpected end of data while decoding a value of the Borsh type `{declaration}`"
+        ));
+    }
+    let (bytes, rest) = input.split_at(length);
+    *input = rest;
+    Ok(bytes)
+}
+
+fn read_bytes<const N: usize>(
+    input: &mut &[u8],
+    declaration: &str,
+) -> color_eyre::eyre::Result<[u8; N]> {
+    let mut bytes = [0; N];
+    bytes.copy_from_slice(take(input, N, declaration)?);
+    Ok(bytes)
+}
+
+fn float_to_json(value: f64) -> color_eyre::eyre::Result<serde_json::Value> {
+    serde_json::Number::from_f64(value)
+        .map(serde_json::Value::Number)
+        .ok_or_else(|| eyre!("The floating point value {value} cannot be represented in JSON"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_standard_types() {
+        let schema = BorshSchemaContainer::for_type::<Option<Vec<(u64, String, u128)>>>();
+        let data =
+            borsh::to_vec(&Some(vec![(7_u64, "seven".to_string(), 10_u128.pow(24))])).unwrap();
+        assert_eq!(
+            decode(&schema, &data).unwrap(),
+            serde_json::json!([[7, "seven", "1000000000000000000000000"]])
+        );
+        assert_eq!(
+            decode(
+                &schema,
+                &borsh::to_vec(&None::<Vec<(u64, String, u128)>>).unwrap()
+            )
+            .unwrap(),
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn decodes_structs_and_enums() {
+        let schema = BorshSchemaContainer::new(
+            "Status".to_string(),
+            [
+                (
+                    "Status".to_string(),
+                    Definition::Enum {
+                        tag_width: 1,
+                        variants: vec![
+                            (0, "Paused".to_string(), "StatusPaused".to_string()),
+                            (1, "Running".to_string(), "StatusRunning".to_string()),
+                        ],
+                    },
+                ),
+                (
+                    "StatusPaused".to_string(),
+                    Definition::Struct {
+                        fields: Fields::Empty,
+                    },
+                ),
+                (
+                    "StatusRunning".to_string(),
+                    Definition::Struct {
+                        fields: Fields::NamedFields(vec![
+                            ("since".to_string(), "u64".to_string()),
+                            ("paused".to_string(), "bool".to_string()),
+                        ]),
+                    },
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        assert_eq!(decode(&schema, &[0]).unwrap(), serde_json::json!("Paused"));
+        assert_eq!(
+            decode(&schema, &[1, 5, 0, 0, 0, 0, 0, 0, 0, 1]).unwrap(),
+            serde_json::json!({"Running": {"since": 5, "paused": true}})
+        );
+        assert!(decode(&schema, &[2]).is_err());
+        assert!(decode(&schema, &[1, 5, 0]).is_err());
+        assert!(decode(&schema, &[0, 0]).is_err());
+    }
+
+    #[test]
+    fn rejects_sequences_longer_than_the_data() {
+        let schema = BorshSchemaContainer::for_type::<Vec<()>>();
+        assert_eq!(
+            decode(&schema, &[0, 0, 0, 0]).unwrap(),
+            serde_json::json!([])
+        );
+        assert!(decode(&schema, &u32::MAX.to_le_bytes()).is_err());
+        assert!(decode(&schema, &[1, 0, 0, 0, 0]).is_err());
+
+        let schema = BorshSchemaContainer::for_type::<Vec<u8>>();
+        assert!(decode(&schema, &[3, 0, 0, 0, 1, 2]).is_err());
+    }
+}
@@ -78,14 +78,26 @@ impl FunctionContext {
             let function_name = scope.function_name.clone();
 
             move |network_config, block_reference| {
+                let args = super::call_function_args_type::function_args_with_abi(
+                    super::call_function_args_type::function_args(
+                        function_args.clone(),
+                        function_args_type.clone(),
+                    )?,
+                    &function_args_type,
+                    previous_context.global_context.offline,
+                    network_config,
+                    &account_id,
+                    &function_name,
+                )?;
                 call_view_function(
                     network_config,
                     &account_id,
                     &function_name,
-                    function_args.clone(),
-                    function_args_type.clone(),
+                    args,
                     block_reference,
                     previous_context.global_context.verbosity,
+                    previous_context.global_context.output_format,
+                    previous_context.global_context.decode_with_abi,
                 )
             }
         });
@@ -119,17 +131,18 @@ impl Function {
 }
 
 #[tracing::instrument(name = "Getting a response to a read-only function call ...", skip_all)]
+#[allow(clippy::too_many_arguments)]
 fn call_view_function(
     network_config: &crate::config::NetworkConfig,
     account_id: &near_primitives::types::AccountId,
     function_name: &str,
-    function_args: String,
-    function_args_type: super::call_function_args_type::FunctionArgsType,
+    args: Vec<u8>,
     block_reference: &near_primitives::types::BlockReference,
     verbosity: crate::Verbosity,
+    output_format: crate::common::OutputFormat,
+    decode_with_abi: bool,
 ) -> crate::CliResult {
     tracing::info!(target: "near_teach_me", "Getting a response to a read-only function call ...");
-    let args = super::call_function_args_type::function_args(function_args, function_args_type)?;
     let call_result = network_config
         .json_rpc_client()
         .blocking_call_view_function(account_id, function_name, args, block_reference.clone())
@@ -140,7 +153,39 @@ fn call_view_function(
             )
         })?;
 
-    let info_str = if call_result.result.is_empty() {
+    let decoded_return_value = if decode_with_abi {
+        crate::commands::contract::abi_result::decode_function_return_value(
+            network_config,
+            account_id,
+            function_name,
+            block_reference,
+            &call_result.result,
+        )?
+    } else {
+        None
+    };
+
+    if let crate::common::OutputFormat::Json = output_format {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "contract_account_id": account_id,
+                "method_name": function_name,
+                "logs": call_result.logs,
+                "result": decoded_return_value.as_ref().map_or_else(
+                    || crate::common::return_value_to_json(&call_result.result),
+                    |decoded_return_value| decoded_return_value.value.clone(),
+                ),
+                "result_abi_type": decoded_return_value.as_ref().map(|decoded_return_value| &decoded_return_value.type_name),
+                "result_base64": near_primitives::serialize::to_base64(&call_result.result),
+            }))?
+        );
+        return Ok(());
+    }
+
+    let info_str = if let Some(decoded_return_value) = &decoded_return_value {
+        serde_json::to_string_pretty(&decoded_return_value.value)?
+    } else if call_result.result.is_empty() {
         "Empty return value".to_string()
     } else if let Ok(json_result) = call_result.parse_result_from_json::<serde_json::Value>() {
         serde_json::to_string_pretty(&json_result)?
@@ -155,8 +200,12 @@ fn call_view_function(
     if let crate::Verbosity::Quiet = verbosity {
         std::io::stdout().write_all(&call_result.result)?;
     } else {
-        tracing_indicatif::suspend_tracing_indicatif(|| {
-            eprintln!("Function execution return value (printed to stdout):")
+        tracing_indicatif::suspend_tracing_indicatif(|| match &decoded_return_value {
+            Some(decoded_return_value) => eprintln!(
+                "Function execution return value of type `{}` (printed to stdout):",
+                decoded_return_value.type_name
+            ),
+            None => eprintln!("Function execution return value (printed to stdout):"),
         });
         tracing_indicatif::suspend_tracing_indicatif(|| println!("{info_str}"));
     };
@@ -65,6 +65,7 @@ pub struct FunctionContext {
     contract_account_id: near_primitives::types::AccountId,
     function_name: String,
     function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::FunctionArgsType,
 }
 
 impl FunctionContext {
@@ -81,6 +82,7 @@ impl FunctionContext {
             contract_account_id: previous_context.contract_account_id,
             function_name: scope.function_name.clone(),
             function_args,
+            function_args_type: scope.function_args_type.clone(),
         })
     }
 }
@@ -117,6 +119,7 @@ pub struct PrepaidGasContext {
     contract_account_id: near_primitives::types::AccountId,
     function_name: String,
     function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::FunctionArgsType,
     gas: crate::common::NearGas,
 }
 
@@ -130,6 +133,7 @@ impl PrepaidGasContext {
             contract_account_id: previous_context.contract_account_id,
             function_name: previous_context.function_name,
             function_args: previous_context.function_args,
+            function_args_type: previous_context.function_args_type,
             gas: scope.gas,
         })
     }
@@ -177,6 +181,7 @@ pub struct DepositContext {
     contract_account_id: near_primitives::types::AccountId,
     function_name: String,
     function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::FunctionArgsType,
     gas: crate::common::NearGas,
     deposit: crate::types::near_token::NearToken,
 }
@@ -191,6 +196,7 @@ impl DepositContext {
             contract_account_id: previous_context.contract_account_id,
             function_name: previous_context.function_name,
             function_args: previous_context.function_args,
+            function_args_type: previous_context.function_args_type,
             gas: previous_context.gas,
             deposit: scope.deposit,
         })
@@ -227,6 +233,7 @@ pub struct SignerAccountIdContext {
     contract_account_id: near_primitives::types::AccountId,
     function_name: String,
     function_args: Vec<u8>,
+    function_args_type: super::call_function_args_type::FunctionArgsType,
     gas: crate::common::NearGas,
     deposit: crate::types::near_token::NearToken,
     signer_account_id: near_primitives::types::AccountId,
@@ -242,6 +249,7 @@ impl SignerAccountIdContext {
             contract_account_id: previous_context.contract_account_id,
             function_name: previous_context.function_name,
             function_args: previous_context.function_args,
+            function_args_type: previous_context.function_args_type,
             gas: previous_context.gas,
             deposit: previous_context.deposit,
             signer_account_id: scope.signer_account_id.clone().into(),
@@ -256,14 +264,22 @@ impl From<SignerAccountIdContext> for crate::commands::ActionContext {
                 let signer_account_id = item.signer_account_id.clone();
                 let receiver_account_id = item.contract_account_id.clone();
 
-                move |_network_config| {
+                move |network_config| {
+                    let args = super::call_function_args_type::function_args_with_abi(
+                        item.function_args.clone(),
+                        &item.function_args_type,
+                        item.global_context.offline,
+                        network_config,
+                        &receiver_account_id,
+                        &item.function_name,
+                    )?;
                     Ok(crate::commands::PrepopulatedTransaction {
                         signer_id: signer_account_id.clone(),
                         receiver_id: receiver_account_id.clone(),
                         actions: vec![near_primitives::transaction::Action::FunctionCall(
                             Box::new(near_primitives::transaction::FunctionCallAction {
                                 method_name: item.function_name.clone(),
-                                args: item.function_args.clone(),
+                                args,
                                 gas: near_primitives::gas::Gas::from_gas(item.gas.as_gas()),
                                 deposit: item.deposit.into(),
                             }),
@@ -296,7 +312,7 @@ impl SignerAccountId {
         context: &DepositContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.global_context.config.credentials_home_dir,
+            &context.global_context.config,
             "What is the signer account ID?",
         )
     }
@@ -0,0 +1,427 @@
+use std::io::IsTerminal;
+
+use color_eyre::eyre::WrapErr;
+use inquire::{Confirm, Select, Text};
+
+use crate::commands::contract::abi_schema;
+
+/// The JSON parameters of a contract function, as found in the contract ABI.
+struct FunctionParameters {
+    params: Vec<(String, serde_json::Value)>,
+    root_schema: serde_json::Value,
+}
+
+fn get_contract_abi(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+) -> Result<near_abi::AbiRoot, crate::commands::contract::FetchAbiError> {
+    tokio::runtime::Runtime::new()
+        .map_err(|err| crate::commands::contract::FetchAbiError::AbiUnknownFormat(err.into()))?
+        .block_on(crate::commands::contract::get_contract_abi(
+            &network_config.json_rpc_client(),
+            &near_primitives::types::Finality::Final.into(),
+            contract_account_id,
+        ))
+}
+
+fn get_function_parameters(
+    abi: &near_abi::AbiRoot,
+    function_name: &str,
+) -> color_eyre::eyre::Result<Option<FunctionParameters>> {
+    let function = abi
+        .body
+        .functions
+        .iter()
+        .find(|function| function.name == function_name)
+        .ok_or_else(|| {
+            color_eyre::eyre::eyre!("The contract ABI has no function \"{function_name}\"")
+        })?;
+    let near_abi::AbiParameters::Json { args } = &function.params else {
+        return Ok(None);
+    };
+    Ok(Some(FunctionParameters {
+        params: args
+            .iter()
+            .map(|arg| Ok((arg.name.clone(), serde_json::to_value(&arg.type_schema)?)))
+            .collect::<color_eyre::eyre::Result<_>>()?,
+        root_schema: serde_json::to_value(&abi.body.root_schema)?,
+    }))
+}
+
+fn parse_args_object(
+    function_args: &[u8],
+) -> color_eyre::eyre::Result<serde_json::Map<String, serde_json::Value>> {
+    if function_args.is_empty() {
+        return Ok(serde_json::Map::new());
+    }
+    match serde_json::from_slice(function_args).wrap_err("Data not in JSON format!")? {
+        serde_json::Value::Object(args) => Ok(args),
+        _ => Err(color_eyre::eyre::eyre!(
+            "The function arguments must be a JSON object"
+        )),
+    }
+}
+
+fn check_args(
+    function_parameters: &FunctionParameters,
+    args: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    for name in args.keys() {
+        if !function_parameters
+            .params
+            .iter()
+            .any(|(param_name, _)| param_name == name)
+        {
+            errors.push(format!("{name}: the function has no such argument"));
+        }
+    }
+    for (name, schema) in &function_parameters.params {
+        match args.get(name) {
+            Some(value) => errors.extend(abi_schema::validate(
+                value,
+                schema,
+                &function_parameters.root_schema,
+                name,
+            )),
+            // Omitted optional arguments are deserialized as `None`.
+            None if abi_schema::validate(
+                &serde_json::Value::Null,
+                schema,
+                &function_parameters.root_schema,
+                name,
+            )
+            .is_empty() => {}
+            None => errors.push(format!("{name}: the argument is required")),
+        }
+    }
+    errors
+}
+
+fn bail_on_errors(function_name: &str, errors: Vec<String>) -> crate::CliResult {
+    if errors.is_empty() {
+        return Ok(());
+    }
+    Err(color_eyre::eyre::eyre!(
+        "The arguments do not match the ABI of the function \"{function_name}\":\n  {}",
+        errors.join("\n  ")
+    ))
+}
+
+/// Completes the arguments given on the command line (a JSON object, possibly empty)
+/// by asking for every missing argument of the function, and validates the result.
+pub fn build_function_args(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+    function_args: &[u8],
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let abi = get_contract_abi(network_config, contract_account_id)
+        .wrap_err("The `abi-args` mode needs the contract ABI")?;
+    let function_parameters = get_function_parameters(&abi, function_name)?.ok_or_else(|| {
+        color_eyre::eyre::eyre!(
+            "The function \"{function_name}\" takes Borsh arguments, which cannot be built from the ABI"
+        )
+    })?;
+    let mut args = parse_args_object(function_args)?;
+
+    let missing_params = function_parameters
+        .params
+        .iter()
+        .filter(|(name, _)| !args.contains_key(name))
+        .collect::<Vec<_>>();
+    if !missing_params.is_empty() && !std::io::stdin().is_terminal() {
+        return Err(color_eyre::eyre::eyre!(
+            "The arguments {} of the function \"{function_name}\" are missing (they can only be asked for in a terminal)",
+            missing_params
+                .iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+    for (name, schema) in missing_params {
+        let value = prompt_value(name, schema, &function_parameters.root_schema)?;
+        if !value.is_null() {
+            args.insert(name.clone(), value);
+        }
+    }
+
+    bail_on_errors(function_name, check_args(&function_parameters, &args))?;
+    let args = serde_json::Value::Object(args);
+    tracing::info!(
+        "{}{}",
+        "Function arguments built from the contract ABI (pass them with `json-args` to skip the questions)",
+        crate::common::indent_payload(&serde_json::to_string_pretty(&args)?)
+    );
+    Ok(serde_json::to_vec(&args)?)
+}
+
+/// Validates the JSON arguments against the contract ABI. Contracts without an ABI are not checked.
+pub fn validate_function_args(
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+    function_args: &[u8],
+) -> crate::CliResult {
+    let abi = match get_contract_abi(network_config, contract_account_id) {
+        Ok(abi) => abi,
+        Err(crate::commands::contract::FetchAbiError::AbiNotSupported) => return Ok(()),
+        Err(err) => {
+            tracing::warn!(
+                "The function arguments were not checked against the contract ABI: {err}"
+            );
+            return Ok(());
+        }
+    };
+    let function_parameters = match get_function_parameters(&abi, function_name) {
+        Ok(Some(function_parameters)) => function_parameters,
+        Ok(None) => return Ok(()),
+        Err(err) => {
+            tracing::warn!(
+                "The function arguments were not checked against the contract ABI: {err}"
+            );
+            return Ok(());
+        }
+    };
+    let args = parse_args_object(function_args)?;
+    bail_on_errors(function_name, check_args(&function_parameters, &args))
+}
+
+/// Asks for a value of the given JSON schema, field by field for objects.
+fn prompt_value(
+    path: &str,
+    schema: &serde_json::Value,
+    root_schema: &serde_json::Value,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    if abi_schema::is_decimal_string(schema) {
+        return Ok(serde_json::Value::String(
+            Text::new(&format!("{path} (an integer, e.g. 1000):"))
+                .with_validator(|input: &str| {
+                    if input.trim_start_matches('-').parse::<u128>().is_ok() {
+                        Ok(inquire::validator::Validation::Valid)
+                    } else {
+                        Ok(inquire::validator::Validation::Invalid(
+                            "Enter an integer".into(),
+                        ))
+                    }
+                })
+                .prompt()?,
+        ));
+    }
+    let resolved_schema = abi_schema::resolve(schema, root_schema)?;
+
+    if let Some(inner_schema) = abi_schema::nullable_inner_schema(resolved_schema) {
+        if !Confirm::new(&format!("Do you want to set the optional {path}?"))
+            .with_default(false)
+            .prompt()?
+        {
+            return Ok(serde_json::Value::Null);
+        }
+        return prompt_value(path, &inner_schema, root_schema);
+    }
+    if let Some(allowed_values) = resolved_schema
+        .get("enum")
+        .and_then(serde_json::Value::as_array)
+    {
+        return Ok(Select::new(&format!("{path}:"), allowed_values.clone()).prompt()?);
+    }
+    if let Some(variants) = resolved_schema
+        .get("oneOf")
+        .or_else(|| resolved_schema.get("anyOf"))
+        .and_then(serde_json::Value::as_array)
+    {
+        let labels = variants
+            .iter()
+            .enumerate()
+            .map(|(index, variant)| variant_label(index, variant, root_schema))
+            .collect::<Vec<_>>();
+        let selected =
+            Select::new(&format!("Which variant of {path}?"), labels.clone()).prompt()?;
+        let index = labels
+            .iter()
+            .position(|label| label == &selected)
+            .unwrap_or_default();
+        return prompt_value(path, &variants[index], root_schema);
+    }
+
+    match resolved_schema
+        .get("type")
+        .and_then(serde_json::Value::as_str)
+    {
+        Some("boolean") => Ok(serde_json::Value::Bool(
+            Confirm::new(&format!("{path}?")).prompt()?,
+        )),
+        Some("object")
+            if resolved_schema
+                .get("properties")
+                .is_some_and(serde_json::Value::is_object) =>
+        {
+            let required = resolved_schema
+                .get("required")
+                .and_then(serde_json::Value::as_array)
+                .cloned()
+                .unwrap_or_default();
+            let mut object = serde_json::Map::new();
+            for (name, field_schema) in resolved_schema["properties"]
+                .as_object()
+                .into_iter()
+                .flatten()
+            {
+                let field_path = format!("{path}.{name}");
+                if !required.contains(&serde_json::Value::String(name.clone()))
+                    && abi_schema::nullable_inner_schema(abi_schema::resolve(
+                        field_schema,
+                        root_schema,
+                    )?)
+                    .is_none()
+                    && !Confirm::new(&format!("Do you want to set the optional {field_path}?"))
+                        .with_default(false)
+                        .prompt()?
+                {
+                    continue;
+                }
+                let value = prompt_value(&field_path, field_schema, root_schema)?;
+                if !value.is_null() {
+                    object.insert(name.clone(), value);
+                }
+            }
+            Ok(serde_json::Value::Object(object))
+        }
+        Some(value_type @ ("string" | "integer" | "number")) => {
+            let is_string = value_type == "string";
+            let message = format!("{path} ({value_type}):");
+            let input = Text::new(&message)
+                .with_validator({
+                    let schema = schema.clone();
+                    let root_schema = root_schema.clone();
+                    let path = path.to_string();
+                    move |input: &str| {
+                        let errors = match parse_scalar(input, is_string) {
+                            Ok(value) => abi_schema::validate(&value, &schema, &root_schema, &path),
+                            Err(_) => vec![format!("Enter a {value_type}")],
+                        };
+                        if errors.is_empty() {
+                            Ok(inquire::validator::Validation::Valid)
+                        } else {
+                            Ok(inquire::validator::Validation::Invalid(
+                                errors.join("; ").into(),
+                            ))
+                        }
+                    }
+                })
+                .prompt()?;
+            Ok(parse_scalar(&input, is_string)?)
+        }
+        // Arrays, maps and anything else are entered as JSON.
+        _ => {
+            let input = Text::new(&format!("{path} (JSON):"))
+                .with_validator({
+                    let schema = schema.clone();
+                    let root_schema = root_schema.clone();
+                    let path = path.to_string();
+                    move |input: &str| {
+                        let errors = match serde_json::from_str(input) {
+                            Ok(value) => abi_schema::validate(&value, &schema, &root_schema, &path),
+                            Err(err) => vec![format!("Not a valid JSON: {err}")],
+                        };
+                        if errors.is_empty() {
+                            Ok(inquire::validator::Validation::Valid)
+                        } else {
+                            Ok(inquire::validator::Validation::Invalid(
+                                errors.join("; ").into(),
+                            ))
+                        }
+                    }
+                })
+                .prompt()?;
+            Ok(serde_json::from_str(&input)?)
+        }
+    }
+}
+
+fn parse_scalar(input: &str, is_string: bool) -> serde_json::Result<serde_json::Value> {
+    if is_string {
+        Ok(serde_json::Value::String(input.to_string()))
+    } else {
+        serde_json::from_str::<serde_json::Number>(input.trim()).map(serde_json::Value::Number)
+    }
+}
+
+fn variant_label(
+    index: usize,
+    variant: &serde_json::Value,
+    root_schema: &serde_json::Value,
+) -> String {
+    let variant = abi_schema::resolve(variant, root_schema).unwrap_or(variant);
+    let label = if let Some([value]) = variant
+        .get("enum")
+        .and_then(serde_json::Value::as_array)
+        .map(Vec::as_slice)
+    {
+        Some(value.to_string())
+    } else if let Some(Some([serde_json::Value::String(name)])) = variant
+        .get("required")
+        .map(|required| required.as_array().map(Vec::as_slice))
+    {
+        // Enum variants with data are objects with a single field named after the variant.
+        Some(name.clone())
+    } else {
+        ["title", "description", "type"]
+            .iter()
+            .find_map(|key| variant.get(*key).map(ToString::to_string))
+    };
+    format!("#{index} {}", label.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_args_against_function_parameters() {
+        let function_parameters = FunctionParameters {
+            params: vec![
+                (
+                    "receiver_id".to_string(),
+                    serde_json::json!({"$ref": "#/definitions/AccountId"}),
+                ),
+                (
+                    "amount".to_string(),
+                    serde_json::json!({"$ref": "#/definitions/U128"}),
+                ),
+                (
+                    "memo".to_string(),
+                    serde_json::json!({"type": ["string", "null"]}),
+                ),
+            ],
+            root_schema: serde_json::json!({
+                "definitions": {
+                    "AccountId": {"type": "string"},
+                    "U128": {"type": "string"}
+                }
+            }),
+        };
+        let args = |value: serde_json::Value| value.as_object().unwrap().clone();
+
+        assert!(
+            check_args(
+                &function_parameters,
+                &args(serde_json::json!({"receiver_id": "bob.near", "amount": "10"}))
+            )
+            .is_empty()
+        );
+        assert_eq!(
+            check_args(
+                &function_parameters,
+                &args(serde_json::json!({"amount": 10, "msg": "hi"}))
+            ),
+            vec![
+                "msg: the function has no such argument".to_string(),
+                "receiver_id: the argument is required".to_string(),
+                "amount: expected an integer as a decimal string (e.g. \"1000\"), found number 10"
+                    .to_string(),
+            ]
+        );
+    }
+}
@@ -4,6 +4,8 @@ use color_eyre::eyre::Context;
 use inquire::Select;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage, IntoEnumIterator};
 
+pub mod abi_args;
+
 #[derive(Debug, EnumDiscriminants, Clone, clap::ValueEnum)]
 #[strum_discriminants(derive(EnumMessage, EnumIter))]
 /// How do you want to pass the function call arguments?
@@ -24,6 +26,11 @@ pub enum FunctionArgsType {
     ))]
     /// Read from file (e.g. reusable JSON or binary data)
     FileArgs,
+    #[strum_discriminants(strum(
+        message = "abi-args     - Build JSON arguments from the contract ABI (e.g. {} to be asked for all)"
+    ))]
+    /// Build JSON arguments from the contract ABI (e.g. {} to be asked for all)
+    AbiArgs,
 }
 
 impl interactive_clap::ToCli for FunctionArgsType {
@@ -38,6 +45,7 @@ impl std::str::FromStr for FunctionArgsType {
             "text-args" => Ok(Self::TextArgs),
             "base64-args" => Ok(Self::Base64Args),
             "file-args" => Ok(Self::FileArgs),
+            "abi-args" => Ok(Self::AbiArgs),
             _ => Err("FunctionArgsType: incorrect value entered".to_string()),
         }
     }
@@ -50,6 +58,7 @@ impl std::fmt::Display for FunctionArgsType {
             Self::TextArgs => write!(f, "text-args"),
             Self::Base64Args => write!(f, "base64-args"),
             Self::FileArgs => write!(f, "file-args"),
+            Self::AbiArgs => write!(f, "abi-args"),
         }
     }
 }
@@ -67,6 +76,10 @@ impl std::fmt::Display for FunctionArgsTypeDiscriminants {
                 f,
                 "file-args    - Read from file reusable JSON or binary data (e.g. ./args.json)"
             ),
+            Self::AbiArgs => write!(
+                f,
+                "abi-args     - Build JSON arguments from the contract ABI, asking for the missing ones"
+            ),
         }
     }
 }
@@ -83,6 +96,7 @@ pub fn input_function_args_type() -> color_eyre::eyre::Result<Option<FunctionArg
         FunctionArgsTypeDiscriminants::TextArgs => Ok(Some(FunctionArgsType::TextArgs)),
         FunctionArgsTypeDiscriminants::Base64Args => Ok(Some(FunctionArgsType::Base64Args)),
         FunctionArgsTypeDiscriminants::FileArgs => Ok(Some(FunctionArgsType::FileArgs)),
+        FunctionArgsTypeDiscriminants::AbiArgs => Ok(Some(FunctionArgsType::AbiArgs)),
     }
 }
 
@@ -107,5 +121,129 @@ pub fn function_args(
                 .wrap_err_with(|| format!("Access to data file <{:?}> not found!", data_path))?;
             Ok(data)
         }
+        // The given arguments are completed from the contract ABI later, once the network is known.
+        super::call_function_args_type::FunctionArgsType::AbiArgs => {
+            if args.trim().is_empty() {
+                return Ok(Vec::new());
+            }
+            let data_json =
+                serde_json::Value::from_str(&args).wrap_err("Data not in JSON format!")?;
+            serde_json::to_vec(&data_json).wrap_err("Internal error!")
+        }
+    }
+}
+
+/// Builds (`abi-args`) or validates (`json-args`) the function arguments with the contract ABI.
+/// In offline mode the ABI cannot be fetched, so `json-args` are passed as they are.
+pub fn function_args_with_abi(
+    function_args: Vec<u8>,
+    function_args_type: &FunctionArgsType,
+    offline: bool,
+    network_config: &crate::config::NetworkConfig,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    match function_args_type {
+        FunctionArgsType::AbiArgs if offline => Err(abi_args_offline_error()),
+        FunctionArgsType::AbiArgs => abi_args::build_function_args(
+            network_config,
+            contract_account_id,
+            function_name,
+            &function_args,
+        ),
+        FunctionArgsType::JsonArgs if offline => Ok(function_args),
+        FunctionArgsType::JsonArgs => {
+            abi_args::validate_function_args(
+                network_config,
+                contract_account_id,
+                function_name,
+                &function_args,
+            )?;
+            Ok(function_args)
+        }
+        FunctionArgsType::TextArgs | FunctionArgsType::Base64Args | FunctionArgsType::FileArgs => {
+            Ok(function_args)
+        }
+    }
+}
+
+fn abi_args_offline_error() -> color_eyre::eyre::Report {
+    color_eyre::eyre::eyre!(
+        "The `abi-args` mode needs the contract ABI, which cannot be fetched in offline mode (use `json-args`)"
+    )
+}
+
+/// Same as [`function_args`], but `abi-args` are built with the ABI of the contract
+/// found on the first network where the contract account exists.
+///
+/// This is used while the actions of a transaction are constructed, before the network is
+/// selected, so the ABI may come from another network connection than the one the
+/// transaction is sent to. `json-args` are not validated here for the same reason.
+pub fn function_args_for_account(
+    args: String,
+    function_args_type: FunctionArgsType,
+    global_context: &crate::GlobalContext,
+    contract_account_id: &near_primitives::types::AccountId,
+    function_name: &str,
+) -> color_eyre::eyre::Result<Vec<u8>> {
+    let function_args = function_args(args, function_args_type.clone())?;
+    if !matches!(function_args_type, FunctionArgsType::AbiArgs) {
+        return Ok(function_args);
+    }
+    if global_context.offline {
+        return Err(abi_args_offline_error());
+    }
+    let network_config =
+        crate::common::find_network_where_account_exist(global_context, contract_account_id.clone())?
+            .ok_or_else(|| {
+                color_eyre::eyre::eyre!(
+                    "The contract account <{contract_account_id}> was not found on any network, so its ABI cannot be fetched"
+                )
+            })?;
+    function_args_with_abi(
+        function_args,
+        &function_args_type,
+        global_context.offline,
+        &network_config,
+        contract_account_id,
+        function_name,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_not_fetch_the_abi_offline() {
+        let network_config = crate::config::Config::default().network_connection["testnet"].clone();
+        let contract_account_id: near_primitives::types::AccountId =
+            "wrap.testnet".parse().unwrap();
+        let function_args = br#"{"account_id": "alice.testnet"}"#.to_vec();
+        assert_eq!(
+            function_args_with_abi(
+                function_args.clone(),
+                &FunctionArgsType::JsonArgs,
+                true,
+                &network_config,
+                &contract_account_id,
+                "ft_balance_of",
+            )
+            .unwrap(),
+            function_args
+        );
+        assert!(
+            function_args_with_abi(
+                function_args,
+                &FunctionArgsType::AbiArgs,
+                true,
+                &network_config,
+                &contract_account_id,
+                "ft_balance_of",
+            )
+            .unwrap_err()
+            .to_string()
+            .contains("offline mode")
+        );
     }
 }
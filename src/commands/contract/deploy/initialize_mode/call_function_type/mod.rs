@@ -24,6 +24,7 @@ pub struct CallFunctionActionContext {
     receiver_account_id: near_primitives::types::AccountId,
     signer_account_id: near_primitives::types::AccountId,
     deploy_action: near_primitives::transaction::Action,
+    lint: bool,
     function_name: String,
     function_args: Vec<u8>,
 }
@@ -33,6 +34,13 @@ impl CallFunctionActionContext {
         previous_context: super::super::GenericDeployContext,
         scope: &<CallFunctionAction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
+        if let super::super::super::call_function::call_function_args_type::FunctionArgsType::AbiArgs =
+            scope.function_args_type
+        {
+            return Err(color_eyre::eyre::eyre!(
+                "The `abi-args` mode needs the ABI of a deployed contract, so it cannot be used to initialize the contract being deployed (use `json-args`)"
+            ));
+        }
         let function_args =
             super::super::super::call_function::call_function_args_type::function_args(
                 scope.function_args.clone(),
@@ -43,6 +51,7 @@ impl CallFunctionActionContext {
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             deploy_action: previous_context.deploy_action,
+            lint: previous_context.lint,
             function_name: scope.function_name.clone(),
             function_args,
         })
@@ -77,6 +86,7 @@ pub struct PrepaidGasContext {
     receiver_account_id: near_primitives::types::AccountId,
     signer_account_id: near_primitives::types::AccountId,
     deploy_action: near_primitives::transaction::Action,
+    lint: bool,
     function_name: String,
     function_args: Vec<u8>,
     gas: crate::common::NearGas,
@@ -92,6 +102,7 @@ impl PrepaidGasContext {
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             deploy_action: previous_context.deploy_action,
+            lint: previous_context.lint,
             function_name: previous_context.function_name,
             function_args: previous_context.function_args,
             gas: scope.gas,
@@ -149,8 +160,16 @@ impl DepositContext {
             std::sync::Arc::new({
                 let signer_account_id = previous_context.signer_account_id.clone();
                 let receiver_account_id = previous_context.receiver_account_id.clone();
-
-                move |_network_config| {
+                let offline = previous_context.global_context.offline;
+
+                move |network_config| {
+                    if previous_context.lint {
+                        super::super::lint_deploy_action(
+                            &previous_context.deploy_action,
+                            network_config,
+                            offline,
+                        )?;
+                    }
                     Ok(crate::commands::PrepopulatedTransaction {
                         signer_id: signer_account_id.clone(),
                         receiver_id: receiver_account_id.clone(),
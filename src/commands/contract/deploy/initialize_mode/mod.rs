@@ -37,6 +37,7 @@ impl NoInitializeContext {
             receiver_account_id: previous_context.receiver_account_id,
             signer_account_id: previous_context.signer_account_id,
             deploy_action: previous_context.deploy_action,
+            lint: previous_context.lint,
         }))
     }
 }
@@ -47,8 +48,12 @@ impl From<NoInitializeContext> for crate::commands::ActionContext {
             std::sync::Arc::new({
                 let signer_account_id = item.0.signer_account_id.clone();
                 let receiver_account_id = item.0.receiver_account_id.clone();
+                let offline = item.0.global_context.offline;
 
-                move |_network_config| {
+                move |network_config| {
+                    if item.0.lint {
+                        super::lint_deploy_action(&item.0.deploy_action, network_config, offline)?;
+                    }
                     Ok(crate::commands::PrepopulatedTransaction {
                         signer_id: signer_account_id.clone(),
                         receiver_id: receiver_account_id.clone(),
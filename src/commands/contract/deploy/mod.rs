@@ -65,7 +65,7 @@ impl Contract {
         context: &crate::GlobalContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.config.credentials_home_dir,
+            &context.config,
             "What is the contract account ID?",
         )
     }
@@ -77,6 +77,26 @@ pub struct GenericDeployContext {
     pub receiver_account_id: near_primitives::types::AccountId,
     pub signer_account_id: near_primitives::types::AccountId,
     pub deploy_action: near_primitives::transaction::Action,
+    /// Whether the deployed code is linted once the network is known
+    pub lint: bool,
+}
+
+/// Lints the code of the `use-file` mode against the limits of the network.
+pub fn lint_deploy_action(
+    deploy_action: &near_primitives::transaction::Action,
+    network_config: &crate::config::NetworkConfig,
+    offline: bool,
+) -> color_eyre::eyre::Result<()> {
+    if let near_primitives::transaction::Action::DeployContract(deploy_contract_action) =
+        deploy_action
+    {
+        super::lint::lint_code_before_deploy(
+            &deploy_contract_action.code,
+            network_config,
+            offline,
+        )?;
+    }
+    Ok(())
 }
 
 #[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
@@ -85,6 +105,9 @@ pub struct GenericDeployContext {
 pub struct ContractFile {
     /// What is the file location of the contract?
     pub file_path: crate::types::path_buf::PathBuf,
+    /// Deploy without checking the contract for features that NEAR does not support
+    #[interactive_clap(long)]
+    skip_lint: bool,
     #[interactive_clap(subcommand)]
     initialize: self::initialize_mode::InitializeMode,
 }
@@ -106,6 +129,7 @@ impl ContractFileContext {
             deploy_action: near_primitives::transaction::Action::DeployContract(
                 near_primitives::action::DeployContractAction { code },
             ),
+            lint: !scope.skip_lint,
         }))
     }
 }
@@ -145,6 +169,7 @@ impl ContractHashContext {
                         ),
                 },
             )),
+            lint: false,
         }))
     }
 }
@@ -196,6 +221,7 @@ impl ContractAccountIdContext {
                         ),
                 },
             )),
+            lint: false,
         })
     }
 }
@@ -15,6 +15,9 @@ pub struct Contract {
 pub struct ContractFile {
     /// What is the file location of the contract?
     pub file_path: crate::types::path_buf::PathBuf,
+    /// Deploy without checking the contract for features that NEAR does not support
+    #[interactive_clap(long)]
+    skip_lint: bool,
     #[interactive_clap(subcommand)]
     mode: DeployGlobalMode,
 }
@@ -23,6 +26,7 @@ pub struct ContractFile {
 pub struct ContractFileContext {
     pub global_context: crate::GlobalContext,
     pub code: Vec<u8>,
+    pub lint: bool,
 }
 
 impl ContractFileContext {
@@ -36,6 +40,7 @@ impl ContractFileContext {
         Ok(Self {
             global_context: previous_context,
             code,
+            lint: !scope.skip_lint,
         })
     }
 }
@@ -63,6 +68,7 @@ pub enum DeployGlobalMode {
 pub struct DeployGlobalModeContext {
     pub global_context: crate::GlobalContext,
     pub code: Vec<u8>,
+    pub lint: bool,
     pub mode: near_primitives::action::GlobalContractDeployMode,
 }
 
@@ -74,6 +80,7 @@ impl DeployGlobalModeContext {
         Ok(DeployGlobalModeContext {
             global_context: previous_context.global_context,
             code: previous_context.code,
+            lint: previous_context.lint,
             mode: match scope {
                 DeployGlobalModeDiscriminants::AsGlobalHash => {
                     near_primitives::action::GlobalContractDeployMode::CodeHash
@@ -112,7 +119,7 @@ impl DeployGlobalResult {
             }
         };
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.global_context.config.credentials_home_dir,
+            &context.global_context.config,
             question,
         )
     }
@@ -121,6 +128,7 @@ impl DeployGlobalResult {
 pub struct DeployGlobalResultContext {
     pub global_context: crate::GlobalContext,
     pub code: Vec<u8>,
+    pub lint: bool,
     pub mode: near_primitives::action::GlobalContractDeployMode,
     pub account_id: near_primitives::types::AccountId,
 }
@@ -133,6 +141,7 @@ impl DeployGlobalResultContext {
         Ok(Self {
             global_context: previous_context.global_context,
             code: previous_context.code,
+            lint: previous_context.lint,
             mode: previous_context.mode,
             account_id: scope.account_id.clone().into(),
         })
@@ -142,9 +151,13 @@ impl DeployGlobalResultContext {
 impl From<DeployGlobalResultContext> for crate::commands::ActionContext {
     fn from(item: DeployGlobalResultContext) -> Self {
         let account_id = item.account_id.clone();
+        let offline = item.global_context.offline;
         let get_prepopulated_transaction_after_getting_network_callback: crate::commands::GetPrepopulatedTransactionAfterGettingNetworkCallback =
         std::sync::Arc::new({
-            move |_network_config| {
+            move |network_config| {
+                if item.lint {
+                    super::lint::lint_code_before_deploy(&item.code, network_config, offline)?;
+                }
                 Ok(crate::commands::PrepopulatedTransaction {
                     signer_id: item.account_id.clone(),
                     receiver_id: item.account_id.clone(),
@@ -0,0 +1,621 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use color_eyre::eyre::Context;
+use color_eyre::owo_colors::OwoColorize;
+
+use crate::common::{CallResultExt, JsonRpcClientExt};
+
+mod wasm_info;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ContractContext)]
+pub struct Contract {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the contract account ID?
+    contract_account_id: crate::types::account_id::AccountId,
+    /// What is the file location of the local contract?
+    file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+impl Contract {
+    pub fn input_contract_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_non_signer_account_id_from_used_account_list(
+            &context.config.credentials_home_dir,
+            "What is the contract account ID?",
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct ContractContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl ContractContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<Contract as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let local_code = std::fs::read(&scope.file_path).wrap_err_with(|| {
+            format!("Failed to open or read the file: {:?}.", scope.file_path.0)
+        })?;
+
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let account_id: near_primitives::types::AccountId = scope.contract_account_id.clone().into();
+            let file_path = scope.file_path.clone();
+            let output_format = previous_context.output_format;
+
+            move |network_config, block_reference| {
+                let deployed_code = super::download_wasm::get_code(
+                    &super::download_wasm::ContractType::Regular(account_id.clone()),
+                    network_config,
+                    block_reference.clone(),
+                )?;
+                let report = diff_contracts(
+                    &deployed_code,
+                    &local_code,
+                    &account_id,
+                    network_config,
+                    block_reference,
+                )?;
+                match output_format {
+                    crate::common::OutputFormat::Json => {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&serde_json::json!({
+                                "contract_account_id": account_id,
+                                "file_path": file_path.0,
+                                "diff": report,
+                            }))?
+                        );
+                    }
+                    crate::common::OutputFormat::Plaintext => {
+                        println!(
+                            "Deployed contract <{account_id}> compared to the local file {:?}:\n",
+                            file_path.0
+                        );
+                        print_report(&report);
+                    }
+                }
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.config,
+            on_after_getting_block_reference_callback,
+            interacting_with_account_ids: vec![scope.contract_account_id.clone().into()],
+        }))
+    }
+}
+
+impl From<ContractContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: ContractContext) -> Self {
+        item.0
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DiffReport {
+    code_hash: Change<String>,
+    code_size: Change<usize>,
+    exported_functions: ListDiff,
+    imported_host_functions: ListDiff,
+    /// `None` if the ABI could not be read from the deployed or the local contract.
+    abi_methods: Option<AbiMethodsDiff>,
+    /// `None` if the contract source metadata could not be read from the deployed or the local contract.
+    contract_source_metadata: Option<FieldsDiff>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct Change<T> {
+    deployed: T,
+    local: T,
+    changed: bool,
+}
+
+impl<T: PartialEq> Change<T> {
+    fn new(deployed: T, local: T) -> Self {
+        Self {
+            changed: deployed != local,
+            deployed,
+            local,
+        }
+    }
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+struct ListDiff {
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+impl ListDiff {
+    fn new(deployed: &BTreeSet<String>, local: &BTreeSet<String>) -> Self {
+        Self {
+            added: local.difference(deployed).cloned().collect(),
+            removed: deployed.difference(local).cloned().collect(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+struct AbiMethodsDiff {
+    added: Vec<String>,
+    removed: Vec<String>,
+    changed: Vec<ChangedAbiMethod>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ChangedAbiMethod {
+    name: String,
+    /// The changed parts of the method declaration (e.g. `params`, `result`, `modifiers`).
+    fields: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct FieldsDiff {
+    added: BTreeMap<String, serde_json::Value>,
+    removed: BTreeMap<String, serde_json::Value>,
+    changed: BTreeMap<String, Change<serde_json::Value>>,
+}
+
+/// Compares the deployed code with the local code.
+///
+/// The ABI and the contract source metadata are read from the WASM; for the deployed contract,
+/// they are requested from the network if they cannot be found in its code.
+fn diff_contracts(
+    deployed_code: &[u8],
+    local_code: &[u8],
+    account_id: &near_primitives::types::AccountId,
+    network_config: &crate::config::NetworkConfig,
+    block_reference: &near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<DiffReport> {
+    let deployed_wasm = wasm_info::WasmInfo::parse(deployed_code)
+        .wrap_err("Failed to parse the deployed contract code")?;
+    let local_wasm =
+        wasm_info::WasmInfo::parse(local_code).wrap_err("Failed to parse the local contract")?;
+
+    let deployed_abi = deployed_wasm
+        .static_return_value("__contract_abi", parse_abi)
+        .or_else(|| {
+            tokio::runtime::Runtime::new()
+                .ok()?
+                .block_on(super::get_contract_abi(
+                    &network_config.json_rpc_client(),
+                    block_reference,
+                    account_id,
+                ))
+                .ok()
+        });
+    let local_abi = local_wasm.static_return_value("__contract_abi", parse_abi);
+
+    let deployed_source_metadata = deployed_wasm
+        .static_return_value("contract_source_metadata", parse_source_metadata)
+        .or_else(|| {
+            network_config
+                .json_rpc_client()
+                .blocking_call_view_function(
+                    account_id,
+                    "contract_source_metadata",
+                    vec![],
+                    block_reference.clone(),
+                )
+                .ok()?
+                .parse_result_from_json::<serde_json::Value>()
+                .ok()
+                .filter(serde_json::Value::is_object)
+        });
+    let local_source_metadata =
+        local_wasm.static_return_value("contract_source_metadata", parse_source_metadata);
+
+    Ok(DiffReport {
+        code_hash: Change::new(
+            near_primitives::hash::CryptoHash::hash_bytes(deployed_code).to_string(),
+            near_primitives::hash::CryptoHash::hash_bytes(local_code).to_string(),
+        ),
+        code_size: Change::new(deployed_code.len(), local_code.len()),
+        exported_functions: ListDiff::new(
+            &deployed_wasm.exported_functions(),
+            &local_wasm.exported_functions(),
+        ),
+        imported_host_functions: ListDiff::new(
+            &deployed_wasm.imported_host_functions(),
+            &local_wasm.imported_host_functions(),
+        ),
+        abi_methods: deployed_abi
+            .zip(local_abi)
+            .map(|(deployed_abi, local_abi)| diff_abi_methods(&deployed_abi, &local_abi)),
+        contract_source_metadata: deployed_source_metadata
+            .zip(local_source_metadata)
+            .map(|(deployed, local)| diff_fields(&deployed, &local)),
+    })
+}
+
+fn parse_abi(data: &[u8]) -> Option<near_abi::AbiRoot> {
+    serde_json::from_slice(&zstd::decode_all(data).ok()?).ok()
+}
+
+fn parse_source_metadata(data: &[u8]) -> Option<serde_json::Value> {
+    serde_json::from_slice::<serde_json::Value>(data)
+        .ok()
+        .filter(serde_json::Value::is_object)
+}
+
+fn diff_abi_methods(
+    deployed_abi: &near_abi::AbiRoot,
+    local_abi: &near_abi::AbiRoot,
+) -> AbiMethodsDiff {
+    let declarations = |abi: &near_abi::AbiRoot| {
+        let root_schema = serde_json::to_value(&abi.body.root_schema).unwrap_or_default();
+        abi.body
+            .functions
+            .iter()
+            .map(|function| {
+                let mut declaration = serde_json::to_value(function).unwrap_or_default();
+                if let Some(declaration) = declaration.as_object_mut() {
+                    declaration.remove("name");
+                    declaration.remove("doc");
+                }
+                // The types are compared by their definitions, not by their names.
+                inline_definitions(&mut declaration, &root_schema, &mut Vec::new());
+                (function.name.clone(), declaration)
+            })
+            .collect::<BTreeMap<_, _>>()
+    };
+    let deployed_methods = declarations(deployed_abi);
+    let local_methods = declarations(local_abi);
+
+    AbiMethodsDiff {
+        added: local_methods
+            .keys()
+            .filter(|name| !deployed_methods.contains_key(*name))
+            .cloned()
+            .collect(),
+        removed: deployed_methods
+            .keys()
+            .filter(|name| !local_methods.contains_key(*name))
+            .cloned()
+            .collect(),
+        changed: deployed_methods
+            .iter()
+            .filter_map(|(name, deployed_declaration)| {
+                let local_declaration = local_methods.get(name)?;
+                let fields = diff_fields(deployed_declaration, local_declaration);
+                let fields = fields
+                    .added
+                    .into_keys()
+                    .chain(fields.removed.into_keys())
+                    .chain(fields.changed.into_keys())
+                    .map(|field| field.split('.').next().unwrap_or_default().to_string())
+                    .collect::<BTreeSet<_>>();
+                (!fields.is_empty()).then(|| ChangedAbiMethod {
+                    name: name.clone(),
+                    fields: fields.into_iter().collect(),
+                })
+            })
+            .collect(),
+    }
+}
+
+/// Replaces the `$ref`s to the root schema definitions with the definitions themselves.
+/// A reference to a definition that is being inlined (a recursive type) is left as is.
+fn inline_definitions(
+    schema: &mut serde_json::Value,
+    root_schema: &serde_json::Value,
+    inlined_names: &mut Vec<String>,
+) {
+    if let Some(name) = super::abi_schema::definition_name(schema)
+        && !inlined_names
+            .iter()
+            .any(|inlined_name| inlined_name == name)
+        && let Some(definition) = root_schema
+            .get("definitions")
+            .and_then(|definitions| definitions.get(name))
+    {
+        inlined_names.push(name.to_string());
+        let mut definition = definition.clone();
+        inline_definitions(&mut definition, root_schema, inlined_names);
+        inlined_names.pop();
+        *schema = definition;
+        return;
+    }
+    match schema {
+        serde_json::Value::Object(object) => object
+            .values_mut()
+            .for_each(|value| inline_definitions(value, root_schema, inlined_names)),
+        serde_json::Value::Array(array) => array
+            .iter_mut()
+            .for_each(|value| inline_definitions(value, root_schema, inlined_names)),
+        _ => {}
+    }
+}
+
+/// Compares the leaf values of two JSON objects by their dotted paths; arrays are compared as a whole.
+fn diff_fields(deployed: &serde_json::Value, local: &serde_json::Value) -> FieldsDiff {
+    fn flatten(
+        value: &serde_json::Value,
+        path: String,
+        fields: &mut BTreeMap<String, serde_json::Value>,
+    ) {
+        match value {
+            serde_json::Value::Object(object) if !object.is_empty() => {
+                for (key, value) in object {
+                    let path = if path.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{path}.{key}")
+                    };
+                    flatten(value, path, fields);
+                }
+            }
+            value => {
+                fields.insert(path, value.clone());
+            }
+        }
+    }
+
+    let mut deployed_fields = BTreeMap::new();
+    flatten(deployed, String::new(), &mut deployed_fields);
+    let mut local_fields = BTreeMap::new();
+    flatten(local, String::new(), &mut local_fields);
+
+    let mut fields_diff = FieldsDiff {
+        added: BTreeMap::new(),
+        removed: BTreeMap::new(),
+        changed: BTreeMap::new(),
+    };
+    for (path, deployed_value) in &deployed_fields {
+        match local_fields.get(path) {
+            None => {
+                fields_diff
+                    .removed
+                    .insert(path.clone(), deployed_value.clone());
+            }
+            Some(local_value) if local_value != deployed_value => {
+                fields_diff.changed.insert(
+                    path.clone(),
+                    Change::new(deployed_value.clone(), local_value.clone()),
+                );
+            }
+            Some(_) => {}
+        }
+    }
+    for (path, local_value) in local_fields {
+        if !deployed_fields.contains_key(&path) {
+            fields_diff.added.insert(path, local_value);
+        }
+    }
+    fields_diff
+}
+
+fn print_report(report: &DiffReport) {
+    if report.code_hash.changed {
+        println!(
+            "Code hash: {} -> {}",
+            report.code_hash.deployed.red(),
+            report.code_hash.local.green()
+        );
+        println!(
+            "Code size: {} -> {} bytes",
+            report.code_size.deployed, report.code_size.local
+        );
+    } else {
+        println!(
+            "Code hash: {} (the local contract is identical to the deployed one)",
+            report.code_hash.local
+        );
+    }
+
+    print_list_diff("Exported functions", &report.exported_functions);
+    print_list_diff("Imported host functions", &report.imported_host_functions);
+
+    println!("\nABI methods:");
+    match &report.abi_methods {
+        None => println!(
+            "  not compared: the ABI (`__contract_abi`) could not be read from the deployed or the local contract"
+        ),
+        Some(abi_methods) => {
+            if abi_methods.added.is_empty()
+                && abi_methods.removed.is_empty()
+                && abi_methods.changed.is_empty()
+            {
+                println!("  no changes");
+            }
+            for name in &abi_methods.added {
+                println!("  {}", format!("+ {name}").green());
+            }
+            for name in &abi_methods.removed {
+                println!("  {}", format!("- {name}").red());
+            }
+            for method in &abi_methods.changed {
+                println!(
+                    "  {} (changed: {})",
+                    format!("~ {}", method.name).yellow(),
+                    method.fields.join(", ")
+                );
+            }
+        }
+    }
+
+    println!("\nContract source metadata:");
+    match &report.contract_source_metadata {
+        None => println!(
+            "  not compared: the contract source metadata could not be read from the deployed or the local contract"
+        ),
+        Some(fields) => {
+            if fields.added.is_empty() && fields.removed.is_empty() && fields.changed.is_empty() {
+                println!("  no changes");
+            }
+            for (field, value) in &fields.added {
+                println!("  {}", format!("+ {field}: {value}").green());
+            }
+            for (field, value) in &fields.removed {
+                println!("  {}", format!("- {field}: {value}").red());
+            }
+            for (field, change) in &fields.changed {
+                println!(
+                    "  {} {} -> {}",
+                    format!("~ {field}:").yellow(),
+                    change.deployed,
+                    change.local
+                );
+            }
+        }
+    }
+}
+
+fn print_list_diff(title: &str, list_diff: &ListDiff) {
+    println!("\n{title}:");
+    if list_diff.is_empty() {
+        println!("  no changes");
+    }
+    for name in &list_diff.added {
+        println!("  {}", format!("+ {name}").green());
+    }
+    for name in &list_diff.removed {
+        println!("  {}", format!("- {name}").red());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diffs_json_fields() {
+        let fields_diff = diff_fields(
+            &serde_json::json!({
+                "version": "1.0.0",
+                "link": "https://github.com/near/near-cli-rs",
+                "build_info": {"build_command": ["cargo", "near", "build"]},
+            }),
+            &serde_json::json!({
+                "version": "1.1.0",
+                "build_info": {
+                    "build_command": ["cargo", "near", "build"],
+                    "contract_path": "contract",
+                },
+            }),
+        );
+        assert_eq!(
+            serde_json::to_value(fields_diff).unwrap(),
+            serde_json::json!({
+                "added": {"build_info.contract_path": "contract"},
+                "removed": {"link": "https://github.com/near/near-cli-rs"},
+                "changed": {"version": {"deployed": "1.0.0", "local": "1.1.0", "changed": true}},
+            })
+        );
+    }
+
+    #[test]
+    fn diffs_abi_methods() {
+        let abi = |functions: serde_json::Value| -> near_abi::AbiRoot {
+            serde_json::from_value(serde_json::json!({
+                "schema_version": "0.4.0",
+                "metadata": {},
+                "body": {
+                    "functions": functions,
+                    "root_schema": {"$schema": "http://json-schema.org/draft-07/schema#"}
+                }
+            }))
+            .unwrap()
+        };
+        let abi_methods = diff_abi_methods(
+            &abi(serde_json::json!([
+                {"name": "get", "kind": "view", "doc": "Old docs"},
+                {"name": "set", "kind": "call"},
+                {"name": "reset", "kind": "call"},
+            ])),
+            &abi(serde_json::json!([
+                {"name": "get", "kind": "view", "doc": "New docs"},
+                {
+                    "name": "set",
+                    "kind": "call",
+                    "modifiers": ["payable"],
+                    "params": {
+                        "serialization_type": "json",
+                        "args": [{"name": "value", "type_schema": {"type": "string"}}]
+                    }
+                },
+                {"name": "migrate", "kind": "call", "modifiers": ["init"]},
+            ])),
+        );
+        assert_eq!(abi_methods.added, vec!["migrate"]);
+        assert_eq!(abi_methods.removed, vec!["reset"]);
+        assert_eq!(abi_methods.changed.len(), 1);
+        assert_eq!(abi_methods.changed[0].name, "set");
+        assert_eq!(abi_methods.changed[0].fields, vec!["modifiers", "params"]);
+    }
+
+    #[test]
+    fn diffs_abi_methods_by_referenced_definitions() {
+        let abi = |token_definition: serde_json::Value| -> near_abi::AbiRoot {
+            serde_json::from_value(serde_json::json!({
+                "schema_version": "0.4.0",
+                "metadata": {},
+                "body": {
+                    "functions": [
+                        {
+                            "name": "get_token",
+                            "kind": "view",
+                            "result": {
+                                "serialization_type": "json",
+                                "type_schema": {"$ref": "#/definitions/Token"}
+                            }
+                        },
+                        {
+                            "name": "get_tree",
+                            "kind": "view",
+                            "result": {
+                                "serialization_type": "json",
+                                "type_schema": {"$ref": "#/definitions/Tree"}
+                            }
+                        }
+                    ],
+                    "root_schema": {
+                        "$schema": "http://json-schema.org/draft-07/schema#",
+                        "definitions": {
+                            "Token": token_definition,
+                            "Tree": {
+                                "type": "object",
+                                "properties": {
+                                    "children": {"type": "array", "items": {"$ref": "#/definitions/Tree"}}
+                                }
+                            }
+                        }
+                    }
+                }
+            }))
+            .unwrap()
+        };
+        let token = serde_json::json!({
+            "type": "object",
+            "properties": {"id": {"type": "string"}}
+        });
+        let abi_methods = diff_abi_methods(&abi(token.clone()), &abi(token));
+        assert!(abi_methods.changed.is_empty());
+
+        let abi_methods = diff_abi_methods(
+            &abi(serde_json::json!({
+                "type": "object",
+                "properties": {"id": {"type": "string"}}
+            })),
+            &abi(serde_json::json!({
+                "type": "object",
+                "properties": {"id": {"type": "integer"}}
+            })),
+        );
+        assert_eq!(abi_methods.changed.len(), 1);
+        assert_eq!(abi_methods.changed[0].name, "get_token");
+        assert_eq!(abi_methods.changed[0].fields, vec!["result"]);
+    }
+}
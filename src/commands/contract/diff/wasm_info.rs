@@ -0,0 +1,321 @@
+//! Static information about a contract WASM: exported functions, imported host functions and
+//! the constant data returned by functions such as `__contract_abi`.
+
+/// How deep the calls from an exported function are followed to find the `value_return` call.
+const MAX_CALL_DEPTH: usize = 3;
+
+pub struct WasmInfo<'a> {
+    /// `(module, name)` of the imported functions, in the order of their function indices.
+    imported_functions: Vec<(&'a str, &'a str)>,
+    function_bodies: Vec<wasmparser::FunctionBody<'a>>,
+    exported_functions: Vec<(&'a str, u32)>,
+    /// Active data segments of the memory: `(offset, data)`.
+    data_segments: Vec<(u64, &'a [u8])>,
+}
+
+impl<'a> WasmInfo<'a> {
+    pub fn parse(code: &'a [u8]) -> color_eyre::eyre::Result<Self> {
+        let mut wasm_info = Self {
+            imported_functions: Vec::new(),
+            function_bodies: Vec::new(),
+            exported_functions: Vec::new(),
+            data_segments: Vec::new(),
+        };
+        for payload in wasmparser::Parser::new(0).parse_all(code) {
+            match payload? {
+                wasmparser::Payload::ImportSection(reader) => {
+                    for import in reader {
+                        let import = import?;
+                        if let wasmparser::TypeRef::Func(_) = import.ty {
+                            wasm_info
+                                .imported_functions
+                                .push((import.module, import.name));
+                        }
+                    }
+                }
+                wasmparser::Payload::ExportSection(reader) => {
+                    for export in reader {
+                        let export = export?;
+                        if export.kind == wasmparser::ExternalKind::Func {
+                            wasm_info
+                                .exported_functions
+                                .push((export.name, export.index));
+                        }
+                    }
+                }
+                wasmparser::Payload::DataSection(reader) => {
+                    for data in reader {
+                        let data = data?;
+                        if let wasmparser::DataKind::Active { offset_expr, .. } = data.kind
+                            && let Ok(wasmparser::Operator::I32Const { value }) =
+                                offset_expr.get_operators_reader().read()
+                        {
+                            wasm_info
+                                .data_segments
+                                .push((u64::from(value as u32), data.data));
+                        }
+                    }
+                }
+                wasmparser::Payload::CodeSectionEntry(body) => {
+                    wasm_info.function_bodies.push(body);
+                }
+                _ => {}
+            }
+        }
+        Ok(wasm_info)
+    }
+
+    pub fn exported_functions(&self) -> std::collections::BTreeSet<String> {
+        self.exported_functions
+            .iter()
+            .map(|(name, _)| name.to_string())
+            .collect()
+    }
+
+    /// Functions imported from the `env` module by name; imports from other modules are
+    /// prefixed with their module name.
+    pub fn imported_host_functions(&self) -> std::collections::BTreeSet<String> {
+        self.imported_functions
+            .iter()
+            .map(|(module, name)| {
+                if *module == "env" {
+                    name.to_string()
+                } else {
+                    format!("{module}.{name}")
+                }
+            })
+            .collect()
+    }
+
+    /// Finds the constant data that the exported function passes to `value_return`.
+    ///
+    /// The pointer and the length of the data are expected to be constants right before the call
+    /// to `value_return` (or to a function that calls it), and every candidate is checked with `parse`.
+    pub fn static_return_value<T>(
+        &self,
+        export_name: &str,
+        parse: impl Fn(&[u8]) -> Option<T>,
+    ) -> Option<T> {
+        let value_return_index = self
+            .imported_functions
+            .iter()
+            .position(|import| *import == ("env", "value_return"))?;
+        let (_, function_index) = self
+            .exported_functions
+            .iter()
+            .find(|(name, _)| *name == export_name)?;
+        self.find_return_value(
+            *function_index,
+            value_return_index as u32,
+            MAX_CALL_DEPTH,
+            &parse,
+        )
+    }
+
+    fn find_return_value<T>(
+        &self,
+        function_index: u32,
+        value_return_index: u32,
+        depth: usize,
+        parse: &impl Fn(&[u8]) -> Option<T>,
+    ) -> Option<T> {
+        let mut constants = Vec::new();
+        for operator in self
+            .local_function_body(function_index)?
+            .get_operators_reader()
+            .ok()?
+        {
+            match operator.ok()? {
+                wasmparser::Operator::I32Const { value } => constants.push(u64::from(value as u32)),
+                wasmparser::Operator::I64Const { value } => constants.push(value as u64),
+                wasmparser::Operator::Call {
+                    function_index: callee_index,
+                } => {
+                    if let [.., first, second] = constants[..]
+                        && self.calls_function(callee_index, value_return_index, depth)
+                    {
+                        // `value_return(value_len, value_ptr)` or a `&[u8]` argument as `(ptr, len)`
+                        for (pointer, length) in [(second, first), (first, second)] {
+                            if let Some(value) = self.data(pointer, length).and_then(parse) {
+                                return Some(value);
+                            }
+                        }
+                    }
+                    if depth > 0
+                        && let Some(value) = self.find_return_value(
+                            callee_index,
+                            value_return_index,
+                            depth - 1,
+                            parse,
+                        )
+                    {
+                        return Some(value);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Whether the function is the target function or calls it (directly or through other calls).
+    fn calls_function(&self, function_index: u32, target_index: u32, depth: usize) -> bool {
+        if function_index == target_index {
+            return true;
+        }
+        if depth == 0 {
+            return false;
+        }
+        let Some(operators) = self
+            .local_function_body(function_index)
+            .and_then(|body| body.get_operators_reader().ok())
+        else {
+            return false;
+        };
+        operators.into_iter().any(|operator| {
+            matches!(operator, Ok(wasmparser::Operator::Call { function_index: callee_index })
+                if self.calls_function(callee_index, target_index, depth - 1))
+        })
+    }
+
+    fn local_function_body(&self, function_index: u32) -> Option<&wasmparser::FunctionBody<'a>> {
+        let local_index = (function_index as usize).checked_sub(self.imported_functions.len())?;
+        self.function_bodies.get(local_index)
+    }
+
+    /// The initial memory contents at `pointer..pointer + length` if a data segment covers them.
+    fn data(&self, pointer: u64, length: u64) -> Option<&'a [u8]> {
+        if length == 0 {
+            return None;
+        }
+        self.data_segments.iter().find_map(|(offset, data)| {
+            let start = usize::try_from(pointer.checked_sub(*offset)?).ok()?;
+            let end = start.checked_add(usize::try_from(length).ok()?)?;
+            data.get(start..end)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leb128(mut value: usize) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 {
+                bytes.push(byte);
+                return bytes;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    fn sleb128(mut value: usize) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+            if value == 0 && byte & 0x40 == 0 {
+                bytes.push(byte);
+                return bytes;
+            }
+            bytes.push(byte | 0x80);
+        }
+    }
+
+    fn section(id: u8, content: Vec<u8>) -> Vec<u8> {
+        [vec![id], leb128(content.len()), content].concat()
+    }
+
+    fn name(name: &str) -> Vec<u8> {
+        [leb128(name.len()), name.as_bytes().to_vec()].concat()
+    }
+
+    /// A contract that returns `data` (stored at the offset 1024) from the exported `get_data`.
+    fn contract(data: &[u8]) -> Vec<u8> {
+        let body = [
+            // i64.const <data length>
+            vec![0x00, 0x42],
+            sleb128(data.len()),
+            // i64.const 1024; call 0 (value_return); end
+            vec![0x42, 0x80, 0x08, 0x10, 0x00, 0x0b],
+        ]
+        .concat();
+        [
+            b"\0asm\x01\0\0\0".to_vec(),
+            section(
+                1,
+                vec![0x02, 0x60, 0x02, 0x7e, 0x7e, 0x00, 0x60, 0x00, 0x00],
+            ),
+            section(
+                2,
+                [
+                    vec![0x01],
+                    name("env"),
+                    name("value_return"),
+                    vec![0x00, 0x00],
+                ]
+                .concat(),
+            ),
+            section(3, vec![0x01, 0x01]),
+            section(5, vec![0x01, 0x00, 0x01]),
+            section(7, [vec![0x01], name("get_data"), vec![0x00, 0x01]].concat()),
+            section(10, [vec![0x01], leb128(body.len()), body].concat()),
+            section(
+                11,
+                [
+                    // Active segment of memory 0 at `i32.const 1024`
+                    vec![0x01, 0x00, 0x41, 0x80, 0x08, 0x0b],
+                    leb128(data.len()),
+                    data.to_vec(),
+                ]
+                .concat(),
+            ),
+        ]
+        .concat()
+    }
+
+    #[test]
+    fn lists_exports_and_imports() {
+        let code = contract(b"{}");
+        let wasm_info = WasmInfo::parse(&code).unwrap();
+        assert_eq!(
+            wasm_info
+                .exported_functions()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["get_data"]
+        );
+        assert_eq!(
+            wasm_info
+                .imported_host_functions()
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["value_return"]
+        );
+    }
+
+    #[test]
+    fn finds_static_return_values() {
+        let data = br#"{"version":"1.0.0","link":"https://github.com/near/near-cli-rs"}"#;
+        let code = contract(data);
+        let wasm_info = WasmInfo::parse(&code).unwrap();
+        let value = wasm_info.static_return_value("get_data", |data| {
+            serde_json::from_slice::<serde_json::Value>(data).ok()
+        });
+        assert_eq!(value.unwrap()["version"], "1.0.0");
+        assert!(
+            wasm_info
+                .static_return_value("get_data", |_| None::<()>)
+                .is_none()
+        );
+        assert!(
+            wasm_info
+                .static_return_value("__contract_abi", |data| Some(data.to_vec()))
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,89 @@
+//! A JSON document with a self-contained JSON Schema for the arguments and the result of every method.
+
+use super::ClientAbi;
+
+const JSON_SCHEMA_DRAFT: &str = "http://json-schema.org/draft-07/schema#";
+
+pub(super) fn generate(client_abi: &ClientAbi) -> color_eyre::eyre::Result<String> {
+    let methods = client_abi
+        .methods
+        .iter()
+        .map(|method| {
+            let args = serde_json::json!({
+                "type": "object",
+                "properties": method
+                    .params
+                    .iter()
+                    .map(|param| (param.name.clone(), param.schema.clone()))
+                    .collect::<serde_json::Map<_, _>>(),
+                "required": method
+                    .params
+                    .iter()
+                    .filter(|param| !param.is_optional)
+                    .map(|param| param.name.clone())
+                    .collect::<Vec<_>>(),
+                "additionalProperties": false,
+            });
+            let mut method_schemas = serde_json::json!({
+                "kind": if method.is_view { "view" } else { "call" },
+                "args": self_contained(client_abi, args),
+                "result": method.result.clone().map(|result| self_contained(client_abi, result)),
+            });
+            if let Some(doc) = super::method_doc(method) {
+                method_schemas["description"] = serde_json::Value::String(doc);
+            }
+            (method.name.clone(), method_schemas)
+        })
+        .collect::<serde_json::Map<_, _>>();
+    Ok(serde_json::to_string_pretty(&serde_json::json!({
+        "description": client_abi.header(),
+        "contract_id": client_abi.contract_account_id,
+        "methods": methods,
+    }))?)
+}
+
+/// Adds the definitions the schema may refer to, so that it can be used on its own.
+fn self_contained(client_abi: &ClientAbi, schema: serde_json::Value) -> serde_json::Value {
+    let mut schema = match schema {
+        serde_json::Value::Object(schema) => schema,
+        // `true` and `false` schemas
+        schema => return schema,
+    };
+    schema.insert(
+        "$schema".to_string(),
+        serde_json::Value::String(JSON_SCHEMA_DRAFT.to_string()),
+    );
+    if !client_abi.definitions.is_empty() {
+        schema.insert(
+            "definitions".to_string(),
+            serde_json::Value::Object(client_abi.definitions.clone()),
+        );
+    }
+    serde_json::Value::Object(schema)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn generates_json_schemas_per_method() {
+        let client: serde_json::Value =
+            serde_json::from_str(&super::generate(&super::super::tests::client_abi()).unwrap())
+                .unwrap();
+        let ft_transfer = &client["methods"]["ft_transfer"];
+        assert_eq!(ft_transfer["kind"], "call");
+        assert_eq!(
+            ft_transfer["args"]["required"],
+            serde_json::json!(["receiver_id", "amount"])
+        );
+        assert_eq!(
+            ft_transfer["args"]["definitions"]["U128"],
+            serde_json::json!({"type": "string"})
+        );
+        assert_eq!(ft_transfer["result"], serde_json::Value::Null);
+        assert_eq!(
+            client["methods"]["ft_balance_of"]["result"]["$ref"],
+            "#/definitions/U128"
+        );
+        assert!(client["methods"].get("get_raw").is_none());
+    }
+}
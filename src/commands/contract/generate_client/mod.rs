@@ -0,0 +1,486 @@
+use color_eyre::eyre::Context;
+use inquire::{CustomType, Select};
+use strum::IntoEnumIterator;
+
+mod json_schema;
+mod rust;
+mod typescript;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = ContractContext)]
+pub struct Contract {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the contract account ID?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    /// Which client do you want to generate (ts, rust or json-schema)?
+    lang: ClientLanguage,
+    #[interactive_clap(named_arg)]
+    /// Enter the file path where to save the client:
+    save_to_file: GenerateClient,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContractContext {
+    global_context: crate::GlobalContext,
+    account_id: near_primitives::types::AccountId,
+    lang: ClientLanguage,
+}
+
+impl ContractContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<Contract as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self {
+            global_context: previous_context,
+            account_id: scope.account_id.clone().into(),
+            lang: scope.lang,
+        })
+    }
+}
+
+impl Contract {
+    pub fn input_account_id(
+        context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_non_signer_account_id_from_used_account_list(
+            &context.config.credentials_home_dir,
+            "What is the contract account ID?",
+        )
+    }
+
+    pub fn input_lang(
+        _context: &crate::GlobalContext,
+    ) -> color_eyre::eyre::Result<Option<ClientLanguage>> {
+        Ok(Some(
+            Select::new(
+                "Which client do you want to generate?",
+                ClientLanguage::iter().collect(),
+            )
+            .prompt()?,
+        ))
+    }
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    strum_macros::EnumIter,
+    strum_macros::EnumString,
+    strum_macros::Display,
+)]
+pub enum ClientLanguage {
+    #[strum(serialize = "ts")]
+    TypeScript,
+    #[strum(serialize = "rust")]
+    Rust,
+    #[strum(serialize = "json-schema")]
+    JsonSchema,
+}
+
+impl interactive_clap::ToCli for ClientLanguage {
+    type CliVariant = ClientLanguage;
+}
+
+impl ClientLanguage {
+    fn file_extension(&self) -> &'static str {
+        match self {
+            Self::TypeScript => "ts",
+            Self::Rust => "rs",
+            Self::JsonSchema => "schema.json",
+        }
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = ContractContext)]
+#[interactive_clap(output_context = GenerateClientContext)]
+pub struct GenerateClient {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Enter the file path where to save the client:
+    file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct GenerateClientContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl GenerateClientContext {
+    pub fn from_previous_context(
+        previous_context: ContractContext,
+        scope: &<GenerateClient as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let account_id = previous_context.account_id.clone();
+            let lang = previous_context.lang;
+            let file_path: std::path::PathBuf = scope.file_path.clone().into();
+
+            move |network_config, block_reference| {
+                generate_client(&account_id, lang, &file_path, network_config, block_reference, previous_context.global_context.verbosity)
+            }
+        });
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.global_context.config,
+            on_after_getting_block_reference_callback,
+            interacting_with_account_ids: vec![previous_context.account_id],
+        }))
+    }
+}
+
+impl From<GenerateClientContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: GenerateClientContext) -> Self {
+        item.0
+    }
+}
+
+impl GenerateClient {
+    fn input_file_path(
+        context: &ContractContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::path_buf::PathBuf>> {
+        Ok(Some(
+            CustomType::new("Enter the file path where the client should be saved to:")
+                .with_starting_input(&format!(
+                    "{}_client.{}",
+                    context.account_id.as_str().replace(['.', '-'], "_"),
+                    context.lang.file_extension()
+                ))
+                .prompt()?,
+        ))
+    }
+}
+
+#[tracing::instrument(name = "Generating the contract client ...", skip_all)]
+fn generate_client(
+    account_id: &near_primitives::types::AccountId,
+    lang: ClientLanguage,
+    file_path: &std::path::PathBuf,
+    network_config: &crate::config::NetworkConfig,
+    block_reference: &near_primitives::types::BlockReference,
+    verbosity: crate::Verbosity,
+) -> crate::CliResult {
+    tracing::info!(target: "near_teach_me", "Generating the contract client ...");
+    let abi_root = tokio::runtime::Runtime::new()?.block_on(super::get_contract_abi(
+        &network_config.json_rpc_client(),
+        block_reference,
+        account_id,
+    ))?;
+    let client_abi = ClientAbi::new(account_id, &abi_root)?;
+    let client = match lang {
+        ClientLanguage::TypeScript => typescript::generate(&client_abi),
+        ClientLanguage::Rust => rust::generate(&client_abi),
+        ClientLanguage::JsonSchema => json_schema::generate(&client_abi)?,
+    };
+    std::fs::write(file_path, client)
+        .wrap_err_with(|| format!("Failed to write to file: {file_path:?}"))?;
+    if let crate::Verbosity::Interactive | crate::Verbosity::TeachMe = verbosity {
+        tracing_indicatif::suspend_tracing_indicatif(|| {
+            eprintln!(
+                "The {lang} client for <{account_id}> ({} functions) was saved to {file_path:?}",
+                client_abi.methods.len()
+            );
+            if !client_abi.skipped_methods.is_empty() {
+                eprintln!(
+                    "Functions with Borsh arguments or result are not supported and were skipped: {}",
+                    client_abi.skipped_methods.join(", ")
+                );
+            }
+        })
+    }
+    Ok(())
+}
+
+/// The parts of the contract ABI the client generators need.
+struct ClientAbi {
+    contract_account_id: near_primitives::types::AccountId,
+    contract_name: Option<String>,
+    contract_version: Option<String>,
+    methods: Vec<ClientMethod>,
+    /// Functions with Borsh arguments or result, which the clients do not support.
+    skipped_methods: Vec<String>,
+    /// The JSON Schema definitions the argument and result types refer to.
+    definitions: serde_json::Map<String, serde_json::Value>,
+}
+
+struct ClientMethod {
+    name: String,
+    doc: Option<String>,
+    is_view: bool,
+    is_payable: bool,
+    is_private: bool,
+    is_init: bool,
+    params: Vec<ClientParam>,
+    result: Option<serde_json::Value>,
+}
+
+struct ClientParam {
+    name: String,
+    schema: serde_json::Value,
+    /// `Option<T>` arguments may be omitted.
+    is_optional: bool,
+}
+
+impl ClientAbi {
+    fn new(
+        contract_account_id: &near_primitives::types::AccountId,
+        abi_root: &near_abi::AbiRoot,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut methods = Vec::new();
+        let mut skipped_methods = Vec::new();
+        for function in &abi_root.body.functions {
+            let near_abi::AbiParameters::Json { args } = &function.params else {
+                skipped_methods.push(function.name.clone());
+                continue;
+            };
+            let result = match &function.result {
+                None => None,
+                Some(near_abi::AbiType::Json { type_schema }) => {
+                    Some(serde_json::to_value(type_schema)?)
+                }
+                Some(near_abi::AbiType::Borsh { .. }) => {
+                    skipped_methods.push(function.name.clone());
+                    continue;
+                }
+            };
+            methods.push(ClientMethod {
+                name: function.name.clone(),
+                doc: function.doc.clone(),
+                is_view: function.kind == near_abi::AbiFunctionKind::View,
+                is_payable: function
+                    .modifiers
+                    .contains(&near_abi::AbiFunctionModifier::Payable),
+                is_private: function
+                    .modifiers
+                    .contains(&near_abi::AbiFunctionModifier::Private),
+                is_init: function
+                    .modifiers
+                    .contains(&near_abi::AbiFunctionModifier::Init),
+                params: args
+                    .iter()
+                    .map(|arg| {
+                        let schema = serde_json::to_value(&arg.type_schema)?;
+                        Ok(ClientParam {
+                            name: arg.name.clone(),
+                            is_optional: super::abi_schema::nullable_inner_schema(&schema)
+                                .is_some(),
+                            schema,
+                        })
+                    })
+                    .collect::<color_eyre::eyre::Result<_>>()?,
+                result,
+            });
+        }
+        let definitions = match serde_json::to_value(&abi_root.body.root_schema)?
+            .get_mut("definitions")
+            .map(serde_json::Value::take)
+        {
+            Some(serde_json::Value::Object(definitions)) => definitions,
+            _ => serde_json::Map::new(),
+        };
+        Ok(Self {
+            contract_account_id: contract_account_id.clone(),
+            contract_name: abi_root.metadata.name.clone(),
+            contract_version: abi_root.metadata.version.clone(),
+            methods,
+            skipped_methods,
+            definitions,
+        })
+    }
+
+    /// A one-line description of the source of the generated client.
+    fn header(&self) -> String {
+        let contract = match (&self.contract_name, &self.contract_version) {
+            (Some(name), Some(version)) => format!(" ({name} {version})"),
+            (Some(name), None) => format!(" ({name})"),
+            _ => String::new(),
+        };
+        format!(
+            "Generated by near-cli-rs from the ABI of <{}>{contract}. Do not edit: regenerate it with `near contract generate-client`.",
+            self.contract_account_id
+        )
+    }
+}
+
+/// `ft_transfer` -> `FtTransfer`
+fn to_pascal_case(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// The documentation of the method, with notes on its modifiers.
+fn method_doc(method: &ClientMethod) -> Option<String> {
+    let mut notes = Vec::new();
+    if method.is_init {
+        notes.push("Initializes the contract.");
+    }
+    if method.is_payable {
+        notes.push("Accepts an attached deposit.");
+    }
+    if method.is_private {
+        notes.push("Private: can only be called by the contract itself.");
+    }
+    let doc = method
+        .doc
+        .iter()
+        .map(|doc| doc.trim())
+        .chain(notes)
+        .collect::<Vec<_>>()
+        .join("\n");
+    (!doc.is_empty()).then_some(doc)
+}
+
+/// The properties of an object schema that describes a struct.
+fn object_properties(
+    schema: &serde_json::Value,
+) -> Option<&serde_json::Map<String, serde_json::Value>> {
+    if schema.get("type").and_then(serde_json::Value::as_str) != Some("object") {
+        return None;
+    }
+    schema
+        .get("properties")
+        .and_then(serde_json::Value::as_object)
+}
+
+/// A type name usable in the generated code (definition names may contain `<`, `,` and such).
+fn type_identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    pub(super) fn client_abi() -> ClientAbi {
+        let abi_root: near_abi::AbiRoot = serde_json::from_value(serde_json::json!({
+            "schema_version": "0.4.0",
+            "metadata": {"name": "token", "version": "1.0.0"},
+            "body": {
+                "functions": [
+                    {
+                        "name": "new",
+                        "kind": "call",
+                        "modifiers": ["init"],
+                        "params": {
+                            "serialization_type": "json",
+                            "args": [
+                                {"name": "owner_id", "type_schema": {"$ref": "#/definitions/AccountId"}}
+                            ]
+                        }
+                    },
+                    {
+                        "name": "ft_balance_of",
+                        "kind": "view",
+                        "params": {
+                            "serialization_type": "json",
+                            "args": [
+                                {"name": "account_id", "type_schema": {"$ref": "#/definitions/AccountId"}}
+                            ]
+                        },
+                        "result": {
+                            "serialization_type": "json",
+                            "type_schema": {"$ref": "#/definitions/U128"}
+                        }
+                    },
+                    {
+                        "name": "ft_transfer",
+                        "doc": " Transfers tokens.",
+                        "kind": "call",
+                        "modifiers": ["payable"],
+                        "params": {
+                            "serialization_type": "json",
+                            "args": [
+                                {"name": "receiver_id", "type_schema": {"$ref": "#/definitions/AccountId"}},
+                                {"name": "amount", "type_schema": {"$ref": "#/definitions/U128"}},
+                                {"name": "memo", "type_schema": {"type": ["string", "null"]}}
+                            ]
+                        }
+                    },
+                    {
+                        "name": "get_status",
+                        "kind": "view",
+                        "result": {
+                            "serialization_type": "json",
+                            "type_schema": {"$ref": "#/definitions/Status"}
+                        }
+                    },
+                    {
+                        "name": "get_raw",
+                        "kind": "view",
+                        "result": {
+                            "serialization_type": "borsh",
+                            "type_schema": {"declaration": "u64", "definitions": {}}
+                        }
+                    }
+                ],
+                "root_schema": {
+                    "$schema": "http://json-schema.org/draft-07/schema#",
+                    "title": "String",
+                    "type": "string",
+                    "definitions": {
+                        "AccountId": {"description": "NEAR Account Identifier.", "type": "string"},
+                        "U128": {"type": "string"},
+                        "Status": {
+                            "oneOf": [
+                                {"type": "string", "enum": ["Paused"]},
+                                {
+                                    "type": "object",
+                                    "required": ["Running"],
+                                    "properties": {
+                                        "Running": {
+                                            "type": "object",
+                                            "required": ["since", "type"],
+                                            "properties": {
+                                                "since": {"type": "integer", "format": "uint64", "minimum": 0},
+                                                "type": {"type": "string"}
+                                            }
+                                        }
+                                    },
+                                    "additionalProperties": false
+                                }
+                            ]
+                        }
+                    }
+                }
+            }
+        }))
+        .unwrap();
+        ClientAbi::new(&"token.near".parse().unwrap(), &abi_root).unwrap()
+    }
+
+    #[test]
+    fn collects_json_methods_only() {
+        let client_abi = client_abi();
+        assert_eq!(
+            client_abi
+                .methods
+                .iter()
+                .map(|method| method.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["new", "ft_balance_of", "ft_transfer", "get_status"]
+        );
+        assert_eq!(client_abi.skipped_methods, vec!["get_raw"]);
+        assert!(client_abi.methods[0].is_init);
+        assert!(client_abi.methods[2].is_payable);
+        assert!(client_abi.methods[2].params[2].is_optional);
+        let mut definition_names = client_abi.definitions.keys().collect::<Vec<_>>();
+        definition_names.sort();
+        assert_eq!(definition_names, vec!["AccountId", "Status", "U128"]);
+    }
+}
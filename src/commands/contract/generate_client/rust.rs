@@ -0,0 +1,368 @@
+//! A Rust module with serde types for the contract types and method arguments, and method
+//! wrappers that prepare function calls for whatever RPC client the project uses.
+
+use std::fmt::Write;
+
+use super::{ClientAbi, ClientMethod};
+
+const RUST_KEYWORDS: [&str; 38] = [
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+const FUNCTION_CALL_TYPE: &str = r#"
+/// A prepared function call: send it with any RPC client, then parse its result.
+#[derive(Debug, Clone)]
+pub struct FunctionCall<R> {
+    pub contract_id: String,
+    pub method_name: &'static str,
+    /// JSON-serialized arguments.
+    pub args: Vec<u8>,
+    /// View methods are called with the `call_function` RPC query, others with a transaction.
+    pub is_view: bool,
+    result: std::marker::PhantomData<R>,
+}
+
+impl<R: serde::de::DeserializeOwned> FunctionCall<R> {
+    fn new<A: Serialize>(contract_id: &str, method_name: &'static str, args: &A, is_view: bool) -> Self {
+        Self {
+            contract_id: contract_id.to_string(),
+            method_name,
+            args: serde_json::to_vec(args).expect("the arguments are serializable to JSON"),
+            is_view,
+            result: std::marker::PhantomData,
+        }
+    }
+
+    /// Parses the JSON return value of the function.
+    pub fn parse_result(&self, result: &[u8]) -> serde_json::Result<R> {
+        if result.is_empty() {
+            serde_json::from_slice(b"null")
+        } else {
+            serde_json::from_slice(result)
+        }
+    }
+}
+"#;
+
+pub(super) fn generate(client_abi: &ClientAbi) -> String {
+    let mut output = format!(
+        "//! {}\n\n#![allow(dead_code, clippy::all)]\n\nuse serde::{{Deserialize, Serialize}};\n",
+        client_abi.header()
+    );
+
+    for (name, schema) in &client_abi.definitions {
+        output.push('\n');
+        write_doc(
+            &mut output,
+            schema
+                .get("description")
+                .and_then(serde_json::Value::as_str),
+            "",
+        );
+        write_definition(&mut output, &super::type_identifier(name), schema);
+    }
+
+    output.push_str("\npub mod args {\n    use super::*;\n");
+    for method in &client_abi.methods {
+        output.push('\n');
+        let _ = writeln!(
+            output,
+            "    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\n    pub struct {} {{",
+            args_type_name(method)
+        );
+        for param in &method.params {
+            let (field_name, rename) = field_identifier(&param.name);
+            if let Some(rename) = rename {
+                let _ = writeln!(output, "        #[serde(rename = {rename:?})]");
+            }
+            if param.is_optional {
+                output.push_str(
+                    "        #[serde(default, skip_serializing_if = \"Option::is_none\")]\n",
+                );
+            }
+            let _ = writeln!(
+                output,
+                "        pub {field_name}: {},",
+                rust_type(&param.schema)
+            );
+        }
+        output.push_str("    }\n");
+    }
+    output.push_str("}\n");
+
+    output.push_str(FUNCTION_CALL_TYPE);
+
+    let _ = writeln!(
+        output,
+        "\n/// The methods of the contract.\n#[derive(Debug, Clone)]\npub struct Client {{\n    pub contract_id: String,\n}}\n\nimpl Default for Client {{\n    fn default() -> Self {{\n        Self::new({:?})\n    }}\n}}\n\nimpl Client {{\n    pub fn new(contract_id: impl Into<String>) -> Self {{\n        Self {{\n            contract_id: contract_id.into(),\n        }}\n    }}",
+        client_abi.contract_account_id.as_str()
+    );
+    for method in &client_abi.methods {
+        output.push('\n');
+        write_doc(&mut output, super::method_doc(method).as_deref(), "    ");
+        let result = method
+            .result
+            .as_ref()
+            .map_or_else(|| "()".to_string(), rust_type);
+        let _ = writeln!(
+            output,
+            "    pub fn {}(&self, args: &args::{}) -> FunctionCall<{result}> {{\n        FunctionCall::new(&self.contract_id, {:?}, args, {})\n    }}",
+            method_identifier(method),
+            args_type_name(method),
+            method.name,
+            method.is_view
+        );
+    }
+    output.push_str("}\n");
+    output
+}
+
+fn write_definition(output: &mut String, name: &str, schema: &serde_json::Value) {
+    const DERIVES: &str = "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]";
+
+    if let Some(properties) = super::object_properties(schema) {
+        let _ = writeln!(output, "{DERIVES}\npub struct {name} {{");
+        write_fields(output, schema, properties, "    ", "pub ");
+        output.push_str("}\n");
+        return;
+    }
+    let string_values = schema
+        .get("enum")
+        .and_then(serde_json::Value::as_array)
+        .and_then(|values| {
+            values
+                .iter()
+                .map(serde_json::Value::as_str)
+                .collect::<Option<Vec<_>>>()
+        });
+    if let Some(values) = string_values {
+        let _ = writeln!(output, "{DERIVES}\npub enum {name} {{");
+        for value in values {
+            write_variant_name(output, value);
+            output.push_str(",\n");
+        }
+        output.push_str("}\n");
+        return;
+    }
+    if let Some(variants) = schema
+        .get("oneOf")
+        .and_then(serde_json::Value::as_array)
+        .and_then(|variants| {
+            variants
+                .iter()
+                .map(enum_variant)
+                .collect::<Option<Vec<_>>>()
+        })
+    {
+        let _ = writeln!(output, "{DERIVES}\npub enum {name} {{");
+        for (variant_name, variant_schema) in variants {
+            write_variant_name(output, variant_name);
+            match variant_schema {
+                None => output.push_str(",\n"),
+                Some(variant_schema) => match super::object_properties(variant_schema) {
+                    Some(properties) => {
+                        output.push_str(" {\n");
+                        write_fields(output, variant_schema, properties, "        ", "");
+                        output.push_str("    },\n");
+                    }
+                    None => {
+                        let _ = writeln!(output, "({}),", rust_type(variant_schema));
+                    }
+                },
+            }
+        }
+        output.push_str("}\n");
+        return;
+    }
+    let _ = writeln!(output, "pub type {name} = {};", rust_type(schema));
+}
+
+/// A variant of an externally tagged serde enum: either a string (unit variant)
+/// or an object with a single property named after the variant.
+fn enum_variant(schema: &serde_json::Value) -> Option<(&str, Option<&serde_json::Value>)> {
+    if let Some([serde_json::Value::String(name)]) = schema
+        .get("enum")
+        .and_then(serde_json::Value::as_array)
+        .map(Vec::as_slice)
+    {
+        return Some((name, None));
+    }
+    let properties = super::object_properties(schema)?;
+    if properties.len() != 1 {
+        return None;
+    }
+    properties
+        .iter()
+        .next()
+        .map(|(name, variant_schema)| (name.as_str(), Some(variant_schema)))
+}
+
+fn write_variant_name(output: &mut String, name: &str) {
+    let variant_name = super::to_pascal_case(name);
+    if variant_name != name {
+        let _ = write!(
+            output,
+            "    #[serde(rename = {name:?})]\n    {variant_name}"
+        );
+    } else {
+        let _ = write!(output, "    {variant_name}");
+    }
+}
+
+fn write_fields(
+    output: &mut String,
+    schema: &serde_json::Value,
+    properties: &serde_json::Map<String, serde_json::Value>,
+    indent: &str,
+    visibility: &str,
+) {
+    let required = schema
+        .get("required")
+        .and_then(serde_json::Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    for (name, property_schema) in properties {
+        write_doc(
+            output,
+            property_schema
+                .get("description")
+                .and_then(serde_json::Value::as_str),
+            indent,
+        );
+        let (field_name, rename) = field_identifier(name);
+        if let Some(rename) = rename {
+            let _ = writeln!(output, "{indent}#[serde(rename = {rename:?})]");
+        }
+        let mut field_type = rust_type(property_schema);
+        if !required.contains(&serde_json::Value::String(name.clone())) {
+            if !field_type.starts_with("Option<") {
+                field_type = format!("Option<{field_type}>");
+            }
+            let _ = writeln!(
+                output,
+                "{indent}#[serde(default, skip_serializing_if = \"Option::is_none\")]"
+            );
+        }
+        let _ = writeln!(output, "{indent}{visibility}{field_name}: {field_type},");
+    }
+}
+
+fn rust_type(schema: &serde_json::Value) -> String {
+    if let Some(name) = crate::commands::contract::abi_schema::definition_name(schema) {
+        return super::type_identifier(name);
+    }
+    if let Some(inner_schema) = crate::commands::contract::abi_schema::nullable_inner_schema(schema)
+    {
+        return format!("Option<{}>", rust_type(&inner_schema));
+    }
+    if let Some(serde_json::Value::Array(all_of)) = schema.get("allOf")
+        && let [inner_schema] = all_of.as_slice()
+    {
+        return rust_type(inner_schema);
+    }
+    match schema.get("type").and_then(serde_json::Value::as_str) {
+        Some("string") => "String".to_string(),
+        Some("integer") => match schema.get("format").and_then(serde_json::Value::as_str) {
+            Some("uint8") => "u8",
+            Some("uint16") => "u16",
+            Some("uint32") => "u32",
+            Some("uint64") => "u64",
+            Some("uint") => "usize",
+            Some("int8") => "i8",
+            Some("int16") => "i16",
+            Some("int32") => "i32",
+            Some("int") => "isize",
+            _ => "i64",
+        }
+        .to_string(),
+        Some("number") => "f64".to_string(),
+        Some("boolean") => "bool".to_string(),
+        Some("null") => "()".to_string(),
+        Some("array") => match schema.get("items") {
+            Some(serde_json::Value::Array(items)) => format!(
+                "({},)",
+                items.iter().map(rust_type).collect::<Vec<_>>().join(", ")
+            ),
+            Some(items) => format!("Vec<{}>", rust_type(items)),
+            None => "Vec<serde_json::Value>".to_string(),
+        },
+        Some("object") if super::object_properties(schema).is_none() => {
+            match schema.get("additionalProperties") {
+                Some(values) if values.is_object() => {
+                    format!("std::collections::HashMap<String, {}>", rust_type(values))
+                }
+                _ => "serde_json::Map<String, serde_json::Value>".to_string(),
+            }
+        }
+        // Anonymous objects and unions have no Rust name.
+        _ => "serde_json::Value".to_string(),
+    }
+}
+
+fn write_doc(output: &mut String, doc: Option<&str>, indent: &str) {
+    let Some(doc) = doc.map(str::trim).filter(|doc| !doc.is_empty()) else {
+        return;
+    };
+    for line in doc.lines() {
+        let _ = writeln!(output, "{indent}/// {}", line.trim());
+    }
+}
+
+fn args_type_name(method: &ClientMethod) -> String {
+    super::to_pascal_case(&method.name)
+}
+
+/// The name of the method wrapper; `new` and `default` are taken by the `Client` constructors.
+fn method_identifier(method: &ClientMethod) -> String {
+    let mut identifier = field_identifier(&method.name).0;
+    if ["new", "default"].contains(&identifier.as_str()) {
+        identifier.push('_');
+    }
+    identifier
+}
+
+/// A Rust identifier for the JSON field name, with the name to rename it to if they differ.
+fn field_identifier(name: &str) -> (String, Option<&str>) {
+    let mut identifier = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if identifier.is_empty() || identifier.starts_with(|c: char| c.is_ascii_digit()) {
+        identifier.insert(0, '_');
+    }
+    if RUST_KEYWORDS.contains(&identifier.as_str()) {
+        if ["crate", "self", "Self", "super"].contains(&identifier.as_str()) {
+            identifier.push('_');
+        } else {
+            return (format!("r#{identifier}"), None);
+        }
+    }
+    let rename = (identifier != name).then_some(name);
+    (identifier, rename)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn generates_rust_module() {
+        let client = super::generate(&super::super::tests::client_abi());
+        for expected in [
+            "pub type U128 = String;",
+            "/// NEAR Account Identifier.\npub type AccountId = String;",
+            "pub enum Status {\n    Paused,\n    Running {\n        since: u64,\n        r#type: String,\n    },\n}",
+            "    pub struct FtTransfer {\n        pub receiver_id: AccountId,\n        pub amount: U128,\n        #[serde(default, skip_serializing_if = \"Option::is_none\")]\n        pub memo: Option<String>,\n    }",
+            "    pub fn ft_balance_of(&self, args: &args::FtBalanceOf) -> FunctionCall<U128> {\n        FunctionCall::new(&self.contract_id, \"ft_balance_of\", args, true)\n    }",
+            "    /// Transfers tokens.\n    /// Accepts an attached deposit.\n    pub fn ft_transfer(&self, args: &args::FtTransfer) -> FunctionCall<()> {",
+            "        Self::new(\"token.near\")",
+            "    /// Initializes the contract.\n    pub fn new_(&self, args: &args::New) -> FunctionCall<()> {\n        FunctionCall::new(&self.contract_id, \"new\", args, false)\n    }",
+        ] {
+            assert!(
+                client.contains(expected),
+                "{expected}\n\nnot found in:\n{client}"
+            );
+        }
+    }
+}
@@ -0,0 +1,278 @@
+//! A TypeScript module with the contract types and an interface of the contract methods.
+
+use std::fmt::Write;
+
+use super::{ClientAbi, ClientMethod};
+
+pub(super) fn generate(client_abi: &ClientAbi) -> String {
+    let mut output = format!("// {}\n", client_abi.header());
+
+    for (name, schema) in &client_abi.definitions {
+        output.push('\n');
+        write_doc(
+            &mut output,
+            schema
+                .get("description")
+                .and_then(serde_json::Value::as_str),
+            "",
+        );
+        let name = super::type_identifier(name);
+        match super::object_properties(schema) {
+            Some(properties) => {
+                let _ = writeln!(output, "export interface {name} {{");
+                write_properties(&mut output, schema, properties, "  ");
+                output.push_str("}\n");
+            }
+            None => {
+                let _ = writeln!(output, "export type {name} = {};", ts_type(schema, ""));
+            }
+        }
+    }
+
+    for method in &client_abi.methods {
+        if method.params.is_empty() {
+            continue;
+        }
+        output.push('\n');
+        let _ = writeln!(output, "export interface {} {{", args_type_name(method));
+        for param in &method.params {
+            let _ = writeln!(
+                output,
+                "  {}{}: {};",
+                property_name(&param.name),
+                if param.is_optional { "?" } else { "" },
+                ts_type(&param.schema, "  ")
+            );
+        }
+        output.push_str("}\n");
+    }
+
+    output.push_str(
+        "\n/** Options of a change method call: `gas` in gas units and `deposit` in yoctoNEAR. */\n\
+         export interface CallOptions {\n  gas?: string;\n  deposit?: string;\n}\n",
+    );
+
+    for (interface_name, is_view) in [
+        ("ContractViewMethods", true),
+        ("ContractChangeMethods", false),
+    ] {
+        output.push('\n');
+        let _ = writeln!(output, "export interface {interface_name} {{");
+        for method in client_abi
+            .methods
+            .iter()
+            .filter(|method| method.is_view == is_view)
+        {
+            write_doc(&mut output, super::method_doc(method).as_deref(), "  ");
+            let args = if method.params.is_empty() {
+                String::new()
+            } else if method.params.iter().all(|param| param.is_optional) {
+                format!("args?: {}", args_type_name(method))
+            } else {
+                format!("args: {}", args_type_name(method))
+            };
+            let options = if is_view {
+                String::new()
+            } else if args.is_empty() {
+                "options?: CallOptions".to_string()
+            } else {
+                ", options?: CallOptions".to_string()
+            };
+            let result = method
+                .result
+                .as_ref()
+                .map_or_else(|| "void".to_string(), |result| ts_type(result, "  "));
+            let _ = writeln!(
+                output,
+                "  {}({args}{options}): Promise<{result}>;",
+                property_name(&method.name)
+            );
+        }
+        output.push_str("}\n");
+    }
+
+    for (constant_name, is_view) in [("viewMethods", true), ("changeMethods", false)] {
+        let _ = writeln!(
+            output,
+            "\nexport const {constant_name} = [{}] as const;",
+            client_abi
+                .methods
+                .iter()
+                .filter(|method| method.is_view == is_view)
+                .map(|method| format!("{:?}", method.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    let _ = writeln!(
+        output,
+        "\nexport const contractId = {:?};",
+        client_abi.contract_account_id.as_str()
+    );
+    output
+}
+
+/// The TypeScript type of the schema; inline object types are indented as if written at `indent`.
+fn ts_type(schema: &serde_json::Value, indent: &str) -> String {
+    if let Some(name) = crate::commands::contract::abi_schema::definition_name(schema) {
+        return super::type_identifier(name);
+    }
+    if let Some(serde_json::Value::Array(all_of)) = schema.get("allOf")
+        && let [inner_schema] = all_of.as_slice()
+    {
+        return ts_type(inner_schema, indent);
+    }
+    if let Some(serde_json::Value::Array(values)) = schema.get("enum") {
+        return union(values.iter().map(serde_json::Value::to_string));
+    }
+    if let Some(value) = schema.get("const") {
+        return value.to_string();
+    }
+    if let Some(serde_json::Value::Array(variants)) =
+        schema.get("oneOf").or_else(|| schema.get("anyOf"))
+    {
+        return union(variants.iter().map(|variant| ts_type(variant, indent)));
+    }
+    if let Some(serde_json::Value::Array(types)) = schema.get("type") {
+        return union(types.iter().map(|value_type| {
+            let mut single_type_schema = schema.clone();
+            single_type_schema["type"] = value_type.clone();
+            ts_type(&single_type_schema, indent)
+        }));
+    }
+    match schema.get("type").and_then(serde_json::Value::as_str) {
+        Some("string") => "string".to_string(),
+        Some("integer" | "number") => "number".to_string(),
+        Some("boolean") => "boolean".to_string(),
+        Some("null") => "null".to_string(),
+        Some("array") => match schema.get("items") {
+            Some(serde_json::Value::Array(items)) => {
+                format!(
+                    "[{}]",
+                    items
+                        .iter()
+                        .map(|item| ts_type(item, indent))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Some(items) => {
+                let item_type = ts_type(items, indent);
+                if item_type.contains(' ') {
+                    format!("({item_type})[]")
+                } else {
+                    format!("{item_type}[]")
+                }
+            }
+            None => "unknown[]".to_string(),
+        },
+        Some("object") => match super::object_properties(schema) {
+            Some(properties) => {
+                let mut output = "{\n".to_string();
+                write_properties(&mut output, schema, properties, &format!("{indent}  "));
+                output.push_str(indent);
+                output.push('}');
+                output
+            }
+            None => match schema.get("additionalProperties") {
+                Some(values) if values.is_object() => {
+                    format!("Record<string, {}>", ts_type(values, indent))
+                }
+                _ => "Record<string, unknown>".to_string(),
+            },
+        },
+        _ => "unknown".to_string(),
+    }
+}
+
+fn write_properties(
+    output: &mut String,
+    schema: &serde_json::Value,
+    properties: &serde_json::Map<String, serde_json::Value>,
+    indent: &str,
+) {
+    let required = schema
+        .get("required")
+        .and_then(serde_json::Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+    for (name, property_schema) in properties {
+        write_doc(
+            output,
+            property_schema
+                .get("description")
+                .and_then(serde_json::Value::as_str),
+            indent,
+        );
+        let is_required = required.contains(&serde_json::Value::String(name.clone()));
+        let _ = writeln!(
+            output,
+            "{indent}{}{}: {};",
+            property_name(name),
+            if is_required { "" } else { "?" },
+            ts_type(property_schema, indent)
+        );
+    }
+}
+
+fn union(types: impl Iterator<Item = String>) -> String {
+    let mut types = types.collect::<Vec<_>>();
+    types.dedup();
+    if types.is_empty() {
+        "never".to_string()
+    } else {
+        types.join(" | ")
+    }
+}
+
+fn write_doc(output: &mut String, doc: Option<&str>, indent: &str) {
+    let Some(doc) = doc.map(str::trim).filter(|doc| !doc.is_empty()) else {
+        return;
+    };
+    let _ = writeln!(output, "{indent}/**");
+    for line in doc.lines() {
+        let _ = writeln!(output, "{indent} * {}", line.trim().replace("*/", "* /"));
+    }
+    let _ = writeln!(output, "{indent} */");
+}
+
+fn args_type_name(method: &ClientMethod) -> String {
+    format!("{}Args", super::to_pascal_case(&method.name))
+}
+
+fn property_name(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("{name:?}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn generates_typescript_interfaces() {
+        let client = super::generate(&super::super::tests::client_abi());
+        for expected in [
+            "export type U128 = string;",
+            "/**\n * NEAR Account Identifier.\n */\nexport type AccountId = string;",
+            "export type Status = \"Paused\" | {\n  Running: {\n    since: number;\n    type: string;\n  };\n};",
+            "export interface FtTransferArgs {\n  receiver_id: AccountId;\n  amount: U128;\n  memo?: string | null;\n}",
+            "  ft_balance_of(args: FtBalanceOfArgs): Promise<U128>;",
+            "  /**\n   * Transfers tokens.\n   * Accepts an attached deposit.\n   */\n  ft_transfer(args: FtTransferArgs, options?: CallOptions): Promise<void>;",
+            "export const viewMethods = [\"ft_balance_of\", \"get_status\"] as const;",
+        ] {
+            assert!(
+                client.contains(expected),
+                "{expected}\n\nnot found in:\n{client}"
+            );
+        }
+    }
+}
@@ -45,6 +45,7 @@ impl ContractContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let account_id: near_primitives::types::AccountId = scope.contract_account_id.clone().into();
+            let output_format = previous_context.output_format;
 
             move |network_config, block_reference| {
                 tokio::runtime::Runtime::new()
@@ -53,6 +54,7 @@ impl ContractContext {
                         &account_id,
                         network_config,
                         block_reference,
+                        output_format,
                     ))
             }
         });
@@ -99,13 +101,14 @@ async fn display_inspect_contract(
     account_id: &near_primitives::types::AccountId,
     network_config: &crate::config::NetworkConfig,
     block_reference: &near_primitives::types::BlockReference,
+    output_format: crate::common::OutputFormat,
 ) -> crate::CliResult {
     tracing::info!(target: "near_teach_me", "Contract inspection ...");
     let json_rpc_client = network_config.json_rpc_client();
     let view_code_response = get_contract_code(account_id, network_config, block_reference).await?;
     let contract_code_view =
         if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewCode(result) =
-            view_code_response.kind
+            &view_code_response.kind
         {
             result
         } else {
@@ -128,6 +131,19 @@ async fn display_inspect_contract(
     )
     .await?;
 
+    if let crate::common::OutputFormat::Json = output_format {
+        return print_inspect_contract_json(
+            account_id,
+            &json_rpc_client,
+            block_reference,
+            &view_code_response,
+            contract_code_view,
+            &account_view,
+            &access_keys,
+        )
+        .await;
+    }
+
     let mut table = prettytable::Table::new();
     table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
 
@@ -379,32 +395,105 @@ async fn display_inspect_contract(
                 "Functions:".yellow()
             );
 
-            let parser = wasmparser::Parser::new(0);
-            for payload in parser.parse_all(&contract_code_view.code) {
-                if let wasmparser::Payload::ExportSection(export_section) =
-                    payload.wrap_err_with(|| {
-                        format!(
-                            "Could not parse WebAssembly binary of the contract <{account_id}>."
-                        )
-                    })?
-                {
-                    for export in export_section {
-                        let export = export
-                            .wrap_err_with(|| format!("Could not parse WebAssembly export section of the contract <{account_id}>."))?;
-                        if let wasmparser::ExternalKind::Func = export.kind {
-                            println!(
-                                " fn {}({}) -> {}\n",
-                                export.name.green(),
-                                "...".yellow(),
-                                "...".blue()
-                            );
-                        }
-                    }
+            for function_name in get_exported_function_names(account_id, &contract_code_view.code)?
+            {
+                println!(
+                    " fn {}({}) -> {}\n",
+                    function_name.green(),
+                    "...".yellow(),
+                    "...".blue()
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The names of the functions that the contract code exports.
+fn get_exported_function_names(
+    account_id: &near_primitives::types::AccountId,
+    code: &[u8],
+) -> color_eyre::eyre::Result<Vec<String>> {
+    let mut function_names = Vec::new();
+    for payload in wasmparser::Parser::new(0).parse_all(code) {
+        if let wasmparser::Payload::ExportSection(export_section) = payload.wrap_err_with(|| {
+            format!("Could not parse WebAssembly binary of the contract <{account_id}>.")
+        })? {
+            for export in export_section {
+                let export = export.wrap_err_with(|| {
+                    format!(
+                        "Could not parse WebAssembly export section of the contract <{account_id}>."
+                    )
+                })?;
+                if let wasmparser::ExternalKind::Func = export.kind {
+                    function_names.push(export.name.to_string());
                 }
             }
         }
     }
+    Ok(function_names)
+}
 
+async fn print_inspect_contract_json(
+    account_id: &near_primitives::types::AccountId,
+    json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
+    block_reference: &near_primitives::types::BlockReference,
+    view_code_response: &near_jsonrpc_client::methods::query::RpcQueryResponse,
+    contract_code_view: &near_primitives::views::ContractCodeView,
+    account_view: &near_primitives::views::AccountView,
+    access_keys: &[near_primitives::views::AccessKeyInfoView],
+) -> crate::CliResult {
+    let full_access_keys_count = access_keys
+        .iter()
+        .filter(|access_key| {
+            matches!(
+                access_key.access_key.permission,
+                near_primitives::views::AccessKeyPermissionView::FullAccess
+            )
+        })
+        .count();
+    let (source_metadata, source_metadata_error) =
+        match get_contract_source_metadata(json_rpc_client, block_reference, account_id).await {
+            Ok(contract_source_metadata) => (Some(contract_source_metadata), None),
+            Err(err) => (None, Some(err.to_string())),
+        };
+    let (abi_schema_version, abi_error, functions) =
+        match super::get_contract_abi(json_rpc_client, block_reference, account_id).await {
+            Ok(abi_root) => (
+                Some(abi_root.schema_version),
+                None,
+                abi_root
+                    .body
+                    .functions
+                    .into_iter()
+                    .map(|function| function.name)
+                    .collect::<Vec<_>>(),
+            ),
+            Err(err) => (
+                None,
+                Some(err.to_string()),
+                get_exported_function_names(account_id, &contract_code_view.code)?,
+            ),
+        };
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&serde_json::json!({
+            "contract_account_id": account_id,
+            "block_height": view_code_response.block_height,
+            "block_hash": view_code_response.block_hash,
+            "contract": crate::common::account_contract_json(account_view),
+            "code_size": contract_code_view.code.len(),
+            "storage_usage": account_view.storage_usage,
+            "full_access_keys": full_access_keys_count,
+            "function_call_access_keys": access_keys.len() - full_access_keys_count,
+            "source_metadata": source_metadata,
+            "source_metadata_error": source_metadata_error,
+            "abi_schema_version": abi_schema_version,
+            "abi_error": abi_error,
+            "functions": functions,
+        }))?
+    );
     Ok(())
 }
 
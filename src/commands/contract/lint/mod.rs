@@ -0,0 +1,599 @@
+use color_eyre::eyre::{Context, eyre};
+use color_eyre::owo_colors::OwoColorize;
+use prettytable::Table;
+
+/// The `max_contract_size` of the NEAR protocol config, used when no network connection is given.
+pub const DEFAULT_MAX_CONTRACT_SIZE: u64 = 4 * 1024 * 1024;
+
+/// Operator proposals supported by the NEAR runtime.
+const SUPPORTED_PROPOSALS: [&str; 2] = ["mvp", "sign_extension"];
+
+/// Host functions that the NEAR runtime provides in the `env` module.
+const NEAR_HOST_FUNCTIONS: &[&str] = &[
+    // Registers
+    "read_register",
+    "register_len",
+    "write_register",
+    // Context
+    "current_account_id",
+    "current_contract_code",
+    "refund_to_account_id",
+    "signer_account_id",
+    "signer_account_pk",
+    "predecessor_account_id",
+    "input",
+    "block_index",
+    "block_timestamp",
+    "epoch_height",
+    "storage_usage",
+    // Economics
+    "account_balance",
+    "account_locked_balance",
+    "attached_deposit",
+    "prepaid_gas",
+    "used_gas",
+    // Math
+    "random_seed",
+    "sha256",
+    "keccak256",
+    "keccak512",
+    "ripemd160",
+    "ecrecover",
+    "ed25519_verify",
+    "alt_bn128_g1_multiexp",
+    "alt_bn128_g1_sum",
+    "alt_bn128_pairing_check",
+    "bls12381_p1_sum",
+    "bls12381_p2_sum",
+    "bls12381_g1_multiexp",
+    "bls12381_g2_multiexp",
+    "bls12381_map_fp_to_g1",
+    "bls12381_map_fp2_to_g2",
+    "bls12381_pairing_check",
+    "bls12381_p1_decompress",
+    "bls12381_p2_decompress",
+    // Miscellaneous
+    "value_return",
+    "panic",
+    "panic_utf8",
+    "log_utf8",
+    "log_utf16",
+    "abort",
+    // Promises
+    "promise_create",
+    "promise_then",
+    "promise_and",
+    "promise_batch_create",
+    "promise_batch_then",
+    "promise_set_refund_to",
+    "promise_batch_action_create_account",
+    "promise_batch_action_deploy_contract",
+    "promise_batch_action_deploy_global_contract",
+    "promise_batch_action_deploy_global_contract_by_account_id",
+    "promise_batch_action_use_global_contract",
+    "promise_batch_action_use_global_contract_by_account_id",
+    "promise_batch_action_function_call",
+    "promise_batch_action_function_call_weight",
+    "promise_batch_action_transfer",
+    "promise_batch_action_stake",
+    "promise_batch_action_add_key_with_full_access",
+    "promise_batch_action_add_key_with_function_call",
+    "promise_batch_action_delete_key",
+    "promise_batch_action_delete_account",
+    "promise_batch_action_state_init",
+    "promise_batch_action_state_init_by_account_id",
+    "set_state_init_data_entry",
+    "promise_yield_create",
+    "promise_yield_resume",
+    "promise_results_count",
+    "promise_result",
+    "promise_return",
+    // Storage
+    "storage_write",
+    "storage_read",
+    "storage_remove",
+    "storage_has_key",
+    "storage_iter_prefix",
+    "storage_iter_range",
+    "storage_iter_next",
+    // Validators
+    "validator_stake",
+    "validator_total_stake",
+    // Gas metering injected into older contracts
+    "gas",
+    // Sandbox only
+    "sandbox_debug_log",
+    "sleep_nanos",
+];
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = LintContext)]
+pub struct Lint {
+    /// What is the file location of the contract?
+    file_path: crate::types::path_buf::PathBuf,
+    /// Check the contract size against the `max_contract_size` of this network connection (default: 4 MiB)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    connection_name: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LintContext;
+
+impl LintContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<Lint as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let code = std::fs::read(&scope.file_path).wrap_err_with(|| {
+            format!("Failed to open or read the file: {:?}.", scope.file_path.0)
+        })?;
+
+        let max_contract_size = match &scope.connection_name {
+            Some(connection_name) => {
+                let network_config = previous_context
+                    .config
+                    .network_connection
+                    .get(connection_name)
+                    .ok_or_else(|| eyre!("Network connection \"{connection_name}\" not found"))?;
+                if previous_context.offline {
+                    return Err(eyre!(
+                        "Cannot get the protocol config of \"{connection_name}\" in offline mode"
+                    ));
+                }
+                get_max_contract_size(network_config)?
+            }
+            None => DEFAULT_MAX_CONTRACT_SIZE,
+        };
+
+        let issues = lint_wasm(&code, max_contract_size);
+        let errors_count = issues
+            .iter()
+            .filter(|issue| issue.severity == LintSeverity::Error)
+            .count();
+
+        if let crate::common::OutputFormat::Json = previous_context.output_format {
+            let messages = |severity: LintSeverity| {
+                issues
+                    .iter()
+                    .filter(|issue| issue.severity == severity)
+                    .map(|issue| issue.message.clone())
+                    .collect::<Vec<_>>()
+            };
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "file_path": scope.file_path.0,
+                    "size": code.len(),
+                    "max_contract_size": max_contract_size,
+                    "errors": messages(LintSeverity::Error),
+                    "warnings": messages(LintSeverity::Warning),
+                }))?
+            );
+        } else if issues.is_empty() {
+            println!(
+                "{} {:?} ({} bytes) passed all checks.",
+                "The contract".green(),
+                scope.file_path.0,
+                code.len()
+            );
+        } else {
+            let mut table = Table::new();
+            table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+            table.set_titles(prettytable::row![Fg->"Severity", Fg->"Issue"]);
+            for issue in &issues {
+                match issue.severity {
+                    LintSeverity::Error => {
+                        table.add_row(prettytable::row![Fr->issue.severity, issue.message])
+                    }
+                    LintSeverity::Warning => {
+                        table.add_row(prettytable::row![Fy->issue.severity, issue.message])
+                    }
+                };
+            }
+            println!("Contract {:?} ({} bytes):", scope.file_path.0, code.len());
+            table.printstd();
+        }
+
+        if errors_count > 0 {
+            return Err(eyre!(
+                "The contract has {errors_count} issue(s) that prevent it from being deployed on NEAR"
+            ));
+        }
+        Ok(Self)
+    }
+}
+
+#[tracing::instrument(name = "Getting the protocol config ...", skip_all)]
+/// Refuses to deploy code that the NEAR runtime of the network would reject; warnings are only printed.
+///
+/// The `max_contract_size` is taken from the protocol config of the network, or the default one in offline mode.
+pub fn lint_code_before_deploy(
+    code: &[u8],
+    network_config: &crate::config::NetworkConfig,
+    offline: bool,
+) -> color_eyre::eyre::Result<()> {
+    let max_contract_size = if offline {
+        DEFAULT_MAX_CONTRACT_SIZE
+    } else {
+        get_max_contract_size(network_config)?
+    };
+    let (errors, warnings): (Vec<_>, Vec<_>) = lint_wasm(code, max_contract_size)
+        .into_iter()
+        .partition(|issue| issue.severity == LintSeverity::Error);
+    for warning in warnings {
+        tracing::warn!("{}", warning.message);
+    }
+    if !errors.is_empty() {
+        return Err(eyre!(
+            "The contract has issues that prevent it from being deployed on NEAR:\n  {}\nUse `--skip-lint` to deploy it anyway.",
+            errors
+                .iter()
+                .map(|issue| issue.message.as_str())
+                .collect::<Vec<_>>()
+                .join("\n  ")
+        ));
+    }
+    Ok(())
+}
+
+fn get_max_contract_size(
+    network_config: &crate::config::NetworkConfig,
+) -> color_eyre::eyre::Result<u64> {
+    tracing::info!(target: "near_teach_me", "Getting the protocol config ...");
+    let protocol_config = tokio::runtime::Runtime::new()?.block_on(
+        crate::types::partial_protocol_config::get_partial_protocol_config(
+            &network_config.json_rpc_client(),
+            &near_primitives::types::BlockReference::Finality(
+                near_primitives::types::Finality::Final,
+            ),
+        ),
+    )?;
+    Ok(protocol_config
+        .runtime_config
+        .wasm_config
+        .limit_config
+        .max_contract_size)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum LintSeverity {
+    #[strum(to_string = "error")]
+    Error,
+    #[strum(to_string = "warning")]
+    Warning,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    pub severity: LintSeverity,
+    pub message: String,
+}
+
+impl LintIssue {
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: LintSeverity::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: LintSeverity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+macro_rules! define_operator_info {
+    ($( @$proposal:ident $op:ident $({ $($arg:ident: $argty:ty),* })? => $visit:ident ($($ann:tt)*) )*) => {
+        /// The WebAssembly proposal that introduced the operator and the operator name.
+        fn operator_info(operator: &wasmparser::Operator<'_>) -> (&'static str, &'static str) {
+            match operator {
+                $( wasmparser::Operator::$op { .. } => (stringify!($proposal), stringify!($op)), )*
+                _ => ("unknown", "unknown"),
+            }
+        }
+    };
+}
+wasmparser::for_each_operator!(define_operator_info);
+
+/// Checks the WebAssembly code for features that prevent it from being deployed or executed on NEAR.
+pub fn lint_wasm(code: &[u8], max_contract_size: u64) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    if code.len() as u64 > max_contract_size {
+        issues.push(LintIssue::error(format!(
+            "The contract size ({} bytes) exceeds the maximum contract size ({max_contract_size} bytes)",
+            code.len()
+        )));
+    }
+    if !code.starts_with(b"\0asm") {
+        issues.push(LintIssue::error(
+            "The file is not a WebAssembly module: the `\\0asm` magic header is missing",
+        ));
+        return issues;
+    }
+    if let Err(err) = lint_wasm_sections(code, &mut issues) {
+        issues.push(LintIssue::error(format!(
+            "The file is not a valid WebAssembly module: {err}"
+        )));
+    }
+    issues
+}
+
+fn lint_wasm_sections(
+    code: &[u8],
+    issues: &mut Vec<LintIssue>,
+) -> Result<(), wasmparser::BinaryReaderError> {
+    let mut memories = Vec::new();
+    let mut tables_count = 0;
+    let mut multi_value_types_count = 0;
+    let mut v128_types_count = 0;
+    let mut has_function_exports = false;
+    // Proposal name -> (operators count, example operator)
+    let mut proposals = std::collections::BTreeMap::<&str, (usize, &str)>::new();
+    let mut float_operators_count = 0;
+
+    for payload in wasmparser::Parser::new(0).parse_all(code) {
+        match payload? {
+            wasmparser::Payload::Version { encoding, .. }
+                if encoding != wasmparser::Encoding::Module =>
+            {
+                issues.push(LintIssue::error(
+                    "The file is a WebAssembly component, but only core modules can be deployed",
+                ));
+                return Ok(());
+            }
+            wasmparser::Payload::TypeSection(reader) => {
+                for rec_group in reader {
+                    for sub_type in rec_group?.types() {
+                        let wasmparser::CompositeInnerType::Func(func_type) =
+                            &sub_type.composite_type.inner
+                        else {
+                            proposals.entry("gc").or_insert((0, "struct/array type")).0 += 1;
+                            continue;
+                        };
+                        if func_type.results().len() > 1 {
+                            multi_value_types_count += 1;
+                        }
+                        if func_type
+                            .params()
+                            .iter()
+                            .chain(func_type.results())
+                            .any(|value_type| *value_type == wasmparser::ValType::V128)
+                        {
+                            v128_types_count += 1;
+                        }
+                    }
+                }
+            }
+            wasmparser::Payload::ImportSection(reader) => {
+                for import in reader {
+                    let import = import?;
+                    match import.ty {
+                        wasmparser::TypeRef::Func(_) if import.module != "env" => {
+                            issues.push(LintIssue::error(format!(
+                                "The function \"{}\" is imported from the module \"{}\", but the NEAR runtime provides only the \"env\" module",
+                                import.name, import.module
+                            )));
+                        }
+                        wasmparser::TypeRef::Func(_) => {
+                            if !NEAR_HOST_FUNCTIONS.contains(&import.name) {
+                                issues.push(LintIssue::warning(format!(
+                                    "The function \"env.{}\" is not a known NEAR host function",
+                                    import.name
+                                )));
+                            }
+                        }
+                        wasmparser::TypeRef::Memory(memory_type) => memories.push(memory_type),
+                        wasmparser::TypeRef::Table(_) => tables_count += 1,
+                        _ => {
+                            issues.push(LintIssue::error(format!(
+                                "\"{}.{}\" is imported, but only functions and memory can be imported",
+                                import.module, import.name
+                            )));
+                        }
+                    }
+                }
+            }
+            wasmparser::Payload::TableSection(reader) => {
+                tables_count += reader.count();
+            }
+            wasmparser::Payload::MemorySection(reader) => {
+                for memory_type in reader {
+                    memories.push(memory_type?);
+                }
+            }
+            wasmparser::Payload::ExportSection(reader) => {
+                for export in reader {
+                    if export?.kind == wasmparser::ExternalKind::Func {
+                        has_function_exports = true;
+                    }
+                }
+            }
+            wasmparser::Payload::CodeSectionEntry(body) => {
+                for local in body.get_locals_reader()? {
+                    if local?.1 == wasmparser::ValType::V128 {
+                        v128_types_count += 1;
+                    }
+                }
+                for operator in body.get_operators_reader()? {
+                    let (proposal, operator_name) = operator_info(&operator?);
+                    if !SUPPORTED_PROPOSALS.contains(&proposal) {
+                        proposals.entry(proposal).or_insert((0, operator_name)).0 += 1;
+                    }
+                    if operator_name.starts_with("F32") || operator_name.starts_with("F64") {
+                        float_operators_count += 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if memories.len() > 1 {
+        issues.push(LintIssue::error(format!(
+            "The contract declares {} memories, but the NEAR runtime does not support the multi-memory proposal",
+            memories.len()
+        )));
+    }
+    if memories.iter().any(|memory_type| memory_type.shared) {
+        issues.push(LintIssue::error(
+            "The contract declares a shared memory, but the NEAR runtime does not support the threads proposal",
+        ));
+    }
+    if memories.iter().any(|memory_type| memory_type.memory64) {
+        issues.push(LintIssue::error(
+            "The contract declares a 64-bit memory, but the NEAR runtime does not support the memory64 proposal",
+        ));
+    }
+    if tables_count > 1 {
+        issues.push(LintIssue::error(format!(
+            "The contract declares {tables_count} tables, but the NEAR runtime does not support the reference-types proposal"
+        )));
+    }
+    if multi_value_types_count > 0 {
+        issues.push(LintIssue::error(format!(
+            "{multi_value_types_count} function type(s) return multiple values, but the NEAR runtime does not support the multi-value proposal"
+        )));
+    }
+    if v128_types_count > 0 {
+        proposals.entry("simd").or_insert((0, "v128")).0 += v128_types_count;
+    }
+    for (proposal, (count, example)) in proposals {
+        issues.push(LintIssue::error(format!(
+            "{count} instruction(s) of the {} proposal (e.g. {example}) are used, but the NEAR runtime does not support it",
+            proposal.replace('_', "-")
+        )));
+    }
+    if float_operators_count > 0 {
+        issues.push(LintIssue::warning(format!(
+            "{float_operators_count} floating point instruction(s) are used; floating point arithmetic is rarely intended in contracts and often comes from formatting or parsing of numbers"
+        )));
+    }
+    if !has_function_exports {
+        issues.push(LintIssue::warning(
+            "The contract exports no functions, so none of them can be called",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn module(sections: &[(u8, Vec<u8>)]) -> Vec<u8> {
+        let mut code = b"\0asm\x01\0\0\0".to_vec();
+        for (id, content) in sections {
+            code.push(*id);
+            code.push(u8::try_from(content.len()).unwrap());
+            code.extend(content);
+        }
+        code
+    }
+
+    fn import(module: &str, name: &str) -> Vec<u8> {
+        let mut import = vec![u8::try_from(module.len()).unwrap()];
+        import.extend(module.as_bytes());
+        import.push(u8::try_from(name.len()).unwrap());
+        import.extend(name.as_bytes());
+        // A function of type 0
+        import.extend([0x00, 0x00]);
+        import
+    }
+
+    fn contract(
+        types: Vec<u8>,
+        imports: &[Vec<u8>],
+        memories: Vec<u8>,
+        function_body: Vec<u8>,
+    ) -> Vec<u8> {
+        let mut import_section = vec![u8::try_from(imports.len()).unwrap()];
+        import_section.extend(imports.concat());
+        let exported_function_index = u8::try_from(imports.len()).unwrap();
+        let mut code_section = vec![0x01, u8::try_from(function_body.len() + 1).unwrap(), 0x00];
+        code_section.extend(function_body);
+        module(&[
+            (1, types),
+            (2, import_section),
+            (3, vec![0x01, 0x00]),
+            (5, memories),
+            (
+                7,
+                [&[0x01, 0x04][..], b"main", &[0x00, exported_function_index]].concat(),
+            ),
+            (10, code_section),
+        ])
+    }
+
+    #[test]
+    fn accepts_near_compatible_contracts() {
+        let code = contract(
+            vec![0x01, 0x60, 0x00, 0x00],
+            &[import("env", "value_return")],
+            vec![0x01, 0x00, 0x11],
+            // i32.const 1; i32.extend8_s; drop; end
+            vec![0x41, 0x01, 0xc0, 0x1a, 0x0b],
+        );
+        assert_eq!(lint_wasm(&code, DEFAULT_MAX_CONTRACT_SIZE), vec![]);
+    }
+
+    #[test]
+    fn reports_near_incompatible_features() {
+        let mut function_body = vec![
+            // f32.const 0; drop
+            0x43, 0x00, 0x00, 0x00, 0x00, 0x1a, // atomic.fence
+            0xfe, 0x03, 0x00, // v128.const 0
+            0xfd, 0x0c,
+        ];
+        function_body.extend([0x00; 16]);
+        // drop; end
+        function_body.extend([0x1a, 0x0b]);
+        let code = contract(
+            // () -> (i32, i32)
+            vec![0x01, 0x60, 0x00, 0x02, 0x7f, 0x7f],
+            &[
+                import("wasi_snapshot_preview1", "fd_write"),
+                import("env", "memcpy"),
+            ],
+            vec![0x02, 0x00, 0x11, 0x00, 0x01],
+            function_body,
+        );
+        let issues = lint_wasm(&code, 64);
+        let messages = issues
+            .iter()
+            .map(|issue| format!("{}: {}", issue.severity, issue.message))
+            .collect::<Vec<_>>();
+        for expected in [
+            "error: The contract size",
+            "error: The function \"fd_write\" is imported from the module \"wasi_snapshot_preview1\"",
+            "warning: The function \"env.memcpy\" is not a known NEAR host function",
+            "error: The contract declares 2 memories",
+            "error: 1 function type(s) return multiple values",
+            "error: 1 instruction(s) of the simd proposal (e.g. V128Const)",
+            "error: 1 instruction(s) of the threads proposal (e.g. AtomicFence)",
+            "warning: 1 floating point instruction(s) are used",
+        ] {
+            assert!(
+                messages.iter().any(|message| message.starts_with(expected)),
+                "{expected}\n\nnot found in:\n{messages:#?}"
+            );
+        }
+        assert_eq!(issues.len(), 8, "{messages:#?}");
+    }
+
+    #[test]
+    fn reports_invalid_modules() {
+        let issues = lint_wasm(b"\0asm\x01\0\0\0\x01\x05\x01", DEFAULT_MAX_CONTRACT_SIZE);
+        assert_eq!(issues.len(), 1);
+        assert!(
+            issues[0]
+                .message
+                .starts_with("The file is not a valid WebAssembly module"),
+            "{issues:?}"
+        );
+    }
+}
@@ -4,13 +4,20 @@ use near_primitives::types::BlockReference;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 use thiserror::Error;
 
+pub mod abi_result;
+pub mod abi_schema;
+#[cfg(feature = "decode_borsh")]
+pub mod borsh_schema;
 pub mod call_function;
 pub mod deploy;
 pub mod deploy_global;
+mod diff;
 mod download_abi;
 pub mod download_wasm;
+mod generate_client;
 #[cfg(feature = "inspect_contract")]
 mod inspect;
+mod lint;
 pub mod state_init;
 
 #[cfg(feature = "verify_contract")]
@@ -50,6 +57,11 @@ pub enum ContractActions {
     ))]
     /// Initialize a deterministic account with a global contract and state data
     StateInit(self::state_init::StateInit),
+    #[strum_discriminants(strum(
+        message = "lint             - Check a local WASM file for features that NEAR does not support"
+    ))]
+    /// Check a local WASM file for features that NEAR does not support
+    Lint(self::lint::Lint),
     #[strum_discriminants(strum(
         message = "inspect          - Get a list of available function names"
     ))]
@@ -68,6 +80,16 @@ pub enum ContractActions {
     #[strum_discriminants(strum(message = "download-wasm    - Download wasm"))]
     /// Download wasm
     DownloadWasm(self::download_wasm::Contract),
+    #[strum_discriminants(strum(
+        message = "diff             - Compare a local WASM file with the contract deployed to an account"
+    ))]
+    /// Compare a local WASM file with the contract deployed to an account
+    Diff(self::diff::Contract),
+    #[strum_discriminants(strum(
+        message = "generate-client  - Generate a typed client (TypeScript, Rust or JSON Schema) from the contract ABI"
+    ))]
+    /// Generate a typed client (TypeScript, Rust or JSON Schema) from the contract ABI
+    GenerateClient(self::generate_client::Contract),
     #[strum_discriminants(strum(message = "view-storage     - View contract storage state"))]
     /// View contract storage state
     ViewStorage(self::view_storage::ViewStorage),
@@ -608,7 +608,7 @@ impl SignerAccountId {
         context: &DepositContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.global_context.config.credentials_home_dir,
+            &context.global_context.config,
             "What is the signer account ID?",
         )
     }
@@ -1,5 +1,8 @@
 mod keys_to_view;
 mod output_format;
+mod state_diff;
+#[cfg(feature = "decode_borsh")]
+mod storage_layout;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = crate::GlobalContext)]
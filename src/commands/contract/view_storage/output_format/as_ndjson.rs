@@ -0,0 +1,417 @@
+use std::io::Write;
+
+use color_eyre::eyre::Context;
+use tracing_indicatif::span_ext::IndicatifSpanExt;
+
+use crate::common::JsonRpcClientExt;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::super::keys_to_view::KeysContext)]
+#[interactive_clap(output_context = AsNdjsonContext)]
+pub struct AsNdjson {
+    /// Write the key-value pairs to this file instead of stdout
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    output_file: Option<crate::types::path_buf::PathBuf>,
+    /// Save the progress to this file and resume from it if it exists
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    checkpoint_file: Option<crate::types::path_buf::PathBuf>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct AsNdjsonContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl AsNdjsonContext {
+    pub fn from_previous_context(
+        previous_context: super::super::keys_to_view::KeysContext,
+        scope: &<AsNdjson as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let contract_account_id = previous_context.contract_account_id.clone();
+            let prefix = previous_context.prefix;
+            let output_file: Option<std::path::PathBuf> = scope.output_file.clone().map(Into::into);
+            let checkpoint_file: Option<std::path::PathBuf> = scope.checkpoint_file.clone().map(Into::into);
+
+            move |network_config, block_reference| {
+                let checkpoint = match &checkpoint_file {
+                    Some(checkpoint_file) if checkpoint_file.exists() => {
+                        let checkpoint = Checkpoint::read_from_file(checkpoint_file)?;
+                        if checkpoint.contract_account_id != contract_account_id {
+                            return Err(color_eyre::eyre::eyre!(
+                                "The checkpoint {checkpoint_file:?} is of <{}>, not <{contract_account_id}>",
+                                checkpoint.contract_account_id
+                            ));
+                        }
+                        tracing::info!(
+                            parent: &tracing::Span::none(),
+                            "Resuming from the checkpoint {:?} at block {} ({} keys were already written)",
+                            checkpoint_file,
+                            checkpoint.block_hash,
+                            checkpoint.keys_count
+                        );
+                        Some(checkpoint)
+                    }
+                    _ => None,
+                };
+                let is_resumed = checkpoint.is_some();
+                let mut checkpoint = match checkpoint {
+                    Some(checkpoint) => checkpoint,
+                    None => Checkpoint {
+                        contract_account_id: contract_account_id.clone(),
+                        block_hash: get_block_hash(network_config, block_reference)?,
+                        pending_prefixes: vec![prefix.clone()],
+                        keys_count: 0,
+                        output_offset: 0,
+                    },
+                };
+
+                let mut output: Box<dyn Write> = match &output_file {
+                    Some(output_file) => Box::new(std::io::BufWriter::new(
+                        open_output_file(output_file, is_resumed.then_some(checkpoint.output_offset))?,
+                    )),
+                    None => {
+                        if is_resumed {
+                            tracing::warn!(
+                                parent: &tracing::Span::none(),
+                                "The output is not a file, so the keys that were written after the checkpoint was saved may be repeated"
+                            );
+                        }
+                        Box::new(std::io::stdout().lock())
+                    }
+                };
+
+                let block_reference = near_primitives::types::BlockReference::BlockId(
+                    near_primitives::types::BlockId::Hash(checkpoint.block_hash),
+                );
+                walk_storage(
+                    &mut checkpoint,
+                    |prefix, limit| view_state(&contract_account_id, prefix, limit, network_config, &block_reference),
+                    |values, checkpoint| {
+                        for value in values {
+                            let line = serde_json::to_string(value)?;
+                            writeln!(output, "{line}")?;
+                            checkpoint.output_offset += line.len() as u64 + 1;
+                        }
+                        // The checkpoint is saved after the page is written, so on resume the output
+                        // is truncated to the offset of the checkpoint to drop a partially saved page.
+                        output.flush()?;
+                        if let Some(checkpoint_file) = &checkpoint_file {
+                            checkpoint.save_to_file(checkpoint_file)?;
+                        }
+                        Ok(())
+                    },
+                )?;
+
+                if let Some(checkpoint_file) = &checkpoint_file {
+                    std::fs::remove_file(checkpoint_file).wrap_err_with(|| {
+                        format!("Failed to remove the checkpoint file: {checkpoint_file:?}")
+                    })?;
+                }
+                tracing::info!(
+                    parent: &tracing::Span::none(),
+                    "{} keys of <{}> at block {} were written{}",
+                    checkpoint.keys_count,
+                    contract_account_id,
+                    checkpoint.block_hash,
+                    output_file
+                        .as_ref()
+                        .map(|output_file| format!(" to {output_file:?}"))
+                        .unwrap_or_default()
+                );
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.global_context.config,
+            interacting_with_account_ids: vec![previous_context.contract_account_id],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<AsNdjsonContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: AsNdjsonContext) -> Self {
+        item.0
+    }
+}
+
+/// The progress of the storage walk: all queries are made at the same block.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct Checkpoint {
+    contract_account_id: near_primitives::types::AccountId,
+    block_hash: near_primitives::hash::CryptoHash,
+    /// The key prefixes that are left to walk; the next one is the last.
+    pending_prefixes: Vec<near_primitives::types::StoreKey>,
+    keys_count: u64,
+    /// The length of the output when the checkpoint was saved.
+    output_offset: u64,
+}
+
+impl Checkpoint {
+    fn read_from_file(file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read(file_path)
+            .wrap_err_with(|| format!("Failed to open or read the file: {file_path:?}."))?;
+        serde_json::from_slice(&data)
+            .wrap_err_with(|| format!("The file {file_path:?} is not a storage walk checkpoint."))
+    }
+
+    fn save_to_file(&self, file_path: &std::path::Path) -> crate::CliResult {
+        // Write to a temporary file first so that an interruption does not corrupt the checkpoint
+        let temporary_file_path = file_path.with_extension("tmp");
+        let mut temporary_file = std::fs::File::create(&temporary_file_path)
+            .wrap_err_with(|| format!("Failed to create file: {temporary_file_path:?}"))?;
+        temporary_file
+            .write_all(&serde_json::to_vec(self)?)
+            .and_then(|()| temporary_file.sync_all())
+            .wrap_err_with(|| format!("Failed to write to file: {temporary_file_path:?}"))?;
+        std::fs::rename(&temporary_file_path, file_path)
+            .wrap_err_with(|| format!("Failed to write to file: {file_path:?}"))
+    }
+}
+
+/// Opens the output file: a new one is truncated, and a resumed one is truncated to the offset
+/// of the checkpoint, dropping the keys that were written after the checkpoint was saved.
+fn open_output_file(
+    output_file: &std::path::Path,
+    resume_offset: Option<u64>,
+) -> color_eyre::eyre::Result<std::fs::File> {
+    let Some(resume_offset) = resume_offset else {
+        return std::fs::File::create(output_file)
+            .wrap_err_with(|| format!("Failed to create file: {output_file:?}"));
+    };
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .open(output_file)
+        .wrap_err_with(|| format!("Failed to open the file to resume: {output_file:?}"))?;
+    let file_len = file.metadata()?.len();
+    if file_len < resume_offset {
+        return Err(color_eyre::eyre::eyre!(
+            "The file {output_file:?} is shorter ({file_len} bytes) than it was when the checkpoint was saved ({resume_offset} bytes)"
+        ));
+    }
+    file.set_len(resume_offset)?;
+    std::io::Seek::seek(&mut file, std::io::SeekFrom::End(0))?;
+    Ok(file)
+}
+
+enum ViewStateResponse {
+    Values(Vec<near_primitives::views::StateItem>),
+    /// The state under the prefix exceeds the RPC limit.
+    TooLarge,
+}
+
+/// Walks the keys under the pending prefixes in lexicographic order, splitting every prefix
+/// whose state is too large into 256 longer prefixes.
+///
+/// `on_shard` is called with the key-value pairs of every walked prefix and the updated checkpoint.
+#[tracing::instrument(name = "Walking the contract storage ...", skip_all)]
+fn walk_storage(
+    checkpoint: &mut Checkpoint,
+    mut view_state: impl FnMut(
+        &[u8],
+        Option<std::num::NonZeroU32>,
+    ) -> color_eyre::eyre::Result<ViewStateResponse>,
+    mut on_shard: impl FnMut(&[near_primitives::views::StateItem], &mut Checkpoint) -> crate::CliResult,
+) -> crate::CliResult {
+    while let Some(prefix) = checkpoint.pending_prefixes.pop() {
+        tracing::Span::current().pb_set_message(&format!(
+            "{} keys, prefix 0x{} ...",
+            checkpoint.keys_count,
+            hex::encode(prefix.as_slice())
+        ));
+        let values = match view_state(&prefix, None)? {
+            ViewStateResponse::Values(values) => values,
+            ViewStateResponse::TooLarge => {
+                // The key equal to the prefix is not under any of the longer prefixes;
+                // it is the first key of the prefix if it exists.
+                let values = if prefix.is_empty() {
+                    Vec::new()
+                } else {
+                    match view_state(&prefix, std::num::NonZeroU32::new(1))? {
+                        ViewStateResponse::Values(values) => values
+                            .into_iter()
+                            .filter(|value| value.key == prefix)
+                            .collect(),
+                        ViewStateResponse::TooLarge => {
+                            tracing::warn!(
+                                parent: &tracing::Span::none(),
+                                "The RPC does not support limited state queries, so the key 0x{} itself is skipped",
+                                hex::encode(prefix.as_slice())
+                            );
+                            Vec::new()
+                        }
+                    }
+                };
+                checkpoint
+                    .pending_prefixes
+                    .extend((0..=u8::MAX).rev().map(|byte| {
+                        near_primitives::types::StoreKey::from(
+                            [prefix.as_slice(), &[byte]].concat(),
+                        )
+                    }));
+                values
+            }
+        };
+        checkpoint.keys_count += values.len() as u64;
+        on_shard(&values, checkpoint)?;
+    }
+    Ok(())
+}
+
+fn view_state(
+    contract_account_id: &near_primitives::types::AccountId,
+    prefix: &[u8],
+    limit: Option<std::num::NonZeroU32>,
+    network_config: &crate::config::NetworkConfig,
+    block_reference: &near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<ViewStateResponse> {
+    match network_config.json_rpc_client().blocking_call(
+        near_jsonrpc_client::methods::query::RpcQueryRequest {
+            block_reference: block_reference.clone(),
+            request: near_primitives::views::QueryRequest::ViewState {
+                account_id: contract_account_id.clone(),
+                prefix: prefix.to_vec().into(),
+                include_proof: false,
+                after_key: None,
+                limit,
+            },
+        },
+    ) {
+        Ok(query_view_method_response) => {
+            if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
+                query_view_method_response.kind
+            {
+                Ok(ViewStateResponse::Values(result.values))
+            } else {
+                Err(color_eyre::Report::msg("Error call result".to_string()))
+            }
+        }
+        Err(err)
+            if matches!(
+                *err,
+                near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                    near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                        near_jsonrpc_primitives::types::query::RpcQueryError::TooLargeContractState { .. }
+                    )
+                )
+            ) =>
+        {
+            Ok(ViewStateResponse::TooLarge)
+        }
+        Err(err) => Err(color_eyre::eyre::eyre!(err)).wrap_err_with(|| {
+            format!(
+                "Failed to fetch query ViewState for <{contract_account_id}> on network <{}>",
+                network_config.network_name
+            )
+        }),
+    }
+}
+
+fn get_block_hash(
+    network_config: &crate::config::NetworkConfig,
+    block_reference: &near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+    Ok(network_config
+        .json_rpc_client()
+        .blocking_call(near_jsonrpc_client::methods::block::RpcBlockRequest {
+            block_reference: block_reference.clone(),
+        })
+        .wrap_err_with(|| {
+            format!(
+                "Failed to fetch block info for block reference {:?} on network <{}>",
+                block_reference, network_config.network_name
+            )
+        })?
+        .header
+        .hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn walks_large_storage_by_splitting_prefixes() {
+        let storage = [
+            b"STATE".to_vec(),
+            b"a".to_vec(),
+            b"a\x00".to_vec(),
+            b"a\x01".to_vec(),
+            b"a\x01x".to_vec(),
+            b"b".to_vec(),
+        ]
+        .into_iter()
+        .map(|key| near_primitives::views::StateItem {
+            key: key.clone().into(),
+            value: key.into(),
+        })
+        .collect::<Vec<_>>();
+        let mut checkpoint = Checkpoint {
+            contract_account_id: "contract.testnet".parse().unwrap(),
+            block_hash: near_primitives::hash::CryptoHash::default(),
+            pending_prefixes: vec![Vec::new().into()],
+            keys_count: 0,
+            output_offset: 0,
+        };
+        let mut queries_count = 0;
+        let mut keys = Vec::new();
+        walk_storage(
+            &mut checkpoint,
+            |prefix, limit| {
+                queries_count += 1;
+                let values = storage
+                    .iter()
+                    .filter(|value| value.key.starts_with(prefix))
+                    .take(limit.map_or(usize::MAX, |limit| limit.get() as usize))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                // Only two keys fit into a response
+                if values.len() > 2 {
+                    Ok(ViewStateResponse::TooLarge)
+                } else {
+                    Ok(ViewStateResponse::Values(values))
+                }
+            },
+            |values, _| {
+                keys.extend(values.iter().map(|value| value.key.to_vec()));
+                Ok(())
+            },
+        )
+        .unwrap();
+        assert_eq!(
+            keys,
+            storage
+                .iter()
+                .map(|value| value.key.to_vec())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(checkpoint.keys_count, 6);
+        assert!(checkpoint.pending_prefixes.is_empty());
+        // The whole storage, 256 prefixes of one byte, the key "a" and 256 prefixes that start with "a"
+        assert_eq!(queries_count, 1 + 256 + 1 + 256);
+    }
+
+    #[test]
+    fn resumed_output_is_truncated_to_the_checkpoint() {
+        let output_dir = tempfile::tempdir().unwrap();
+        let output_file = output_dir.path().join("state.ndjson");
+        std::fs::write(&output_file, "{\"page\":1}\n{\"page\":2}\n").unwrap();
+
+        let mut file = open_output_file(&output_file, Some(11)).unwrap();
+        writeln!(file, "{{\"page\":3}}").unwrap();
+        drop(file);
+        assert_eq!(
+            std::fs::read_to_string(&output_file).unwrap(),
+            "{\"page\":1}\n{\"page\":3}\n"
+        );
+
+        assert!(open_output_file(&output_file, Some(100)).is_err());
+        drop(open_output_file(&output_file, None).unwrap());
+        assert_eq!(std::fs::read_to_string(&output_file).unwrap(), "");
+    }
+}
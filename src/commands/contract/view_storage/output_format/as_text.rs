@@ -20,14 +20,18 @@ impl AsTextContext {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let contract_account_id = previous_context.contract_account_id.clone();
             let prefix = previous_context.prefix;
+            let output_format = previous_context.global_context.output_format;
 
             move |network_config, block_reference| {
                 let query_view_method_response =
                     super::get_contract_state(&contract_account_id, prefix.clone(), network_config, block_reference.clone())?;
 
                 if let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
-                    query_view_method_response.kind
+                    &query_view_method_response.kind
                 {
+                    if let crate::common::OutputFormat::Json = output_format {
+                        return super::print_contract_state_json(&contract_account_id, &prefix, &query_view_method_response);
+                    }
                     let mut info_str = String::new();
                     for value in &result.values {
                         info_str.push_str(&format!("\n\tkey:   {}", key_value_to_string(&value.key)?.green()));
@@ -0,0 +1,78 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::super::keys_to_view::KeysContext)]
+#[interactive_clap(output_context = DecodeWithSchemaContext)]
+pub struct DecodeWithSchema {
+    /// What is the file location of the storage layout (the contract state type, collections and Borsh schema)?
+    layout_file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct DecodeWithSchemaContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl DecodeWithSchemaContext {
+    pub fn from_previous_context(
+        previous_context: super::super::keys_to_view::KeysContext,
+        scope: &<DecodeWithSchema as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let layout_file_path: std::path::PathBuf = scope.layout_file_path.clone().into();
+        let layout =
+            super::super::storage_layout::StorageLayout::read_from_file(&layout_file_path)?;
+
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let contract_account_id = previous_context.contract_account_id.clone();
+            let prefix = previous_context.prefix;
+            let output_format = previous_context.global_context.output_format;
+
+            move |network_config, block_reference| {
+                // The Borsh schemas of the contract ABI complete the type definitions of the layout
+                let abi = match tokio::runtime::Runtime::new()?.block_on(
+                    super::super::super::get_contract_abi(
+                        &network_config.json_rpc_client(),
+                        block_reference,
+                        &contract_account_id,
+                    ),
+                ) {
+                    Ok(abi) => Some(abi),
+                    Err(super::super::super::FetchAbiError::AbiNotSupported) => None,
+                    Err(err) => {
+                        tracing::warn!(
+                            parent: &tracing::Span::none(),
+                            "The Borsh schemas of the contract ABI are not used: {err}"
+                        );
+                        None
+                    }
+                };
+                let decoder = super::super::storage_layout::StorageDecoder::new(layout.clone(), abi.as_ref())?;
+
+                let query_view_method_response =
+                    super::get_contract_state(&contract_account_id, prefix.clone(), network_config, block_reference.clone())?;
+                let snapshot = super::super::state_diff::StateSnapshot::from_query_response(
+                    &contract_account_id,
+                    &prefix,
+                    &query_view_method_response,
+                )?;
+                decoder.decode_state(&snapshot.values).print(
+                    &contract_account_id,
+                    snapshot.block_height,
+                    snapshot.block_hash,
+                    output_format,
+                )
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.global_context.config,
+            interacting_with_account_ids: vec![previous_context.contract_account_id],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<DecodeWithSchemaContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: DecodeWithSchemaContext) -> Self {
+        item.0
+    }
+}
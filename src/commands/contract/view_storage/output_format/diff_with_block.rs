@@ -0,0 +1,61 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::super::keys_to_view::KeysContext)]
+#[interactive_clap(output_context = DiffWithBlockContext)]
+pub struct DiffWithBlock {
+    /// What is the height or the hash of the block to compare the state with?
+    block_id: crate::types::block_id::BlockId,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct DiffWithBlockContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl DiffWithBlockContext {
+    pub fn from_previous_context(
+        previous_context: super::super::keys_to_view::KeysContext,
+        scope: &<DiffWithBlock as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let contract_account_id = previous_context.contract_account_id.clone();
+            let prefix = previous_context.prefix;
+            let base_block_reference = near_primitives::types::BlockReference::BlockId(scope.block_id.clone().into());
+            let output_format = previous_context.global_context.output_format;
+
+            move |network_config, block_reference| {
+                let mut states = Vec::new();
+                for block_reference in [&base_block_reference, block_reference] {
+                    let query_view_method_response =
+                        super::get_contract_state(&contract_account_id, prefix.clone(), network_config, block_reference.clone())?;
+                    states.push(super::super::state_diff::StateSnapshot::from_query_response(
+                        &contract_account_id,
+                        &prefix,
+                        &query_view_method_response,
+                    )?);
+                }
+                let [base_state, state] = &states[..] else {
+                    unreachable!("the state is fetched at two blocks");
+                };
+                super::super::state_diff::print_state_diff(
+                    &super::super::state_diff::StateSource::new(base_state, None),
+                    &super::super::state_diff::StateSource::new(state, None),
+                    &super::super::state_diff::diff_states(&base_state.values, &state.values, &prefix),
+                    output_format,
+                )
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.global_context.config,
+            interacting_with_account_ids: vec![previous_context.contract_account_id],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<DiffWithBlockContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: DiffWithBlockContext) -> Self {
+        item.0
+    }
+}
@@ -0,0 +1,67 @@
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::super::keys_to_view::KeysContext)]
+#[interactive_clap(output_context = DiffWithSnapshotContext)]
+pub struct DiffWithSnapshot {
+    /// What is the file location of the contract storage state snapshot?
+    snapshot_file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct DiffWithSnapshotContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl DiffWithSnapshotContext {
+    pub fn from_previous_context(
+        previous_context: super::super::keys_to_view::KeysContext,
+        scope: &<DiffWithSnapshot as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let snapshot_file_path: std::path::PathBuf = scope.snapshot_file_path.clone().into();
+        let snapshot =
+            super::super::state_diff::StateSnapshot::read_from_file(&snapshot_file_path)?;
+        if snapshot.contract_account_id != previous_context.contract_account_id {
+            return Err(color_eyre::eyre::eyre!(
+                "The snapshot {:?} is of <{}>, not <{}>",
+                snapshot_file_path,
+                snapshot.contract_account_id,
+                previous_context.contract_account_id
+            ));
+        }
+
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let contract_account_id = previous_context.contract_account_id.clone();
+            let prefix = previous_context.prefix;
+            let output_format = previous_context.global_context.output_format;
+
+            move |network_config, block_reference| {
+                let query_view_method_response =
+                    super::get_contract_state(&contract_account_id, prefix.clone(), network_config, block_reference.clone())?;
+                let current_state = super::super::state_diff::StateSnapshot::from_query_response(
+                    &contract_account_id,
+                    &prefix,
+                    &query_view_method_response,
+                )?;
+                let common_prefix = snapshot.common_prefix(&current_state)?;
+                super::super::state_diff::print_state_diff(
+                    &super::super::state_diff::StateSource::new(&snapshot, Some(snapshot_file_path.clone())),
+                    &super::super::state_diff::StateSource::new(&current_state, None),
+                    &super::super::state_diff::diff_states(&snapshot.values, &current_state.values, common_prefix),
+                    output_format,
+                )
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.global_context.config,
+            interacting_with_account_ids: vec![previous_context.contract_account_id],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<DiffWithSnapshotContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: DiffWithSnapshotContext) -> Self {
+        item.0
+    }
+}
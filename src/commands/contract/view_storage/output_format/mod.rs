@@ -4,21 +4,72 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 use crate::common::JsonRpcClientExt;
 
 mod as_json;
+mod as_ndjson;
 mod as_text;
+#[cfg(feature = "decode_borsh")]
+mod decode_with_schema;
+mod diff_with_block;
+mod diff_with_snapshot;
+mod save_to_file;
 
 #[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(context = super::keys_to_view::KeysContext)]
 #[strum_discriminants(derive(EnumMessage, EnumIter))]
-/// Choose a format to view contract storage state:
+/// Choose how to view contract storage state:
 pub enum OutputFormat {
     #[strum_discriminants(strum(
-        message = "as-json    - View contract storage state in JSON format"
+        message = "as-json               - View contract storage state in JSON format"
     ))]
     /// View contract storage state in JSON format
     AsJson(self::as_json::AsJson),
-    #[strum_discriminants(strum(message = "as-text    - View contract storage state in the text"))]
+    #[strum_discriminants(strum(
+        message = "as-text               - View contract storage state in the text"
+    ))]
     /// View contract storage state in the text
     AsText(self::as_text::AsText),
+    #[strum_discriminants(strum(
+        message = "as-ndjson             - Stream contract storage state of any size as NDJSON (one key-value pair per line)"
+    ))]
+    /// Stream contract storage state of any size as NDJSON (one key-value pair per line)
+    AsNdjson(self::as_ndjson::AsNdjson),
+    #[strum_discriminants(strum(
+        message = "decode-with-schema    - View contract storage state decoded with a Borsh storage layout"
+    ))]
+    /// View contract storage state decoded with a Borsh storage layout
+    #[cfg(feature = "decode_borsh")]
+    DecodeWithSchema(self::decode_with_schema::DecodeWithSchema),
+    #[strum_discriminants(strum(
+        message = "save-to-file          - Save a snapshot of contract storage state to a file"
+    ))]
+    /// Save a snapshot of contract storage state to a file
+    SaveToFile(self::save_to_file::SaveToFile),
+    #[strum_discriminants(strum(
+        message = "diff-with-snapshot    - Compare contract storage state with a saved snapshot"
+    ))]
+    /// Compare contract storage state with a saved snapshot
+    DiffWithSnapshot(self::diff_with_snapshot::DiffWithSnapshot),
+    #[strum_discriminants(strum(
+        message = "diff-with-block       - Compare contract storage state with the state at another block"
+    ))]
+    /// Compare contract storage state with the state at another block
+    DiffWithBlock(self::diff_with_block::DiffWithBlock),
+}
+
+/// Prints the contract state as a single JSON document (used with the global `--output json`)
+fn print_contract_state_json(
+    contract_account_id: &near_primitives::types::AccountId,
+    prefix: &near_primitives::types::StoreKey,
+    query_view_method_response: &near_jsonrpc_client::methods::query::RpcQueryResponse,
+) -> crate::CliResult {
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&super::state_diff::StateSnapshot::from_query_response(
+            contract_account_id,
+            prefix,
+            query_view_method_response,
+        )?)?
+    );
+    Ok(())
 }
 
 #[tracing::instrument(name = "Obtaining the state of the contract ...", skip_all)]
@@ -0,0 +1,75 @@
+use color_eyre::eyre::Context;
+use inquire::CustomType;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::super::keys_to_view::KeysContext)]
+#[interactive_clap(output_context = SaveToFileContext)]
+pub struct SaveToFile {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Enter the file path where to save the contract storage state:
+    file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct SaveToFileContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl SaveToFileContext {
+    pub fn from_previous_context(
+        previous_context: super::super::keys_to_view::KeysContext,
+        scope: &<SaveToFile as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
+            let contract_account_id = previous_context.contract_account_id.clone();
+            let prefix = previous_context.prefix;
+            let file_path: std::path::PathBuf = scope.file_path.clone().into();
+
+            move |network_config, block_reference| {
+                let query_view_method_response =
+                    super::get_contract_state(&contract_account_id, prefix.clone(), network_config, block_reference.clone())?;
+                let snapshot = super::super::state_diff::StateSnapshot::from_query_response(
+                    &contract_account_id,
+                    &prefix,
+                    &query_view_method_response,
+                )?;
+                std::fs::write(&file_path, serde_json::to_vec_pretty(&snapshot)?)
+                    .wrap_err_with(|| format!("Failed to write to file: {file_path:?}"))?;
+                tracing::info!(
+                    parent: &tracing::Span::none(),
+                    "The contract storage state of <{}> ({} keys at block #{}) was saved to {:?}",
+                    contract_account_id,
+                    snapshot.values.len(),
+                    snapshot.block_height,
+                    file_path
+                );
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config: previous_context.global_context.config,
+            interacting_with_account_ids: vec![previous_context.contract_account_id],
+            on_after_getting_block_reference_callback,
+        }))
+    }
+}
+
+impl From<SaveToFileContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: SaveToFileContext) -> Self {
+        item.0
+    }
+}
+
+impl SaveToFile {
+    fn input_file_path(
+        _context: &super::super::keys_to_view::KeysContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::path_buf::PathBuf>> {
+        Ok(Some(
+            CustomType::new("Enter the file path where to save the contract storage state:")
+                .with_starting_input("contract-state.json")
+                .prompt()?,
+        ))
+    }
+}
@@ -0,0 +1,350 @@
+use std::collections::BTreeMap;
+
+use color_eyre::eyre::{Context, eyre};
+use color_eyre::owo_colors::OwoColorize;
+
+/// The contract state saved with `save-to-file` (or printed with the global `--output json`).
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct StateSnapshot {
+    pub contract_account_id: near_primitives::types::AccountId,
+    pub block_height: near_primitives::types::BlockHeight,
+    pub block_hash: near_primitives::hash::CryptoHash,
+    /// The key prefix that the state was fetched with (Base64); `values` holds only the keys that start with it.
+    pub prefix: near_primitives::types::StoreKey,
+    pub values: Vec<near_primitives::views::StateItem>,
+}
+
+impl StateSnapshot {
+    pub fn from_query_response(
+        contract_account_id: &near_primitives::types::AccountId,
+        prefix: &near_primitives::types::StoreKey,
+        query_view_method_response: &near_jsonrpc_client::methods::query::RpcQueryResponse,
+    ) -> color_eyre::eyre::Result<Self> {
+        let near_jsonrpc_primitives::types::query::QueryResponseKind::ViewState(result) =
+            &query_view_method_response.kind
+        else {
+            return Err(color_eyre::Report::msg("Error call result".to_string()));
+        };
+        Ok(Self {
+            contract_account_id: contract_account_id.clone(),
+            block_height: query_view_method_response.block_height,
+            block_hash: query_view_method_response.block_hash,
+            prefix: prefix.clone(),
+            values: result.values.clone(),
+        })
+    }
+
+    pub fn read_from_file(file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read(file_path)
+            .wrap_err_with(|| format!("Failed to open or read the file: {file_path:?}."))?;
+        serde_json::from_slice(&data)
+            .wrap_err_with(|| format!("The file {file_path:?} is not a contract state snapshot."))
+    }
+
+    /// The key prefix that both snapshots cover: the longer of the two prefixes, if it starts with the shorter one.
+    ///
+    /// Snapshots of different contracts or of disjoint key ranges can't be compared.
+    pub fn common_prefix<'a>(&'a self, other: &'a Self) -> color_eyre::eyre::Result<&'a [u8]> {
+        if self.contract_account_id != other.contract_account_id {
+            return Err(eyre!(
+                "The states of different contracts can't be compared: <{}> and <{}>",
+                self.contract_account_id,
+                other.contract_account_id
+            ));
+        }
+        let (shorter, longer) = if self.prefix.len() <= other.prefix.len() {
+            (&self.prefix, &other.prefix)
+        } else {
+            (&other.prefix, &self.prefix)
+        };
+        if !longer.starts_with(shorter) {
+            return Err(eyre!(
+                "The states were fetched with the disjoint key prefixes {:?} and {:?}, so they have no keys in common",
+                String::from_utf8_lossy(&self.prefix),
+                String::from_utf8_lossy(&other.prefix)
+            ));
+        }
+        Ok(longer)
+    }
+}
+
+#[derive(Debug, PartialEq, serde::Serialize)]
+pub struct StateDiff {
+    added: Vec<StateItemDiff>,
+    removed: Vec<StateItemDiff>,
+    modified: Vec<StateItemDiff>,
+}
+
+#[derive(Debug, PartialEq, serde::Serialize)]
+struct StateItemDiff {
+    key: serde_json::Value,
+    /// The exact key bytes in Base64.
+    key_base64: near_primitives::types::StoreKey,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<serde_json::Value>,
+}
+
+impl StateItemDiff {
+    fn new(key: &[u8], before: Option<&[u8]>, after: Option<&[u8]>) -> Self {
+        Self {
+            key: decode_bytes(key),
+            key_base64: key.to_vec().into(),
+            before: before.map(decode_bytes),
+            after: after.map(decode_bytes),
+        }
+    }
+}
+
+/// Compares two contract states, considering only the keys that start with the prefix.
+pub fn diff_states(
+    before: &[near_primitives::views::StateItem],
+    after: &[near_primitives::views::StateItem],
+    prefix: &[u8],
+) -> StateDiff {
+    let state = |values: &[near_primitives::views::StateItem]| {
+        values
+            .iter()
+            .filter(|item| item.key.starts_with(prefix))
+            .map(|item| (item.key.to_vec(), item.value.to_vec()))
+            .collect::<BTreeMap<_, _>>()
+    };
+    let before = state(before);
+    let after = state(after);
+
+    let mut state_diff = StateDiff {
+        added: Vec::new(),
+        removed: Vec::new(),
+        modified: Vec::new(),
+    };
+    for (key, before_value) in &before {
+        match after.get(key) {
+            None => state_diff
+                .removed
+                .push(StateItemDiff::new(key, Some(before_value), None)),
+            Some(after_value) if after_value != before_value => state_diff.modified.push(
+                StateItemDiff::new(key, Some(before_value), Some(after_value)),
+            ),
+            Some(_) => {}
+        }
+    }
+    for (key, after_value) in &after {
+        if !before.contains_key(key) {
+            state_diff
+                .added
+                .push(StateItemDiff::new(key, None, Some(after_value)));
+        }
+    }
+    state_diff
+}
+
+/// Decodes the bytes as JSON or UTF-8 text if possible; Borsh and other binary data are shown as hex.
+pub(super) fn decode_bytes(bytes: &[u8]) -> serde_json::Value {
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        if let Ok(value) = serde_json::from_str::<serde_json::Value>(text) {
+            return serde_json::json!({ "json": value });
+        }
+        if !text
+            .chars()
+            .any(|c| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+        {
+            return serde_json::json!({ "utf8": text });
+        }
+    }
+    serde_json::json!({ "hex": hex::encode(bytes) })
+}
+
+fn decoded_to_string(decoded: &serde_json::Value) -> String {
+    match decoded
+        .as_object()
+        .and_then(|decoded| decoded.iter().next())
+    {
+        Some((format, serde_json::Value::String(value))) if format != "json" => {
+            format!("{format}: {value}")
+        }
+        Some((format, value)) => format!("{format}: {value}"),
+        None => decoded.to_string(),
+    }
+}
+
+/// Where a compared contract state comes from.
+#[derive(Debug, serde::Serialize)]
+pub struct StateSource {
+    contract_account_id: near_primitives::types::AccountId,
+    block_height: near_primitives::types::BlockHeight,
+    block_hash: near_primitives::hash::CryptoHash,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    snapshot_file: Option<std::path::PathBuf>,
+}
+
+impl StateSource {
+    pub fn new(snapshot: &StateSnapshot, snapshot_file: Option<std::path::PathBuf>) -> Self {
+        Self {
+            contract_account_id: snapshot.contract_account_id.clone(),
+            block_height: snapshot.block_height,
+            block_hash: snapshot.block_hash,
+            snapshot_file,
+        }
+    }
+}
+
+impl std::fmt::Display for StateSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if let Some(snapshot_file) = &self.snapshot_file {
+            write!(f, "the snapshot {snapshot_file:?} of ")?;
+        }
+        write!(
+            f,
+            "<{}> at block #{} ({})",
+            self.contract_account_id, self.block_height, self.block_hash
+        )
+    }
+}
+
+/// Prints the state diff in the global output format.
+pub fn print_state_diff(
+    before: &StateSource,
+    after: &StateSource,
+    state_diff: &StateDiff,
+    output_format: crate::common::OutputFormat,
+) -> crate::CliResult {
+    if let crate::common::OutputFormat::Json = output_format {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "before": before,
+                "after": after,
+                "added": state_diff.added,
+                "removed": state_diff.removed,
+                "modified": state_diff.modified,
+            }))?
+        );
+        return Ok(());
+    }
+
+    println!("Contract state changes\n  from: {before}\n  to:   {after}");
+    for (title, items) in [
+        ("Added keys", &state_diff.added),
+        ("Removed keys", &state_diff.removed),
+        ("Modified keys", &state_diff.modified),
+    ] {
+        if items.is_empty() {
+            continue;
+        }
+        println!("\n{title} ({}):", items.len());
+        for item in items {
+            println!("\tkey:    {}", decoded_to_string(&item.key).green());
+            if let Some(before) = &item.before {
+                println!("\tbefore: {}", decoded_to_string(before).red());
+            }
+            if let Some(after) = &item.after {
+                println!("\tafter:  {}", decoded_to_string(after).yellow());
+            }
+            println!("\t--------------------------------");
+        }
+    }
+    println!(
+        "\n{} added, {} removed, {} modified",
+        state_diff.added.len(),
+        state_diff.removed.len(),
+        state_diff.modified.len()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(values: &[(&[u8], &[u8])]) -> Vec<near_primitives::views::StateItem> {
+        values
+            .iter()
+            .map(|(key, value)| near_primitives::views::StateItem {
+                key: key.to_vec().into(),
+                value: value.to_vec().into(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn decodes_values() {
+        assert_eq!(
+            decode_bytes(br#"{"owner":"alice.near"}"#),
+            serde_json::json!({"json": {"owner": "alice.near"}})
+        );
+        assert_eq!(decode_bytes(b"STATE"), serde_json::json!({"utf8": "STATE"}));
+        assert_eq!(
+            decode_bytes(&[5, 0, 0, 0, b'a']),
+            serde_json::json!({"hex": "0500000061"})
+        );
+    }
+
+    #[test]
+    fn finds_common_prefix() {
+        let snapshot = |contract_account_id: &str, prefix: &[u8]| StateSnapshot {
+            contract_account_id: contract_account_id.parse().unwrap(),
+            block_height: 1,
+            block_hash: Default::default(),
+            prefix: prefix.to_vec().into(),
+            values: Vec::new(),
+        };
+        assert_eq!(
+            snapshot("a.near", b"")
+                .common_prefix(&snapshot("a.near", b"ab"))
+                .unwrap(),
+            b"ab"
+        );
+        assert_eq!(
+            snapshot("a.near", b"ab")
+                .common_prefix(&snapshot("a.near", b"a"))
+                .unwrap(),
+            b"ab"
+        );
+        assert!(
+            snapshot("a.near", b"ab")
+                .common_prefix(&snapshot("a.near", b"b"))
+                .is_err()
+        );
+        assert!(
+            snapshot("a.near", b"")
+                .common_prefix(&snapshot("b.near", b""))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn diffs_states() {
+        let state_diff = diff_states(
+            &state(&[(b"a1", b"1"), (b"a2", b"2"), (b"a3", b"3"), (b"b1", b"1")]),
+            &state(&[(b"a1", b"1"), (b"a2", b"22"), (b"a4", b"4"), (b"b2", b"2")]),
+            b"a",
+        );
+        let keys = |items: &[StateItemDiff]| {
+            items
+                .iter()
+                .map(|item| item.key.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            keys(&state_diff.added),
+            vec![serde_json::json!({"utf8": "a4"})]
+        );
+        assert_eq!(
+            keys(&state_diff.removed),
+            vec![serde_json::json!({"utf8": "a3"})]
+        );
+        assert_eq!(
+            keys(&state_diff.modified),
+            vec![serde_json::json!({"utf8": "a2"})]
+        );
+        assert_eq!(
+            state_diff.modified[0].before,
+            Some(serde_json::json!({"json": 2}))
+        );
+        assert_eq!(
+            state_diff.modified[0].after,
+            Some(serde_json::json!({"json": 22}))
+        );
+    }
+}
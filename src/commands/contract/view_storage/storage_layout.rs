@@ -0,0 +1,480 @@
+//! Decoding of Borsh-serialized contract storage with a storage layout: the type of the contract
+//! struct saved under the `STATE` key and the `near-sdk` collections with their key prefixes.
+
+use std::collections::BTreeMap;
+
+use borsh::schema::{BorshSchemaContainer, Declaration, Definition, Fields};
+use color_eyre::eyre::Context;
+
+/// The key under which `near-sdk` saves the contract struct.
+const STATE_KEY: &[u8] = b"STATE";
+
+/// The storage layout of a contract, as written in a layout file.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StorageLayout {
+    /// The type of the contract struct saved under the `STATE` key.
+    state: Option<Declaration>,
+    #[serde(default)]
+    collections: Vec<CollectionLayout>,
+    /// Borsh type definitions in the format of the Borsh schemas of the contract ABI.
+    #[serde(default)]
+    definitions: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+struct CollectionLayout {
+    name: String,
+    prefix: KeyPrefix,
+    #[serde(flatten)]
+    kind: CollectionKind,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(untagged)]
+enum KeyPrefix {
+    Utf8(String),
+    Bytes(Vec<u8>),
+}
+
+impl KeyPrefix {
+    fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::Utf8(prefix) => prefix.as_bytes(),
+            Self::Bytes(prefix) => prefix,
+        }
+    }
+}
+
+/// The `near-sdk` collections and how they lay out their elements under the collection prefix.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum CollectionKind {
+    /// `LookupMap` (both `near_sdk::store` and `near_sdk::collections`): `prefix + key` -> `value`
+    LookupMap {
+        key: Declaration,
+        value: Declaration,
+    },
+    /// `near_sdk::collections::UnorderedMap`: `prefix + 'k' + index` -> `key`,
+    /// `prefix + 'v' + index` -> `value` and `prefix + 'i' + key` -> `index`
+    UnorderedMap {
+        key: Declaration,
+        value: Declaration,
+    },
+    /// `Vector`: `prefix + index` -> `value` (a `u32` index in `near_sdk::store`, `u64` in `near_sdk::collections`)
+    Vector { value: Declaration },
+}
+
+impl CollectionKind {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::LookupMap { .. } => "lookup_map",
+            Self::UnorderedMap { .. } => "unordered_map",
+            Self::Vector { .. } => "vector",
+        }
+    }
+}
+
+impl StorageLayout {
+    pub fn read_from_file(file_path: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let data = std::fs::read(file_path)
+            .wrap_err_with(|| format!("Failed to open or read the file: {file_path:?}."))?;
+        serde_json::from_slice(&data)
+            .wrap_err_with(|| format!("The file {file_path:?} is not a valid storage layout."))
+    }
+}
+
+pub struct StorageDecoder {
+    layout: StorageLayout,
+    definitions: BTreeMap<Declaration, Definition>,
+}
+
+impl StorageDecoder {
+    /// The definitions of the layout file take precedence over the definitions found in the
+    /// Borsh schemas of the contract ABI, which take precedence over the common types
+    /// (`String`, `Vec<u8>`, `AccountId`, `U64` and `U128`).
+    pub fn new(
+        layout: StorageLayout,
+        abi: Option<&near_abi::AbiRoot>,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut definitions = common_definitions();
+        if let Some(abi) = abi {
+            for function in &abi.body.functions {
+                if let near_abi::AbiParameters::Borsh { args } = &function.params {
+                    for arg in args {
+                        extend_definitions(&mut definitions, &arg.type_schema);
+                    }
+                }
+                if let Some(near_abi::AbiType::Borsh { type_schema }) = &function.result {
+                    extend_definitions(&mut definitions, type_schema);
+                }
+            }
+        }
+        // The layout file uses the format of the ABI, so its definitions are parsed as an ABI type.
+        let near_abi::AbiType::Borsh { type_schema } = serde_json::from_value(serde_json::json!({
+            "serialization_type": "borsh",
+            "type_schema": { "declaration": "()", "definitions": layout.definitions },
+        }))
+        .wrap_err("The Borsh type definitions of the storage layout are invalid")?
+        else {
+            unreachable!("the serialization type is Borsh");
+        };
+        extend_definitions(&mut definitions, &type_schema);
+        Ok(Self {
+            layout,
+            definitions,
+        })
+    }
+
+    /// Decodes the contract state; the keys that do not match the layout are decoded as JSON,
+    /// UTF-8 text or hex.
+    pub fn decode_state(&self, values: &[near_primitives::views::StateItem]) -> DecodedState {
+        let mut decoded_state = DecodedState {
+            state: None,
+            collections: self
+                .layout
+                .collections
+                .iter()
+                .map(|collection| DecodedCollection {
+                    name: collection.name.clone(),
+                    kind: collection.kind.name(),
+                    entries: Vec::new(),
+                })
+                .collect(),
+            other: Vec::new(),
+        };
+        // Map and vector elements by their logical index
+        let mut indexed_entries =
+            vec![BTreeMap::<u64, DecodedEntry>::new(); self.layout.collections.len()];
+
+        for item in values {
+            if let Some(state) = &self.layout.state
+                && item.key.as_slice() == STATE_KEY
+            {
+                decoded_state.state = Some(DecodedEntry {
+                    key: Some(serde_json::json!("STATE")),
+                    ..self.decode_value(state, &item.value)
+                });
+                continue;
+            }
+            // The longest prefix wins, as collections may be nested under other prefixes
+            let Some((collection_index, collection)) = self
+                .layout
+                .collections
+                .iter()
+                .enumerate()
+                .filter(|(_, collection)| item.key.starts_with(collection.prefix.as_bytes()))
+                .max_by_key(|(_, collection)| collection.prefix.as_bytes().len())
+            else {
+                decoded_state
+                    .other
+                    .push(DecodedEntry::raw(&item.key, &item.value));
+                continue;
+            };
+            let suffix = &item.key[collection.prefix.as_bytes().len()..];
+            match &collection.kind {
+                CollectionKind::LookupMap { key, value } => {
+                    let mut entry = self.decode_value(value, &item.value);
+                    entry.set_key(self.decode_value(key, suffix));
+                    decoded_state.collections[collection_index]
+                        .entries
+                        .push(entry);
+                }
+                CollectionKind::Vector { value } => match decode_index(suffix) {
+                    Some(index) => {
+                        let mut entry = self.decode_value(value, &item.value);
+                        entry.index = Some(index);
+                        indexed_entries[collection_index].insert(index, entry);
+                    }
+                    None => decoded_state
+                        .other
+                        .push(DecodedEntry::raw(&item.key, &item.value)),
+                },
+                CollectionKind::UnorderedMap { key, value } => match suffix.split_first() {
+                    // The index of a key duplicates the `k` entries
+                    Some((b'i', _)) => {}
+                    Some((part @ (b'k' | b'v'), index)) => {
+                        let Some(index) = decode_index(index) else {
+                            decoded_state
+                                .other
+                                .push(DecodedEntry::raw(&item.key, &item.value));
+                            continue;
+                        };
+                        let entry = indexed_entries[collection_index]
+                            .entry(index)
+                            .or_insert_with(|| DecodedEntry {
+                                index: Some(index),
+                                ..DecodedEntry::default()
+                            });
+                        if *part == b'k' {
+                            entry.set_key(self.decode_value(key, &item.value));
+                        } else {
+                            let decoded_value = self.decode_value(value, &item.value);
+                            entry.value = decoded_value.value;
+                            entry.errors.extend(decoded_value.errors);
+                        }
+                    }
+                    _ => decoded_state
+                        .other
+                        .push(DecodedEntry::raw(&item.key, &item.value)),
+                },
+            }
+        }
+        for (collection, entries) in decoded_state.collections.iter_mut().zip(indexed_entries) {
+            collection.entries.extend(entries.into_values());
+        }
+        decoded_state
+    }
+
+    /// Decodes the data as a value of the type; data that does not match the type is shown raw.
+    fn decode_value(&self, declaration: &Declaration, data: &[u8]) -> DecodedEntry {
+        let schema = BorshSchemaContainer::new(declaration.clone(), self.definitions.clone());
+        match super::super::borsh_schema::decode(&schema, data) {
+            Ok(value) => DecodedEntry {
+                value: Some(value),
+                ..DecodedEntry::default()
+            },
+            Err(err) => DecodedEntry {
+                value: Some(super::state_diff::decode_bytes(data)),
+                errors: vec![err.to_string()],
+                ..DecodedEntry::default()
+            },
+        }
+    }
+}
+
+fn common_definitions() -> BTreeMap<Declaration, Definition> {
+    let mut definitions = BTreeMap::new();
+    extend_definitions(
+        &mut definitions,
+        &BorshSchemaContainer::for_type::<String>(),
+    );
+    extend_definitions(
+        &mut definitions,
+        &BorshSchemaContainer::for_type::<Vec<u8>>(),
+    );
+    for (declaration, wrapped) in [("AccountId", "String"), ("U64", "u64"), ("U128", "u128")] {
+        definitions.insert(
+            declaration.to_string(),
+            Definition::Struct {
+                fields: Fields::UnnamedFields(vec![wrapped.to_string()]),
+            },
+        );
+    }
+    definitions
+}
+
+fn extend_definitions(
+    definitions: &mut BTreeMap<Declaration, Definition>,
+    schema: &BorshSchemaContainer,
+) {
+    definitions.extend(
+        schema
+            .definitions()
+            .map(|(declaration, definition)| (declaration.clone(), definition.clone())),
+    );
+}
+
+/// Vector indices are `u32` in `near_sdk::store` and `u64` in `near_sdk::collections`.
+fn decode_index(bytes: &[u8]) -> Option<u64> {
+    match bytes.len() {
+        4 => Some(u32::from_le_bytes(bytes.try_into().ok()?).into()),
+        8 => Some(u64::from_le_bytes(bytes.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DecodedState {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<DecodedEntry>,
+    collections: Vec<DecodedCollection>,
+    /// The keys that do not match the storage layout.
+    other: Vec<DecodedEntry>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct DecodedCollection {
+    name: String,
+    kind: &'static str,
+    entries: Vec<DecodedEntry>,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+struct DecodedEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    index: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    key: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
+}
+
+impl DecodedEntry {
+    fn raw(key: &[u8], value: &[u8]) -> Self {
+        Self {
+            key: Some(super::state_diff::decode_bytes(key)),
+            value: Some(super::state_diff::decode_bytes(value)),
+            ..Self::default()
+        }
+    }
+
+    fn set_key(&mut self, decoded_key: DecodedEntry) {
+        self.key = decoded_key.value;
+        self.errors.extend(decoded_key.errors);
+    }
+}
+
+impl DecodedState {
+    pub fn print(
+        &self,
+        contract_account_id: &near_primitives::types::AccountId,
+        block_height: near_primitives::types::BlockHeight,
+        block_hash: near_primitives::hash::CryptoHash,
+        output_format: crate::common::OutputFormat,
+    ) -> crate::CliResult {
+        use color_eyre::owo_colors::OwoColorize;
+
+        if let crate::common::OutputFormat::Json = output_format {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "contract_account_id": contract_account_id,
+                    "block_height": block_height,
+                    "block_hash": block_hash,
+                    "state": self.state,
+                    "collections": self.collections,
+                    "other": self.other,
+                }))?
+            );
+            return Ok(());
+        }
+
+        let print_entry = |entry: &DecodedEntry| {
+            if let Some(index) = entry.index {
+                println!("\tindex: {}", index.cyan());
+            }
+            if let Some(key) = &entry.key {
+                println!("\tkey:   {}", key.to_string().green());
+            }
+            if let Some(value) = &entry.value {
+                println!("\tvalue: {}", value.to_string().yellow());
+            }
+            for error in &entry.errors {
+                println!("\terror: {}", error.red());
+            }
+            println!("\t--------------------------------");
+        };
+        println!(
+            "Contract state of <{contract_account_id}> at block #{block_height} ({block_hash}):"
+        );
+        if let Some(state) = &self.state {
+            println!("\nSTATE:");
+            print_entry(state);
+        }
+        for collection in &self.collections {
+            println!(
+                "\nCollection \"{}\" ({}, {} entries):",
+                collection.name,
+                collection.kind,
+                collection.entries.len()
+            );
+            collection.entries.iter().for_each(print_entry);
+        }
+        if !self.other.is_empty() {
+            println!(
+                "\nKeys that do not match the storage layout ({}):",
+                self.other.len()
+            );
+            self.other.iter().for_each(print_entry);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(key: &[u8], value: Vec<u8>) -> near_primitives::views::StateItem {
+        near_primitives::views::StateItem {
+            key: key.to_vec().into(),
+            value: value.into(),
+        }
+    }
+
+    #[test]
+    fn decodes_state_and_collections() {
+        let layout: StorageLayout = serde_json::from_value(serde_json::json!({
+            "state": "Contract",
+            "collections": [
+                { "name": "balances", "prefix": "b", "kind": "lookup_map", "key": "AccountId", "value": "U128" },
+                { "name": "items", "prefix": [0], "kind": "vector", "value": "String" },
+                { "name": "owners", "prefix": "o", "kind": "unordered_map", "key": "u64", "value": "AccountId" },
+            ],
+            "definitions": {
+                "Contract": { "Struct": [["owner", "AccountId"], ["items_count", "u32"]] },
+            },
+        }))
+        .unwrap();
+        let decoder = StorageDecoder::new(layout, None).unwrap();
+
+        let account = |account_id: &str| borsh::to_vec(&account_id.to_string()).unwrap();
+        let decoded_state = decoder.decode_state(&[
+            item(
+                b"STATE",
+                [account("owner.near"), 2_u32.to_le_bytes().to_vec()].concat(),
+            ),
+            item(&[0, 1, 0, 0, 0], borsh::to_vec("second").unwrap()),
+            item(&[0, 0, 0, 0, 0], borsh::to_vec("first").unwrap()),
+            item(
+                &[b"b".to_vec(), account("alice.near")].concat(),
+                10_u128.pow(24).to_le_bytes().to_vec(),
+            ),
+            item(b"oi\x07\0\0\0\0\0\0\0", 0_u64.to_le_bytes().to_vec()),
+            item(b"ok\0\0\0\0\0\0\0\0", 7_u64.to_le_bytes().to_vec()),
+            item(b"ov\0\0\0\0\0\0\0\0", account("bob.near")),
+            item(b"x", vec![1, 2]),
+        ]);
+        assert_eq!(
+            serde_json::to_value(&decoded_state).unwrap(),
+            serde_json::json!({
+                "state": { "key": "STATE", "value": { "owner": "owner.near", "items_count": 2 } },
+                "collections": [
+                    { "name": "balances", "kind": "lookup_map", "entries": [
+                        { "key": "alice.near", "value": "1000000000000000000000000" },
+                    ] },
+                    { "name": "items", "kind": "vector", "entries": [
+                        { "index": 0, "value": "first" },
+                        { "index": 1, "value": "second" },
+                    ] },
+                    { "name": "owners", "kind": "unordered_map", "entries": [
+                        { "index": 0, "key": 7, "value": "bob.near" },
+                    ] },
+                ],
+                "other": [
+                    { "key": { "utf8": "x" }, "value": { "hex": "0102" } },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn shows_values_that_do_not_match_the_type() {
+        let layout: StorageLayout = serde_json::from_value(serde_json::json!({
+            "collections": [{ "name": "flags", "prefix": "f", "kind": "lookup_map", "key": "String", "value": "Flag" }],
+        }))
+        .unwrap();
+        let decoder = StorageDecoder::new(layout, None).unwrap();
+        let decoded_state = decoder.decode_state(&[item(b"f\x01\0\0\0a", vec![1])]);
+        let entry = &decoded_state.collections[0].entries[0];
+        assert_eq!(entry.key, Some(serde_json::json!("a")));
+        assert_eq!(entry.value, Some(serde_json::json!({ "hex": "01" })));
+        assert_eq!(
+            entry.errors,
+            vec!["The Borsh schema has no definition for the type `Flag`"]
+        );
+    }
+}
@@ -1,6 +1,9 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
+pub mod sign_envelope;
 pub mod sign_nep413;
+pub mod verify_envelope;
+pub mod verify_nep413;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(context = crate::GlobalContext)]
@@ -20,4 +23,19 @@ pub enum MessageActions {
     ))]
     /// Sign a NEP-413 message off-chain
     SignNep413(self::sign_nep413::SignNep413),
+    #[strum_discriminants(strum(
+        message = "verify-nep413       - Verify a NEP-413 signed message"
+    ))]
+    /// Verify a NEP-413 signed message
+    VerifyNep413(self::verify_nep413::VerifyNep413),
+    #[strum_discriminants(strum(
+        message = "sign-envelope       - Sign a JSON or Borsh payload in a NEP-461 prefixed envelope"
+    ))]
+    /// Sign a JSON or Borsh payload in a NEP-461 prefixed envelope
+    SignEnvelope(self::sign_envelope::SignEnvelope),
+    #[strum_discriminants(strum(
+        message = "verify-envelope     - Verify a signed envelope offline"
+    ))]
+    /// Verify a signed envelope offline
+    VerifyEnvelope(self::verify_envelope::VerifyEnvelope),
 }
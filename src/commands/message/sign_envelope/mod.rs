@@ -0,0 +1,384 @@
+use color_eyre::eyre::{Context, eyre};
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+/// NEP-461 reserves the tags from 2^31 for off-chain messages: the tag is 2^31 + NEP number.
+const OFF_CHAIN_TAG_BASE: u32 = 1 << 31;
+/// NEP-413 messages have their own command, so their tag can't be used to forge a login.
+const NEP413: u64 = 413;
+
+/// Returns the NEP-461 off-chain prefix tag of the NEP.
+pub fn off_chain_tag(nep: u64) -> color_eyre::eyre::Result<u32> {
+    if nep >= 1 << 30 {
+        return Err(eyre!(
+            "NEP number {nep} is too large: the off-chain prefix tag is 2^31 + NEP, and NEP must be less than 2^30"
+        ));
+    }
+    if nep == NEP413 {
+        return Err(eyre!(
+            "NEP-413 messages must be signed with the `message sign-nep413` command"
+        ));
+    }
+    Ok(OFF_CHAIN_TAG_BASE + nep as u32)
+}
+
+/// The payload of a signed envelope.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EnvelopeData {
+    Json(serde_json::Value),
+    Borsh(Vec<u8>),
+}
+
+impl EnvelopeData {
+    /// JSON payloads are signed in the canonical form (sorted keys, no whitespace), so the
+    /// signature doesn't depend on how the envelope is formatted.
+    pub fn to_bytes(&self) -> color_eyre::eyre::Result<Vec<u8>> {
+        match self {
+            Self::Json(value) => Ok(serde_json::to_vec(&canonicalize_json(value))?),
+            Self::Borsh(bytes) => Ok(bytes.clone()),
+        }
+    }
+}
+
+fn canonicalize_json(value: &serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries = map.iter().collect::<Vec<_>>();
+            entries.sort_by_key(|(key, _)| *key);
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key.clone(), canonicalize_json(value)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(values) => {
+            serde_json::Value::Array(values.iter().map(canonicalize_json).collect())
+        }
+        value => value.clone(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EnvelopePayload {
+    pub nep: u64,
+    pub data: EnvelopeData,
+}
+
+impl EnvelopePayload {
+    /// The bytes that are hashed and signed: the prefix tag and the payload.
+    pub fn signed_bytes(&self) -> color_eyre::eyre::Result<Vec<u8>> {
+        let mut bytes = off_chain_tag(self.nep)?.to_le_bytes().to_vec();
+        bytes.extend(self.data.to_bytes()?);
+        Ok(bytes)
+    }
+
+    pub fn hash(&self) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+        Ok(near_primitives::hash::hash(&self.signed_bytes()?))
+    }
+}
+
+#[derive(Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Encoding {
+    Json,
+    Borsh,
+}
+
+/// The portable form of a signed envelope that `message verify-envelope` checks.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SignedEnvelope {
+    pub nep: u64,
+    pub tag: u32,
+    pub encoding: Encoding,
+    /// The JSON value itself, or the base64-encoded Borsh bytes.
+    pub payload: serde_json::Value,
+    pub account_id: near_primitives::types::AccountId,
+    pub public_key: near_crypto::PublicKey,
+    pub signature: near_crypto::Signature,
+}
+
+impl SignedEnvelope {
+    pub fn new(
+        payload: &EnvelopePayload,
+        account_id: near_primitives::types::AccountId,
+        public_key: near_crypto::PublicKey,
+        signature: near_crypto::Signature,
+    ) -> color_eyre::eyre::Result<Self> {
+        let (encoding, data) = match &payload.data {
+            EnvelopeData::Json(value) => (Encoding::Json, value.clone()),
+            EnvelopeData::Borsh(bytes) => (
+                Encoding::Borsh,
+                serde_json::Value::String(near_primitives::serialize::to_base64(bytes)),
+            ),
+        };
+        Ok(Self {
+            nep: payload.nep,
+            tag: off_chain_tag(payload.nep)?,
+            encoding,
+            payload: data,
+            account_id,
+            public_key,
+            signature,
+        })
+    }
+
+    pub fn envelope_payload(&self) -> color_eyre::eyre::Result<EnvelopePayload> {
+        if off_chain_tag(self.nep)? != self.tag {
+            return Err(eyre!("The tag {} doesn't match NEP-{}", self.tag, self.nep));
+        }
+        let data = match self.encoding {
+            Encoding::Json => EnvelopeData::Json(self.payload.clone()),
+            Encoding::Borsh => {
+                let serde_json::Value::String(payload) = &self.payload else {
+                    return Err(eyre!("The Borsh payload must be a base64-encoded string"));
+                };
+                EnvelopeData::Borsh(
+                    near_primitives::serialize::from_base64(payload)
+                        .map_err(|err| eyre!("The Borsh payload is not valid base64: {err}"))?,
+                )
+            }
+        };
+        Ok(EnvelopePayload {
+            nep: self.nep,
+            data,
+        })
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = SignEnvelopeContext)]
+pub struct SignEnvelope {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Which NEP defines the payload (the prefix tag is 2^31 + NEP number)?
+    nep: u64,
+    #[interactive_clap(subcommand)]
+    payload_type: PayloadType,
+}
+
+#[derive(Debug, Clone)]
+pub struct SignEnvelopeContext {
+    global_context: crate::GlobalContext,
+    nep: u64,
+}
+
+impl SignEnvelopeContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<SignEnvelope as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        off_chain_tag(scope.nep)?;
+        Ok(Self {
+            global_context: previous_context,
+            nep: scope.nep,
+        })
+    }
+}
+
+impl SignEnvelope {
+    fn input_nep(_context: &crate::GlobalContext) -> color_eyre::eyre::Result<Option<u64>> {
+        loop {
+            let nep: u64 = inquire::CustomType::new(
+                "Which NEP defines the payload (the prefix tag is 2^31 + NEP number)?",
+            )
+            .prompt()?;
+            match off_chain_tag(nep) {
+                Ok(_) => return Ok(Some(nep)),
+                Err(err) => eprintln!("{err}"),
+            }
+        }
+    }
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = SignEnvelopeContext)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// Select the payload encoding:
+pub enum PayloadType {
+    #[strum_discriminants(strum(
+        message = "json     - The payload is a JSON value (signed in canonical form)"
+    ))]
+    /// The payload is a JSON value (signed in canonical form)
+    Json(JsonPayload),
+    #[strum_discriminants(strum(
+        message = "borsh    - The payload is Borsh-serialized bytes (base64-encoded)"
+    ))]
+    /// The payload is Borsh-serialized bytes (base64-encoded)
+    Borsh(BorshPayload),
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = SignEnvelopeContext)]
+#[interactive_clap(output_context = JsonPayloadContext)]
+pub struct JsonPayload {
+    /// Enter the JSON payload to sign:
+    payload: crate::types::json::Json,
+    #[interactive_clap(named_arg)]
+    sign_as: SignAs,
+}
+
+#[derive(Debug, Clone)]
+pub struct JsonPayloadContext(SignAsContext);
+
+impl JsonPayloadContext {
+    pub fn from_previous_context(
+        previous_context: SignEnvelopeContext,
+        scope: &<JsonPayload as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self(SignAsContext {
+            global_context: previous_context.global_context,
+            payload: EnvelopePayload {
+                nep: previous_context.nep,
+                data: EnvelopeData::Json(scope.payload.clone().into()),
+            },
+        }))
+    }
+}
+
+impl From<JsonPayloadContext> for SignAsContext {
+    fn from(item: JsonPayloadContext) -> Self {
+        item.0
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = SignEnvelopeContext)]
+#[interactive_clap(output_context = BorshPayloadContext)]
+pub struct BorshPayload {
+    /// Enter the base64-encoded Borsh payload to sign:
+    payload: crate::types::base64_bytes::Base64Bytes,
+    #[interactive_clap(named_arg)]
+    sign_as: SignAs,
+}
+
+#[derive(Debug, Clone)]
+pub struct BorshPayloadContext(SignAsContext);
+
+impl BorshPayloadContext {
+    pub fn from_previous_context(
+        previous_context: SignEnvelopeContext,
+        scope: &<BorshPayload as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        Ok(Self(SignAsContext {
+            global_context: previous_context.global_context,
+            payload: EnvelopePayload {
+                nep: previous_context.nep,
+                data: EnvelopeData::Borsh(scope.payload.clone().into_bytes()),
+            },
+        }))
+    }
+}
+
+impl From<BorshPayloadContext> for SignAsContext {
+    fn from(item: BorshPayloadContext) -> Self {
+        item.0
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = SignAsContext)]
+#[interactive_clap(output_context = SignAsWrapperContext)]
+pub struct SignAs {
+    /// Which account to sign the envelope with:
+    signer_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(subcommand)]
+    sign_with: super::sign_nep413::signature_options::SignWith,
+}
+
+#[derive(Debug, Clone)]
+pub struct SignAsContext {
+    global_context: crate::GlobalContext,
+    payload: EnvelopePayload,
+}
+
+#[derive(Debug, Clone)]
+pub struct SignAsWrapperContext(super::sign_nep413::FinalSignNep413Context);
+
+impl SignAsWrapperContext {
+    pub fn from_previous_context(
+        previous_context: SignAsContext,
+        scope: &<SignAs as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let on_after_signing_callback: super::sign_nep413::OnAfterSigningNep413Callback =
+            std::sync::Arc::new({
+                let payload = previous_context.payload.clone();
+
+                move |signed_message| {
+                    let signed_envelope = SignedEnvelope::new(
+                        &payload,
+                        signed_message.account_id.parse()?,
+                        signed_message
+                            .public_key
+                            .parse()
+                            .wrap_err("Failed to parse the public key of the signer")?,
+                        signed_message
+                            .signature
+                            .parse()
+                            .wrap_err("Failed to parse the signature")?,
+                    )?;
+                    println!("{}", serde_json::to_string_pretty(&signed_envelope)?);
+                    Ok(())
+                }
+            });
+
+        Ok(Self(super::sign_nep413::FinalSignNep413Context {
+            global_context: previous_context.global_context,
+            payload: super::sign_nep413::MessagePayload::Envelope(previous_context.payload),
+            signer_id: scope.signer_account_id.clone().into(),
+            on_after_signing_callback,
+        }))
+    }
+}
+
+impl From<SignAsWrapperContext> for super::sign_nep413::FinalSignNep413Context {
+    fn from(item: SignAsWrapperContext) -> Self {
+        item.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn off_chain_tag_is_in_the_nep461_off_chain_range() {
+        assert_eq!(off_chain_tag(1000).unwrap(), 2147484648);
+        assert!(off_chain_tag(NEP413).is_err());
+        assert!(off_chain_tag(1 << 30).is_err());
+    }
+
+    #[test]
+    fn json_payload_is_signed_in_canonical_form() {
+        let payload = EnvelopePayload {
+            nep: 1000,
+            data: EnvelopeData::Json(
+                serde_json::from_str(r#"{ "b": [ { "y": 1, "x": 2 } ], "a": "order" }"#).unwrap(),
+            ),
+        };
+        let mut expected = 2147484648u32.to_le_bytes().to_vec();
+        expected.extend(br#"{"a":"order","b":[{"x":2,"y":1}]}"#);
+        assert_eq!(payload.signed_bytes().unwrap(), expected);
+    }
+
+    #[test]
+    fn signed_envelope_round_trips_the_borsh_payload() {
+        let payload = EnvelopePayload {
+            nep: 1000,
+            data: EnvelopeData::Borsh(vec![1, 2, 3]),
+        };
+        let secret_key = near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "alice");
+        let signature = secret_key.sign(payload.hash().unwrap().as_ref());
+        let signed_envelope = SignedEnvelope::new(
+            &payload,
+            "alice.near".parse().unwrap(),
+            secret_key.public_key(),
+            signature,
+        )
+        .unwrap();
+        assert_eq!(signed_envelope.payload, serde_json::json!("AQID"));
+
+        let envelope_payload = signed_envelope.envelope_payload().unwrap();
+        assert_eq!(envelope_payload.data, payload.data);
+        assert_eq!(envelope_payload.hash().unwrap(), payload.hash().unwrap());
+    }
+}
@@ -26,14 +26,37 @@ pub struct SignedMessage {
     pub signature: String,
 }
 
-pub fn sign_nep413_payload(
+/// The hash that is signed: the NEP-413 prefix tag and the Borsh-serialized payload.
+pub fn nep413_payload_hash(
     payload: &NEP413Payload,
-    secret_key: &SecretKey,
-) -> color_eyre::eyre::Result<Signature> {
+) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
     const NEP413_SIGN_MESSAGE_PREFIX: u32 = (1u32 << 31u32) + 413u32;
     let mut bytes = NEP413_SIGN_MESSAGE_PREFIX.to_le_bytes().to_vec();
     borsh::to_writer(&mut bytes, payload)?;
-    let hash = hash(&bytes);
+    Ok(hash(&bytes))
+}
+
+/// The data to sign: a NEP-413 message or the payload of a signed envelope.
+#[derive(Debug, Clone)]
+pub enum MessagePayload {
+    Nep413(NEP413Payload),
+    Envelope(super::sign_envelope::EnvelopePayload),
+}
+
+impl MessagePayload {
+    pub fn hash(&self) -> color_eyre::eyre::Result<near_primitives::hash::CryptoHash> {
+        match self {
+            Self::Nep413(payload) => nep413_payload_hash(payload),
+            Self::Envelope(payload) => payload.hash(),
+        }
+    }
+}
+
+pub fn sign_message_payload(
+    payload: &MessagePayload,
+    secret_key: &SecretKey,
+) -> color_eyre::eyre::Result<Signature> {
+    let hash = payload.hash()?;
     let signature = secret_key.sign(hash.as_ref());
     Ok(signature)
 }
@@ -50,6 +73,20 @@ impl From<NEP413Payload> for near_ledger::NEP413Payload {
     }
 }
 
+#[cfg(feature = "ledger")]
+impl TryFrom<MessagePayload> for near_ledger::NEP413Payload {
+    type Error = color_eyre::eyre::Error;
+
+    fn try_from(payload: MessagePayload) -> Result<Self, Self::Error> {
+        match payload {
+            MessagePayload::Nep413(payload) => Ok(payload.into()),
+            MessagePayload::Envelope(_) => Err(color_eyre::eyre::eyre!(
+                "The NEAR Ledger app can only sign NEP-413 messages, not signed envelopes"
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
 #[interactive_clap(input_context = crate::GlobalContext)]
 #[interactive_clap(output_context = SignNep413Context)]
@@ -87,7 +124,7 @@ fn default_on_after_signing_nep413_callback() -> OnAfterSigningNep413Callback {
 #[derive(Clone)]
 pub struct FinalSignNep413Context {
     pub global_context: crate::GlobalContext,
-    pub payload: NEP413Payload,
+    pub payload: MessagePayload,
     pub signer_id: near_primitives::types::AccountId,
     pub on_after_signing_callback: OnAfterSigningNep413Callback,
 }
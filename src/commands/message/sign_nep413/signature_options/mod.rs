@@ -5,6 +5,7 @@ pub mod sign_with_keychain;
 #[cfg(feature = "ledger")]
 pub mod sign_with_ledger;
 pub mod sign_with_legacy_keychain;
+pub mod sign_with_mpc;
 pub mod sign_with_private_key;
 pub mod sign_with_seed_phrase;
 
@@ -38,4 +39,8 @@ pub enum SignWith {
         message = "sign-with-seed-phrase            - Sign using a seed phrase"
     ))]
     SignWithSeedPhrase(self::sign_with_seed_phrase::SignSeedPhrase),
+    #[strum_discriminants(strum(
+        message = "sign-with-mpc                    - Sign with a key derived by the MPC contract (chain signatures)"
+    ))]
+    SignWithMpc(self::sign_with_mpc::SignMpc),
 }
@@ -22,7 +22,7 @@ impl SignAccessKeyFileContext {
             serde_json::from_str(&data)
                 .wrap_err_with(|| format!("Error reading data from file: {:?}", scope.file_path))?;
 
-        let signature = super::super::sign_nep413_payload(
+        let signature = super::super::sign_message_payload(
             &previous_context.payload,
             &account_json.private_key,
         )?;
@@ -22,7 +22,7 @@ impl SignKeychainContext {
                 move |network_config| {
                     let key_pair = crate::commands::account::export_account::get_account_key_pair_from_keychain(network_config, &signer_id)?;
                     let signature =
-                        super::super::sign_nep413_payload(&payload, &key_pair.private_key)?;
+                        super::super::sign_message_payload(&payload, &key_pair.private_key)?;
 
                     let signed_message = super::super::SignedMessage {
                         account_id: signer_id.to_string(),
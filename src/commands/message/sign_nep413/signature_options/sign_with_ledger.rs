@@ -24,6 +24,11 @@ impl SignLedgerContext {
         previous_context: super::super::FinalSignNep413Context,
         scope: &<SignLedger as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
+        if let super::super::MessagePayload::Envelope(_) = previous_context.payload {
+            return Err(color_eyre::eyre::eyre!(
+                "The NEAR Ledger app can only sign NEP-413 messages, not signed envelopes"
+            ));
+        }
         Ok(Self {
             final_context: previous_context,
             seed_phrase_hd_path: scope.seed_phrase_hd_path.clone(),
@@ -80,7 +85,7 @@ impl UsbSignNep413Context {
         );
 
         let signature_bytes = near_ledger::sign_message_nep413(
-            &previous_context.final_context.payload.into(),
+            &previous_context.final_context.payload.try_into()?,
             seed_phrase_hd_path.into(),
         )
         .map_err(|err| color_eyre::eyre::eyre!("Ledger signing error: {:?}", err))?;
@@ -120,7 +125,7 @@ impl BleSignNep413Context {
         let (verifying_key, signature_bytes) =
             crate::transaction_signature_options::sign_with_ledger::ble_helpers::ble_get_public_key_and_sign_nep413(
                 seed_phrase_hd_path.clone().into(),
-                previous_context.final_context.payload.into(),
+                previous_context.final_context.payload.try_into()?,
             )?;
 
         let public_key = near_crypto::PublicKey::ED25519(near_crypto::ED25519PublicKey::from(
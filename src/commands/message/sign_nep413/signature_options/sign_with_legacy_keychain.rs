@@ -31,7 +31,7 @@ impl SignLegacyKeychainContext {
                         &credentials_home_dir
                     )?;
                     let signature =
-                        super::super::sign_nep413_payload(&payload, &key_pair.private_key)?;
+                        super::super::sign_message_payload(&payload, &key_pair.private_key)?;
 
                     let signed_message = super::super::SignedMessage {
                         account_id: signer_id.to_string(),
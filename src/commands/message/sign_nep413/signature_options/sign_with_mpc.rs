@@ -0,0 +1,283 @@
+use color_eyre::eyre::{Context, eyre};
+use color_eyre::owo_colors::OwoColorize;
+
+use crate::common::JsonRpcClientExt;
+use crate::transaction_signature_options::sign_with_mpc::{
+    derive_public_key, mpc_sign_request, mpc_sign_result, near_key_type_to_mpc_domain_id,
+};
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = super::super::FinalSignNep413Context)]
+#[interactive_clap(output_context = SignMpcContext)]
+pub struct SignMpc {
+    #[interactive_clap(skip_default_input_arg)]
+    /// What is the Admin account address?
+    admin_account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(skip_default_input_arg, always_quote)]
+    /// What is the derivation path?
+    derivation_path: String,
+    /// Gas for the MPC contract call (default: 15 Tgas)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    prepaid_gas: Option<crate::common::NearGas>,
+    /// Deposit for the MPC contract call (default: 1 yoctoNEAR)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    attached_deposit: Option<crate::types::near_token::NearToken>,
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_for_transaction::NetworkForTransactionArgs,
+}
+
+#[derive(Clone)]
+pub struct SignMpcContext(crate::commands::ActionContext);
+
+impl SignMpcContext {
+    pub fn from_previous_context(
+        previous_context: super::super::FinalSignNep413Context,
+        scope: &<SignMpc as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        if previous_context.global_context.offline {
+            return Err(eyre!("Internet connection is required to sign with MPC!"));
+        }
+        let admin_account_id: near_primitives::types::AccountId =
+            scope.admin_account_id.clone().into();
+        let derivation_path = scope.derivation_path.clone();
+        let gas = scope
+            .prepaid_gas
+            .map(|gas| near_primitives::gas::Gas::from_gas(gas.as_gas()))
+            .unwrap_or_else(|| near_primitives::gas::Gas::from_teragas(15));
+        let deposit = scope
+            .attached_deposit
+            .map(Into::into)
+            .unwrap_or_else(|| near_token::NearToken::from_yoctonear(1));
+
+        let mpc_sign_request =
+            message_mpc_sign_request(&previous_context.payload, &derivation_path)?;
+
+        let get_prepopulated_transaction_after_getting_network_callback: crate::commands::GetPrepopulatedTransactionAfterGettingNetworkCallback =
+            std::sync::Arc::new({
+                let admin_account_id = admin_account_id.clone();
+                let derivation_path = derivation_path.clone();
+                let signer_id = previous_context.signer_id.clone();
+                let mpc_sign_request = mpc_sign_request.clone();
+
+                move |network_config| {
+                    let derived_public_key = derive_public_key(
+                        &network_config.get_mpc_contract_account_id()?,
+                        &admin_account_id,
+                        &derivation_path,
+                        &near_crypto::KeyType::ED25519,
+                        network_config,
+                    )?;
+                    tracing::info!(
+                        "Derived public key for <{}>:{}",
+                        signer_id,
+                        crate::common::indent_payload(&format!("\n{derived_public_key}\n"))
+                    );
+                    warn_if_not_full_access_key(network_config, &signer_id, &derived_public_key);
+
+                    Ok(crate::commands::PrepopulatedTransaction {
+                        signer_id: admin_account_id.clone(),
+                        receiver_id: network_config.get_mpc_contract_account_id()?,
+                        actions: vec![near_primitives::transaction::Action::FunctionCall(
+                            Box::new(near_primitives::transaction::FunctionCallAction {
+                                method_name: "sign".to_string(),
+                                args: serde_json::to_vec(&mpc_sign_request)?,
+                                gas,
+                                deposit,
+                            }),
+                        )],
+                    })
+                }
+            });
+
+        let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback = std::sync::Arc::new({
+            let admin_account_id = admin_account_id.clone();
+            let signer_id = previous_context.signer_id.clone();
+            let payload = previous_context.payload.clone();
+            let on_after_signing_callback = previous_context.on_after_signing_callback.clone();
+
+            move |outcome_view, network_config| {
+                // A DAO admin account submits the sign request as a proposal, so the signature
+                // is only available after the proposal is executed.
+                let is_dao_proposal = matches!(
+                    outcome_view.transaction.actions.first(),
+                    Some(near_primitives::views::ActionView::FunctionCall { method_name, .. })
+                        if method_name == "add_proposal"
+                );
+                let sign_result: mpc_sign_result::SignResult = if is_dao_proposal {
+                    let Some(sign_result) = crate::transaction_signature_options::sign_with_mpc::prompt_for_dao_mpc_sign_result(
+                        network_config,
+                        outcome_view,
+                        &mpc_sign_request,
+                    )?
+                    else {
+                        return Ok(());
+                    };
+                    sign_result
+                } else {
+                    let near_primitives::views::FinalExecutionStatus::SuccessValue(result) =
+                        &outcome_view.status
+                    else {
+                        return Err(eyre!(
+                            "Failed to sign the message for <{signer_id}>: unexpected outcome of the \"sign\" call to the MPC contract"
+                        ));
+                    };
+                    serde_json::from_slice(result)
+                        .wrap_err("Couldn't parse sign response from MPC contract")?
+                };
+                let signature: near_crypto::Signature = sign_result.into();
+
+                let derived_public_key = derive_public_key(
+                    &network_config.get_mpc_contract_account_id()?,
+                    &admin_account_id,
+                    &derivation_path,
+                    &near_crypto::KeyType::ED25519,
+                    network_config,
+                )?;
+                if !signature.verify(payload.hash()?.as_ref(), &derived_public_key) {
+                    return Err(eyre!(
+                        "The signature returned by the MPC contract does not match the derived public key <{derived_public_key}>"
+                    ));
+                }
+
+                on_after_signing_callback(super::super::SignedMessage {
+                    account_id: signer_id.to_string(),
+                    public_key: derived_public_key.to_string(),
+                    signature: signature.to_string(),
+                })
+            }
+        });
+
+        Ok(Self(crate::commands::ActionContext {
+            global_context: previous_context.global_context,
+            interacting_with_account_ids: vec![admin_account_id, previous_context.signer_id],
+            get_prepopulated_transaction_after_getting_network_callback,
+            on_before_signing_callback: std::sync::Arc::new(
+                |_prepopulated_unsigned_transaction, _network_config| Ok(()),
+            ),
+            on_before_sending_transaction_callback: std::sync::Arc::new(
+                |_signed_transaction, _network_config| Ok(String::new()),
+            ),
+            on_after_sending_transaction_callback,
+            on_sending_delegate_action_callback: None,
+            sign_as_delegate_action: false,
+        }))
+    }
+}
+
+impl From<SignMpcContext> for crate::commands::ActionContext {
+    fn from(item: SignMpcContext) -> Self {
+        item.0
+    }
+}
+
+impl SignMpc {
+    pub fn input_admin_account_id(
+        context: &super::super::FinalSignNep413Context,
+    ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
+        crate::common::input_signer_account_id_from_used_account_list(
+            &context.global_context.config,
+            "What is the Admin AccountId?",
+        )
+    }
+
+    pub fn input_derivation_path(
+        _context: &super::super::FinalSignNep413Context,
+    ) -> color_eyre::eyre::Result<Option<String>> {
+        let derivation_path = inquire::Text::new("What is the derivation path?")
+            .with_help_message("The same path that was used to derive the key of the account")
+            .prompt()?;
+        Ok(Some(derivation_path))
+    }
+}
+
+/// Messages are signed with ed25519 keys, so the MPC contract signs the payload hash in the
+/// ed25519 domain.
+fn message_mpc_sign_request(
+    payload: &super::super::MessagePayload,
+    derivation_path: &str,
+) -> color_eyre::eyre::Result<mpc_sign_request::MpcSignRequest> {
+    let payload_hash = payload.hash()?;
+    Ok(mpc_sign_request::MpcSignRequest {
+        request: mpc_sign_request::MpcSignRequestArgs {
+            payload: mpc_sign_request::MpcSignPayload::Eddsa(payload_hash.0.to_vec()),
+            path: derivation_path.to_string(),
+            domain_id: near_key_type_to_mpc_domain_id(near_crypto::KeyType::ED25519),
+        },
+    })
+}
+
+/// NEP-413 signatures are accepted only from full access keys, so the derived key has to be
+/// added to the account before the signed message can be verified.
+fn warn_if_not_full_access_key(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) {
+    let is_full_access_key = network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key(
+            account_id,
+            public_key,
+            near_primitives::types::BlockReference::latest(),
+        )
+        .ok()
+        .and_then(|rpc_query_response| match rpc_query_response.kind {
+            near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key) => {
+                Some(access_key.permission)
+            }
+            _ => None,
+        })
+        .is_some_and(|permission| {
+            matches!(
+                permission,
+                near_primitives::views::AccessKeyPermissionView::FullAccess
+                    | near_primitives::views::AccessKeyPermissionView::GasKeyFullAccess { .. }
+            )
+        });
+    if !is_full_access_key {
+        tracing::warn!(
+            "The derived key is not a full access key of <{account_id}>, so the signed message will not pass the on-chain check. You can add it with:{}",
+            crate::common::indent_payload(
+                &format!(
+                    "\n{} account add-key {} grant-full-access use-manually-provided-public-key {}",
+                    crate::common::get_near_exec_path(),
+                    account_id,
+                    public_key
+                )
+                .yellow()
+                .to_string()
+            )
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_nep413_mpc_sign_request() {
+        let payload =
+            super::super::super::MessagePayload::Nep413(super::super::super::NEP413Payload {
+                message: "Login to myapp.com".to_string(),
+                nonce: [7; 32],
+                recipient: "myapp.com".to_string(),
+                callback_url: None,
+            });
+        let mpc_sign_request = message_mpc_sign_request(&payload, "alice-path").unwrap();
+        let payload_hash = payload.hash().unwrap();
+        assert_eq!(
+            serde_json::to_value(&mpc_sign_request).unwrap(),
+            serde_json::json!({
+                "request": {
+                    "payload_v2": { "Eddsa": hex::encode(payload_hash.0) },
+                    "path": "alice-path",
+                    "domain_id": 1,
+                }
+            })
+        );
+    }
+}
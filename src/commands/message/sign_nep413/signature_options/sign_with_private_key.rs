@@ -16,7 +16,7 @@ impl SignPrivateKeyContext {
     ) -> color_eyre::eyre::Result<Self> {
         let secret_key: near_crypto::SecretKey = scope.private_key.clone().into();
         let public_key = secret_key.public_key();
-        let signature = super::super::sign_nep413_payload(&previous_context.payload, &secret_key)?;
+        let signature = super::super::sign_message_payload(&previous_context.payload, &secret_key)?;
 
         let signed_message = super::super::SignedMessage {
             account_id: previous_context.signer_id.to_string(),
@@ -24,7 +24,7 @@ impl SignSeedPhraseContext {
         )?;
         let secret_key = near_crypto::SecretKey::from_str(&key_pair_properties.secret_keypair_str)?;
 
-        let signature = super::super::sign_nep413_payload(&previous_context.payload, &secret_key)?;
+        let signature = super::super::sign_message_payload(&previous_context.payload, &secret_key)?;
 
         let signed_message = super::super::SignedMessage {
             account_id: previous_context.signer_id.to_string(),
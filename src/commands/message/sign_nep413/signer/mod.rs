@@ -24,12 +24,12 @@ impl SignAsWrapperContext {
         previous_context: SignAsContext,
         scope: &<SignAs as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
-        let payload = super::NEP413Payload {
+        let payload = super::MessagePayload::Nep413(super::NEP413Payload {
             message: previous_context.message,
             nonce: previous_context.nonce.as_array(),
             recipient: previous_context.recipient,
             callback_url: None,
-        };
+        });
 
         Ok(Self(super::FinalSignNep413Context {
             global_context: previous_context.global_context,
@@ -0,0 +1,131 @@
+use color_eyre::eyre::{Context, eyre};
+use color_eyre::owo_colors::OwoColorize;
+
+use super::sign_envelope::SignedEnvelope;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = VerifyEnvelopeContext)]
+pub struct VerifyEnvelope {
+    /// What is the location of the signed envelope file (JSON)?
+    file_path: crate::types::path_buf::PathBuf,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The NEP that the envelope is expected to be prefixed with
+    nep: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyEnvelopeContext;
+
+impl VerifyEnvelopeContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<VerifyEnvelope as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let signed_envelope: SignedEnvelope =
+            serde_json::from_slice(&scope.file_path.read_bytes()?).wrap_err_with(|| {
+                format!("The file <{}> is not a signed envelope", scope.file_path)
+            })?;
+        if let Some(nep) = scope.nep
+            && nep != signed_envelope.nep
+        {
+            return Err(eyre!(
+                "The envelope is prefixed with NEP-{}, but NEP-{nep} was expected",
+                signed_envelope.nep
+            ));
+        }
+        if !verify_envelope_signature(&signed_envelope)? {
+            return Err(eyre!(
+                "The signature is not valid: it was not made with the key <{}>, or the payload differs from the signed one",
+                signed_envelope.public_key
+            ));
+        }
+
+        if let crate::common::OutputFormat::Json = previous_context.output_format {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "nep": signed_envelope.nep,
+                    "tag": signed_envelope.tag,
+                    "account_id": signed_envelope.account_id,
+                    "public_key": signed_envelope.public_key,
+                    "signature_valid": true,
+                }))?
+            );
+        } else {
+            println!(
+                "{}",
+                format!(
+                    "The signature is valid: the NEP-{} envelope was signed with the key <{}> of <{}>.",
+                    signed_envelope.nep, signed_envelope.public_key, signed_envelope.account_id
+                )
+                .green()
+            );
+            println!(
+                "{}",
+                "The key was not checked on-chain, so it may not belong to the account.".yellow()
+            );
+        }
+        Ok(Self)
+    }
+}
+
+/// Checks the signature of the envelope payload, prefixed with its NEP-461 off-chain tag.
+pub fn verify_envelope_signature(
+    signed_envelope: &SignedEnvelope,
+) -> color_eyre::eyre::Result<bool> {
+    let hash = signed_envelope.envelope_payload()?.hash()?;
+    Ok(signed_envelope
+        .signature
+        .verify(hash.as_ref(), &signed_envelope.public_key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::sign_envelope::{EnvelopeData, EnvelopePayload};
+    use super::*;
+
+    #[test]
+    fn verifies_envelope_signature() {
+        let secret_key = near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "alice");
+        let payload = EnvelopePayload {
+            nep: 1000,
+            data: EnvelopeData::Json(serde_json::json!({ "order": "buy", "amount": "10" })),
+        };
+        let signature = super::super::sign_nep413::sign_message_payload(
+            &super::super::sign_nep413::MessagePayload::Envelope(payload.clone()),
+            &secret_key,
+        )
+        .unwrap();
+        let signed_envelope: SignedEnvelope = serde_json::from_value(serde_json::json!({
+            "nep": 1000,
+            "tag": 2147484648u32,
+            "encoding": "json",
+            "payload": { "amount": "10", "order": "buy" },
+            "account_id": "alice.near",
+            "public_key": secret_key.public_key(),
+            "signature": signature,
+        }))
+        .unwrap();
+        assert!(verify_envelope_signature(&signed_envelope).unwrap());
+
+        let other_payload = SignedEnvelope {
+            payload: serde_json::json!({ "amount": "1000", "order": "buy" }),
+            ..signed_envelope.clone()
+        };
+        assert!(!verify_envelope_signature(&other_payload).unwrap());
+        let other_nep = SignedEnvelope {
+            nep: 1001,
+            tag: 2147484649,
+            ..signed_envelope.clone()
+        };
+        assert!(!verify_envelope_signature(&other_nep).unwrap());
+        let nep413_tag = SignedEnvelope {
+            nep: 413,
+            tag: 2147484061,
+            ..signed_envelope
+        };
+        assert!(verify_envelope_signature(&nep413_tag).is_err());
+    }
+}
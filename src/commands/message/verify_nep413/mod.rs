@@ -0,0 +1,300 @@
+use color_eyre::eyre::eyre;
+use color_eyre::owo_colors::OwoColorize;
+use strum::{EnumDiscriminants, EnumIter, EnumMessage};
+
+use crate::common::JsonRpcClientExt;
+
+use super::sign_nep413::NEP413Payload;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = VerifyNep413Context)]
+pub struct VerifyNep413 {
+    /// The signed text message (UTF-8 encoded):
+    message: String,
+    #[interactive_clap(long)]
+    /// A 32-byte nonce as a base64-encoded string:
+    nonce: crate::types::nonce32_bytes::Nonce32,
+    #[interactive_clap(long)]
+    /// The recipient of the message (e.g. "alice.near" or "myapp.com"):
+    recipient: String,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    /// The callback URL of the signed message
+    callback_url: Option<String>,
+    #[interactive_clap(long)]
+    /// Which account signed the message?
+    account_id: crate::types::account_id::AccountId,
+    #[interactive_clap(long)]
+    /// Enter the public key of the signer:
+    public_key: crate::types::public_key::PublicKey,
+    #[interactive_clap(long)]
+    /// Enter the signature (e.g. "ed25519:..."):
+    signature: crate::types::signature::Signature,
+    #[interactive_clap(subcommand)]
+    on_chain_check: OnChainCheck,
+}
+
+#[derive(Debug, Clone)]
+pub struct VerifyNep413Context {
+    global_context: crate::GlobalContext,
+    account_id: near_primitives::types::AccountId,
+    public_key: near_crypto::PublicKey,
+    signature: near_crypto::Signature,
+}
+
+impl VerifyNep413Context {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<VerifyNep413 as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let payload = NEP413Payload {
+            message: scope.message.clone(),
+            nonce: scope.nonce.as_array(),
+            recipient: scope.recipient.clone(),
+            callback_url: scope.callback_url.clone(),
+        };
+        let public_key: near_crypto::PublicKey = scope.public_key.clone().into();
+        let signature: near_crypto::Signature = scope.signature.clone().into();
+
+        if !verify_nep413_signature(&payload, &public_key, &signature)? {
+            return Err(eyre!(
+                "The signature is not valid: it was not made with the key <{public_key}>, or the message, nonce, recipient or callback URL differ from the signed ones"
+            ));
+        }
+        Ok(Self {
+            global_context: previous_context,
+            account_id: scope.account_id.clone().into(),
+            public_key,
+            signature,
+        })
+    }
+}
+
+/// Checks the signature of the NEP-413 payload hash, reconstructed as `sign-nep413` does it.
+pub fn verify_nep413_signature(
+    payload: &NEP413Payload,
+    public_key: &near_crypto::PublicKey,
+    signature: &near_crypto::Signature,
+) -> color_eyre::eyre::Result<bool> {
+    let hash = super::sign_nep413::nep413_payload_hash(payload)?;
+    Ok(signature.verify(hash.as_ref(), public_key))
+}
+
+#[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(context = VerifyNep413Context)]
+#[strum_discriminants(derive(EnumMessage, EnumIter))]
+/// Do you want to confirm on-chain that the key belongs to the account?
+pub enum OnChainCheck {
+    #[strum_discriminants(strum(
+        message = "check-on-chain        - Also confirm that the public key is a full access key of the account"
+    ))]
+    /// Also confirm that the public key is a full access key of the account
+    CheckOnChain(CheckOnChain),
+    #[strum_discriminants(strum(message = "skip-on-chain-check   - Verify only the signature"))]
+    /// Verify only the signature
+    SkipOnChainCheck(SkipOnChainCheck),
+}
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = VerifyNep413Context)]
+#[interactive_clap(output_context = CheckOnChainContext)]
+pub struct CheckOnChain {
+    #[interactive_clap(named_arg)]
+    /// Select network
+    network_config: crate::network_view_at_block::NetworkViewAtBlockArgs,
+}
+
+#[derive(Clone)]
+pub struct CheckOnChainContext(crate::network_view_at_block::ArgsForViewContext);
+
+impl CheckOnChainContext {
+    pub fn from_previous_context(
+        previous_context: VerifyNep413Context,
+        _scope: &<CheckOnChain as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let config = previous_context.global_context.config.clone();
+        let interacting_with_account_ids = vec![previous_context.account_id.clone()];
+        let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new(
+            move |network_config, block_reference| {
+                let on_chain_key = get_on_chain_full_access_key(
+                    network_config,
+                    &previous_context.account_id,
+                    &previous_context.public_key,
+                    block_reference,
+                )?;
+                print_verification_result(&previous_context, Some(&on_chain_key))
+            },
+        );
+        Ok(Self(crate::network_view_at_block::ArgsForViewContext {
+            config,
+            on_after_getting_block_reference_callback,
+            interacting_with_account_ids,
+        }))
+    }
+}
+
+impl From<CheckOnChainContext> for crate::network_view_at_block::ArgsForViewContext {
+    fn from(item: CheckOnChainContext) -> Self {
+        item.0
+    }
+}
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = VerifyNep413Context)]
+#[interactive_clap(output_context = SkipOnChainCheckContext)]
+pub struct SkipOnChainCheck;
+
+#[derive(Debug, Clone)]
+pub struct SkipOnChainCheckContext;
+
+impl SkipOnChainCheckContext {
+    pub fn from_previous_context(
+        previous_context: VerifyNep413Context,
+        _scope: &<SkipOnChainCheck as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        print_verification_result(&previous_context, None)?;
+        Ok(Self)
+    }
+}
+
+/// The access key of the signer found on-chain.
+#[derive(Debug, serde::Serialize)]
+struct OnChainKey {
+    network: String,
+    block_height: near_primitives::types::BlockHeight,
+    block_hash: near_primitives::hash::CryptoHash,
+    permission: near_primitives::views::AccessKeyPermissionView,
+}
+
+#[tracing::instrument(name = "Checking the access key on-chain ...", skip_all)]
+fn get_on_chain_full_access_key(
+    network_config: &crate::config::NetworkConfig,
+    account_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+    block_reference: &near_primitives::types::BlockReference,
+) -> color_eyre::eyre::Result<OnChainKey> {
+    tracing::info!(target: "near_teach_me", "Checking the access key on-chain ...");
+    let rpc_query_response = match network_config
+        .json_rpc_client()
+        .blocking_call_view_access_key(account_id, public_key, block_reference.clone())
+        .map_err(|err| *err)
+    {
+        Ok(rpc_query_response) => rpc_query_response,
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccessKey { .. },
+            ),
+        )) => {
+            return Err(eyre!(
+                "The public key <{public_key}> is not an access key of <{account_id}> on the <{}> network",
+                network_config.network_name
+            ));
+        }
+        Err(err) => {
+            return Err(eyre!(
+                "Failed to fetch the access key <{public_key}> of <{account_id}> on the <{}> network: {err}",
+                network_config.network_name
+            ));
+        }
+    };
+    let near_jsonrpc_primitives::types::query::QueryResponseKind::AccessKey(access_key_view) =
+        rpc_query_response.kind
+    else {
+        return Err(eyre!("Error call result"));
+    };
+    match access_key_view.permission {
+        near_primitives::views::AccessKeyPermissionView::FullAccess
+        | near_primitives::views::AccessKeyPermissionView::GasKeyFullAccess { .. } => {}
+        _ => {
+            return Err(eyre!(
+                "The public key <{public_key}> is a function-call access key of <{account_id}>, but NEP-413 messages must be signed with a full access key"
+            ));
+        }
+    }
+    Ok(OnChainKey {
+        network: network_config.network_name.clone(),
+        block_height: rpc_query_response.block_height,
+        block_hash: rpc_query_response.block_hash,
+        permission: access_key_view.permission,
+    })
+}
+
+fn print_verification_result(
+    context: &VerifyNep413Context,
+    on_chain_key: Option<&OnChainKey>,
+) -> crate::CliResult {
+    if let crate::common::OutputFormat::Json = context.global_context.output_format {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "account_id": context.account_id,
+                "public_key": context.public_key,
+                "signature": context.signature,
+                "signature_valid": true,
+                "on_chain": on_chain_key,
+            }))?
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        format!(
+            "The signature is valid: the message was signed with the key <{}> of <{}>.",
+            context.public_key, context.account_id
+        )
+        .green()
+    );
+    match on_chain_key {
+        Some(on_chain_key) => println!(
+            "The key is a full access key of <{}> on the <{}> network at block #{} ({}).",
+            context.account_id,
+            on_chain_key.network,
+            on_chain_key.block_height,
+            on_chain_key.block_hash
+        ),
+        None => println!(
+            "{}",
+            "The key was not checked on-chain, so it may not belong to the account.".yellow()
+        ),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_nep413_signature() {
+        let secret_key = near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "alice");
+        let public_key = secret_key.public_key();
+        let payload = NEP413Payload {
+            message: "Login to myapp.com".to_string(),
+            nonce: [7; 32],
+            recipient: "myapp.com".to_string(),
+            callback_url: Some("https://myapp.com/callback".to_string()),
+        };
+        let signature = super::super::sign_nep413::sign_message_payload(
+            &super::super::sign_nep413::MessagePayload::Nep413(payload.clone()),
+            &secret_key,
+        )
+        .unwrap();
+        assert!(verify_nep413_signature(&payload, &public_key, &signature).unwrap());
+
+        let other_recipient = NEP413Payload {
+            recipient: "evil.com".to_string(),
+            ..payload.clone()
+        };
+        assert!(!verify_nep413_signature(&other_recipient, &public_key, &signature).unwrap());
+        let without_callback_url = NEP413Payload {
+            callback_url: None,
+            ..payload.clone()
+        };
+        assert!(!verify_nep413_signature(&without_callback_url, &public_key, &signature).unwrap());
+        let other_public_key =
+            near_crypto::SecretKey::from_seed(near_crypto::KeyType::ED25519, "bob").public_key();
+        assert!(!verify_nep413_signature(&payload, &other_public_key, &signature).unwrap());
+    }
+}
@@ -2,6 +2,7 @@
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 pub mod account;
+pub mod batch;
 mod config;
 pub mod contract;
 pub mod message;
@@ -40,9 +41,14 @@ pub enum TopLevelCommand {
     #[strum_discriminants(strum(message = "transaction - Operate transactions"))]
     /// Use this to construct transactions or view a transaction status.
     Transaction(self::transaction::TransactionCommands),
-    #[strum_discriminants(strum(message = "message     - Sign an arbitrary message (NEP-413)"))]
-    /// Sign an arbitrary message (NEP-413)
+    #[strum_discriminants(strum(
+        message = "message     - Sign and verify arbitrary messages (NEP-413)"
+    ))]
+    /// Sign and verify arbitrary messages (NEP-413)
     Message(self::message::MessageCommand),
+    #[strum_discriminants(strum(message = "batch       - Run many commands from a manifest file"))]
+    /// Use this to run a list of commands from a manifest file (TOML or YAML) one by one
+    Batch(self::batch::BatchCommands),
     #[strum_discriminants(strum(
         message = "config      - Manage connections in a configuration file (config.toml)"
     ))]
@@ -26,6 +26,7 @@ impl ViewBalanceContext {
         let validator_account_id: near_primitives::types::AccountId =
             scope.validator_account_id.clone().into();
         let interacting_with_account_ids = vec![account_id.clone(), validator_account_id.clone()];
+        let output_format = previous_context.global_context.output_format;
 
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
 
@@ -35,6 +36,7 @@ impl ViewBalanceContext {
                     &validator_account_id,
                     network_config,
                     block_reference,
+                    output_format,
                 )
             }
         });
@@ -69,6 +71,7 @@ fn calculation_delegated_stake_balance(
     validator_account_id: &near_primitives::types::AccountId,
     network_config: &crate::config::NetworkConfig,
     block_reference: &near_primitives::types::BlockReference,
+    output_format: crate::common::OutputFormat,
 ) -> crate::CliResult {
     tracing::info!(target: "near_teach_me", "Calculation of the delegated stake balance for your account ...");
     let user_staked_balance: u128 = get_user_staked_balance(
@@ -89,12 +92,30 @@ fn calculation_delegated_stake_balance(
         validator_account_id,
         account_id,
     )?;
-    let withdrawal_availability_message =
-        match is_account_unstaked_balance_available_for_withdrawal(
+    let is_unstaked_balance_available_for_withdrawal =
+        is_account_unstaked_balance_available_for_withdrawal(
             network_config,
             validator_account_id,
             account_id,
-        )? {
+        )?;
+
+    if let crate::common::OutputFormat::Json = output_format {
+        tracing_indicatif::suspend_tracing_indicatif(|| {
+            serde_json::to_string_pretty(&serde_json::json!({
+                "account_id": account_id,
+                "validator_account_id": validator_account_id,
+                "staked_balance": near_token::NearToken::from_yoctonear(user_staked_balance),
+                "unstaked_balance": near_token::NearToken::from_yoctonear(user_unstaked_balance),
+                "total_balance": near_token::NearToken::from_yoctonear(user_total_balance),
+                "is_unstaked_balance_available_for_withdrawal": is_unstaked_balance_available_for_withdrawal,
+            }))
+            .map(|json| println!("{json}"))
+        })?;
+        return Ok(());
+    }
+
+    let withdrawal_availability_message =
+        match is_unstaked_balance_available_for_withdrawal {
             true if user_unstaked_balance > 0 => "(available for withdrawal)",
             false if user_unstaked_balance > 0 => {
                 "(not available for withdrawal in the current epoch)"
@@ -31,6 +31,7 @@ impl ViewFtBalanceContext {
             let owner_account_id = previous_context.owner_account_id.clone();
             let ft_contract = scope.ft_contract.clone();
             let credentials_home_dir = previous_context.global_context.config.credentials_home_dir.clone();
+            let output_format = previous_context.global_context.output_format;
 
             move |network_config, block_reference| {
                 if let FTContract::SingleContract(ft_contract_account_id) = &ft_contract {
@@ -63,9 +64,23 @@ impl ViewFtBalanceContext {
                         ft_metadata.symbol
                     );
 
-                    println!("<{owner_account_id}> account has {fungible_token}  (FT-contract: {ft_contract_account_id})");
+                    if let crate::common::OutputFormat::Json = output_format {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&json!({
+                                "account_id": owner_account_id,
+                                "ft_contract_account_id": ft_contract_account_id,
+                                "amount": fungible_token.amount().to_string(),
+                                "amount_formatted": fungible_token.to_string(),
+                                "decimals": fungible_token.decimals(),
+                                "symbol": fungible_token.symbol(),
+                            }))?
+                        );
+                    } else {
+                        println!("<{owner_account_id}> account has {fungible_token}  (FT-contract: {ft_contract_account_id})");
+                    }
                 } else {
-                    print_fts_inventory(network_config, &owner_account_id, previous_context.global_context.verbosity)?;
+                    print_fts_inventory(network_config, &owner_account_id, previous_context.global_context.verbosity, output_format)?;
                 }
                 Ok(())
             }
@@ -212,6 +227,7 @@ fn print_fts_inventory(
     network_config: &crate::config::NetworkConfig,
     account_id: &near_primitives::types::AccountId,
     verbosity: crate::Verbosity,
+    output_format: crate::common::OutputFormat,
 ) -> crate::CliResult {
     let inventory = get_account_ft_inventory(network_config, account_id)?;
     let min_usd = 0.1; // $0.10
@@ -222,6 +238,36 @@ fn print_fts_inventory(
         .filter_map(|ft| calculate_ft_usd_value(&ft).filter(|item| item.usd_value >= min_usd))
         .collect::<Vec<_>>();
 
+    fts.sort_by(|a, b| {
+        b.usd_value
+            .partial_cmp(&a.usd_value)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    if let crate::common::OutputFormat::Json = output_format {
+        let tokens = fts
+            .iter()
+            .map(|item| {
+                json!({
+                    "ft_contract_account_id": item.ft.ft_contract_account_id,
+                    "amount": item.ft.amount,
+                    "amount_formatted": item.tokens_scaled,
+                    "decimals": item.ft.ft_meta.decimals,
+                    "symbol": item.ft.ft_meta.symbol,
+                    "usd_value": item.usd_value,
+                })
+            })
+            .collect::<Vec<_>>();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({
+                "account_id": account_id,
+                "tokens": tokens,
+            }))?
+        );
+        return Ok(());
+    }
+
     if fts.is_empty() {
         if let crate::Verbosity::Interactive | crate::Verbosity::TeachMe = verbosity {
             eprintln!(
@@ -232,12 +278,6 @@ fn print_fts_inventory(
         return Ok(());
     }
 
-    fts.sort_by(|a, b| {
-        b.usd_value
-            .partial_cmp(&a.usd_value)
-            .unwrap_or(std::cmp::Ordering::Equal)
-    });
-
     let output = fts
         .iter()
         .map(|item| {
@@ -17,6 +17,7 @@ impl ViewNearBalanceContext {
     ) -> color_eyre::eyre::Result<Self> {
         let on_after_getting_block_reference_callback: crate::network_view_at_block::OnAfterGettingBlockReferenceCallback = std::sync::Arc::new({
             let owner_account_id = previous_context.owner_account_id.clone();
+            let output_format = previous_context.global_context.output_format;
 
             move |network_config, block_reference| {
                 let account_transfer_allowance = tokio::runtime::Runtime::new()
@@ -27,7 +28,11 @@ impl ViewNearBalanceContext {
                     block_reference.clone(),
                 ))?;
 
-                println!("{account_transfer_allowance}");
+                if let crate::common::OutputFormat::Json = output_format {
+                    println!("{}", serde_json::to_string_pretty(&account_transfer_allowance.to_json())?);
+                } else {
+                    println!("{account_transfer_allowance}");
+                }
 
                 Ok(())
             }
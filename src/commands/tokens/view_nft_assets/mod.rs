@@ -37,6 +37,18 @@ impl ViewNftAssetsContext {
                 call_result.print_logs();
                 let serde_call_result: serde_json::Value = call_result.parse_result_from_json()?;
 
+                if let crate::common::OutputFormat::Json = previous_context.global_context.output_format {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&json!({
+                            "account_id": owner_account_id,
+                            "nft_contract_account_id": nft_contract_account_id,
+                            "tokens": serde_call_result,
+                        }))?
+                    );
+                    return Ok(());
+                }
+
                 if let crate::Verbosity::Interactive | crate::Verbosity::TeachMe = previous_context.global_context.verbosity {
                     eprintln!("\n{owner_account_id} account has NFT tokens (printed to stdout):");
                 }
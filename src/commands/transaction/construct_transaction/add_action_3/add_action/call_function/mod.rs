@@ -36,9 +36,12 @@ impl FunctionCallActionContext {
         scope: &<FunctionCallAction as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
     ) -> color_eyre::eyre::Result<Self> {
         let function_args =
-            crate::commands::contract::call_function::call_function_args_type::function_args(
+            crate::commands::contract::call_function::call_function_args_type::function_args_for_account(
                 scope.function_args.clone(),
                 scope.function_args_type.clone(),
+                &previous_context.global_context,
+                &previous_context.receiver_account_id,
+                &scope.function_name,
             )?;
         Ok(Self {
             global_context: previous_context.global_context,
@@ -0,0 +1,226 @@
+use color_eyre::eyre::WrapErr;
+use prettytable::Table;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::GlobalContext)]
+#[interactive_clap(output_context = TransactionHistoryContext)]
+pub struct TransactionHistory {
+    /// Show only the transactions where this account is the signer or the receiver
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    account_id: Option<crate::types::account_id::AccountId>,
+    /// Show only the transactions sent to this network (the name of the network connection, e.g. testnet)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    network_name: Option<String>,
+    /// Show only the transactions sent on or after this date (YYYY-MM-DD or RFC 3339, UTC)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    since: Option<String>,
+    /// Show only the transactions sent on or before this date (YYYY-MM-DD or RFC 3339, UTC)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    until: Option<String>,
+    /// Show only the latest N matching transactions
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    limit: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct TransactionHistoryContext;
+
+impl TransactionHistoryContext {
+    pub fn from_previous_context(
+        previous_context: crate::GlobalContext,
+        scope: &<TransactionHistory as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let since = scope
+            .since
+            .as_deref()
+            .map(|since| parse_date(since, DateBound::Start))
+            .transpose()?;
+        let until = scope
+            .until
+            .as_deref()
+            .map(|until| parse_date(until, DateBound::End))
+            .transpose()?;
+        let account_id: Option<near_primitives::types::AccountId> =
+            scope.account_id.clone().map(Into::into);
+
+        let mut transaction_history: Vec<crate::common::TransactionHistoryEntry> =
+            crate::common::get_transaction_history(&previous_context.config.credentials_home_dir)?
+                .into_iter()
+                .filter(|entry| {
+                    account_id.as_ref().is_none_or(|account_id| {
+                        &entry.signer_id == account_id || &entry.receiver_id == account_id
+                    })
+                })
+                .filter(|entry| {
+                    scope
+                        .network_name
+                        .as_ref()
+                        .is_none_or(|network_name| &entry.network_name == network_name)
+                })
+                .filter(|entry| since.is_none_or(|since| entry.timestamp >= since))
+                .filter(|entry| until.is_none_or(|until| entry.timestamp <= until))
+                .collect();
+        if let Some(limit) = scope.limit {
+            let skipped = transaction_history
+                .len()
+                .saturating_sub(usize::try_from(limit).unwrap_or(usize::MAX));
+            transaction_history.drain(..skipped);
+        }
+
+        if let crate::common::OutputFormat::Json = previous_context.output_format {
+            println!("{}", serde_json::to_string_pretty(&transaction_history)?);
+            return Ok(Self);
+        }
+
+        if transaction_history.is_empty() {
+            eprintln!(
+                "\nThere are no matching transactions in the local history ({:?}).",
+                previous_context
+                    .config
+                    .credentials_home_dir
+                    .join("transaction-history.jsonl")
+            );
+            return Ok(Self);
+        }
+
+        let mut table = Table::new();
+        table.set_titles(prettytable::row![
+            Fg->"Date (UTC)",
+            Fg->"Network",
+            Fg->"Signer",
+            Fg->"Receiver",
+            Fg->"Actions",
+            Fg->"Outcome",
+            Fg->"Transaction ID"
+        ]);
+        for entry in &transaction_history {
+            table.add_row(prettytable::row![
+                entry.timestamp.format("%Y-%m-%d %H:%M:%S"),
+                entry.network_name,
+                entry.signer_id,
+                entry.receiver_id,
+                entry.actions.join("\n"),
+                match &entry.error {
+                    Some(error) => format!("{}\n{}", entry.outcome, textwrap::fill(error, 40)),
+                    None => entry.outcome.clone(),
+                },
+                entry.transaction_hash
+            ]);
+        }
+        table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+        table.printstd();
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DateBound {
+    Start,
+    End,
+}
+
+/// Parses a date given as `YYYY-MM-DD` (the whole day, in UTC) or as an RFC 3339 timestamp.
+fn parse_date(
+    date: &str,
+    bound: DateBound,
+) -> color_eyre::eyre::Result<chrono::DateTime<chrono::Utc>> {
+    if let Ok(date_time) = chrono::DateTime::parse_from_rfc3339(date) {
+        return Ok(date_time.with_timezone(&chrono::Utc));
+    }
+    let date = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").wrap_err_with(|| {
+        format!("Invalid date <{date}>: expected YYYY-MM-DD or an RFC 3339 timestamp")
+    })?;
+    let time = match bound {
+        DateBound::Start => chrono::NaiveTime::MIN,
+        DateBound::End => chrono::NaiveTime::from_hms_nano_opt(23, 59, 59, 999_999_999)
+            .expect("Internal error: the end of the day must be a valid time"),
+    };
+    Ok(date.and_time(time).and_utc())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_date_covers_the_whole_day() {
+        assert_eq!(
+            parse_date("2024-03-05", DateBound::Start)
+                .unwrap()
+                .to_rfc3339(),
+            "2024-03-05T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_date("2024-03-05", DateBound::End)
+                .unwrap()
+                .to_rfc3339_opts(chrono::SecondsFormat::Secs, true),
+            "2024-03-05T23:59:59Z"
+        );
+        assert_eq!(
+            parse_date("2024-03-05T10:00:00+02:00", DateBound::End)
+                .unwrap()
+                .to_rfc3339(),
+            "2024-03-05T08:00:00+00:00"
+        );
+        assert!(parse_date("last week", DateBound::Start).is_err());
+    }
+
+    #[test]
+    fn transaction_history_skips_malformed_lines() {
+        let credentials_home_dir = tempfile::tempdir().unwrap();
+        let entry = crate::common::TransactionHistoryEntry {
+            timestamp: chrono::Utc::now(),
+            network_name: "testnet".to_string(),
+            signer_id: "alice.testnet".parse().unwrap(),
+            receiver_id: "bob.testnet".parse().unwrap(),
+            actions: vec!["transfer 1 NEAR".to_string()],
+            transaction_hash: near_primitives::hash::CryptoHash::default(),
+            outcome: "success".to_string(),
+            error: None,
+        };
+        crate::common::append_transaction_history_entry(credentials_home_dir.path(), &entry)
+            .unwrap();
+        let transaction_history_path = credentials_home_dir
+            .path()
+            .join("transaction-history.jsonl");
+        let mut content = std::fs::read_to_string(&transaction_history_path).unwrap();
+        content.push_str("{\"truncated\n");
+        std::fs::write(&transaction_history_path, content).unwrap();
+        crate::common::append_transaction_history_entry(credentials_home_dir.path(), &entry)
+            .unwrap();
+
+        let transaction_history =
+            crate::common::get_transaction_history(credentials_home_dir.path()).unwrap();
+        assert_eq!(transaction_history.len(), 2);
+        assert_eq!(transaction_history[1].receiver_id, entry.receiver_id);
+    }
+
+    #[test]
+    fn transaction_history_read_errors_are_reported() {
+        let credentials_home_dir = tempfile::tempdir().unwrap();
+        assert!(
+            crate::common::get_transaction_history(credentials_home_dir.path())
+                .unwrap()
+                .is_empty()
+        );
+
+        std::fs::write(
+            credentials_home_dir
+                .path()
+                .join("transaction-history.jsonl"),
+            [0xff, 0xfe, b'\n'],
+        )
+        .unwrap();
+        let err = crate::common::get_transaction_history(credentials_home_dir.path()).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Failed to read the transaction history file"),
+            "{err:#}"
+        );
+    }
+}
@@ -3,6 +3,7 @@ use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 pub mod construct_meta_transaction;
 pub mod construct_transaction;
+mod history;
 mod print_transaction;
 mod reconstruct_transaction;
 pub mod send_meta_transaction;
@@ -26,6 +27,11 @@ pub enum TransactionActions {
     #[strum_discriminants(strum(message = "view-status              - View a transaction status"))]
     /// Execute function (contract method)
     ViewStatus(self::view_status::TransactionInfo),
+    #[strum_discriminants(strum(
+        message = "history                  - View the transactions sent from this computer"
+    ))]
+    /// View the local history of the transactions sent from this computer
+    History(self::history::TransactionHistory),
     #[strum_discriminants(strum(
         message = "reconstruct-transaction  - Use any existing transaction from the chain to construct NEAR CLI command (helpful tool for re-submitting similar transactions)"
     ))]
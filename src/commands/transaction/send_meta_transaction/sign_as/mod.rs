@@ -79,7 +79,7 @@ impl RelayerAccountId {
         loop {
             let relayer_account_id = if let Some(account_id) =
                 crate::common::input_signer_account_id_from_used_account_list(
-                    &context.global_context.config.credentials_home_dir,
+                    &context.global_context.config,
                     "What is the relayer account ID?",
                 )? {
                 account_id
@@ -77,6 +77,7 @@ impl SubmitContext {
                 &previous_context.network_config,
                 &previous_context.signed_transaction,
                 near_primitives::views::TxExecutionStatus::Final,
+                &previous_context.global_context.config.credentials_home_dir,
             )?;
 
         if let Some(transaction_info) = transaction_info {
@@ -84,6 +85,8 @@ impl SubmitContext {
                 &transaction_info,
                 &previous_context.network_config,
                 previous_context.global_context.verbosity,
+                previous_context.global_context.output_format,
+                previous_context.global_context.decode_with_abi,
             )?;
         }
         Ok(())
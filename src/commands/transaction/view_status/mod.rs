@@ -29,7 +29,12 @@ impl TransactionInfoContext {
                 move |network_config| {
                     let query_view_transaction_status =
                         get_transaction_info(network_config, tx_hash)?;
-                    if let crate::Verbosity::Interactive | crate::Verbosity::TeachMe =
+                    if let crate::common::OutputFormat::Json = previous_context.output_format {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&query_view_transaction_status)?
+                        );
+                    } else if let crate::Verbosity::Interactive | crate::Verbosity::TeachMe =
                         previous_context.verbosity
                     {
                         eprintln!("Transaction status:");
@@ -38,6 +38,9 @@ pub fn get_near_exec_path() -> String {
 #[derive(
     Debug,
     Clone,
+    Copy,
+    PartialEq,
+    Eq,
     strum_macros::IntoStaticStr,
     strum_macros::EnumString,
     strum_macros::EnumVariantNames,
@@ -50,6 +53,10 @@ pub enum OutputFormat {
     Json,
 }
 
+impl interactive_clap::ToCli for OutputFormat {
+    type CliVariant = OutputFormat;
+}
+
 impl std::fmt::Display for OutputFormat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -163,6 +170,18 @@ impl AccountTransferAllowance {
             .saturating_sub(self.liquid_storage_stake())
             .saturating_sub(self.pessimistic_transaction_fee)
     }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "account_id": self.account_id,
+            "transfer_allowance": self.transfer_allowance(),
+            "liquid_balance": self.account_liquid_balance,
+            "locked_balance": self.account_locked_balance,
+            "storage_stake": self.storage_stake,
+            "liquid_storage_stake": self.liquid_storage_stake(),
+            "pessimistic_transaction_fee": self.pessimistic_transaction_fee,
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -980,6 +999,7 @@ pub fn generate_keypair() -> color_eyre::eyre::Result<KeyPairProperties> {
         "ed25519:{}",
         bs58::encode(signing_key.to_keypair_bytes()).into_string()
     );
+    crate::commands::batch::record_step_output("public_key", &public_key_str);
     let key_pair_properties: KeyPairProperties = KeyPairProperties {
         seed_phrase_hd_path: generate_keypair.seed_phrase_hd_path,
         master_seed_phrase,
@@ -1081,6 +1101,7 @@ impl GeneratedKeyPair {
             SignatureScheme::MlDsa65 => {
                 let private_key =
                     near_crypto::SecretKey::from_random(near_crypto::KeyType::MLDSA65);
+                crate::commands::batch::record_step_output("public_key", private_key.public_key());
                 Ok(Self::MlDsa65 {
                     public_key: private_key.public_key().to_string(),
                     private_key: private_key.to_string(),
@@ -2147,10 +2168,107 @@ fn calculate_usd_amount(tokens: u128, price: f64) -> Option<rust_decimal::Decima
     Some(tokens_decimal * price_decimal)
 }
 
+/// Waits for the transaction execution to complete and prints its result in the given output format
+/// (see `docs/JSON_OUTPUT.md` for JSON); returns an error if the transaction failed
 pub fn print_transaction_status(
     transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
     network_config: &crate::config::NetworkConfig,
     verbosity: crate::Verbosity,
+    output_format: OutputFormat,
+    decode_with_abi: bool,
+) -> crate::CliResult {
+    let transaction_info = wait_for_transaction_execution(transaction_info, network_config)?;
+    match output_format {
+        OutputFormat::Json => {
+            print_transaction_status_as_json(&transaction_info, network_config, decode_with_abi)
+        }
+        OutputFormat::Plaintext => print_transaction_status_as_text(
+            &transaction_info,
+            network_config,
+            verbosity,
+            decode_with_abi,
+        ),
+    }
+}
+
+/// Polls the transaction status while its execution has not completed (a few times at most)
+/// and returns the latest known outcome
+fn wait_for_transaction_execution(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    network_config: &crate::config::NetworkConfig,
+) -> color_eyre::eyre::Result<near_primitives::views::FinalExecutionOutcomeView> {
+    let mut transaction_info = transaction_info.clone();
+
+    let retries_number = 5;
+    let mut retries = (1..=retries_number).rev();
+    loop {
+        let message = match &transaction_info.status {
+            near_primitives::views::FinalExecutionStatus::NotStarted => {
+                "The execution has not yet started."
+            }
+            near_primitives::views::FinalExecutionStatus::Started => {
+                "The execution has started and still going."
+            }
+            _ => break,
+        };
+        let Some(retries_left) = retries.next() else {
+            break;
+        };
+        crate::transaction_signature_options::send::sleep_before_retry(format!(
+            "{} ({} Will retry {} more times)",
+            network_config.rpc_url,
+            message.red(),
+            retries_left
+        ));
+        if let Some(final_execution_outcome) =
+            crate::commands::transaction::view_status::get_transaction_info(
+                network_config,
+                transaction_info.transaction_outcome.id,
+            )?
+            .final_execution_outcome
+        {
+            transaction_info = final_execution_outcome.into_outcome();
+        }
+    }
+    Ok(transaction_info)
+}
+
+/// Decodes the return value of the last function call of the transaction with the contract ABI.
+/// The transaction has already been executed, so a return value that does not match the
+/// contract ABI is reported, but does not fail the command.
+fn decode_transaction_return_value(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    network_config: &crate::config::NetworkConfig,
+    return_value: &[u8],
+) -> Option<crate::commands::contract::abi_result::DecodedReturnValue> {
+    let Some(near_primitives::views::ActionView::FunctionCall { method_name, .. }) =
+        transaction_info.transaction.actions.last()
+    else {
+        return None;
+    };
+    crate::commands::contract::abi_result::decode_function_return_value(
+        network_config,
+        &transaction_info.transaction.receiver_id,
+        method_name,
+        &near_primitives::types::Finality::Final.into(),
+        return_value,
+    )
+    .unwrap_or_else(|err| {
+        tracing::error!(
+            parent: &tracing::Span::none(),
+            "{}{}",
+            "The return value does not match the contract ABI".red(),
+            indent_payload(&format!("\n{err:#}\n "))
+        );
+        None
+    })
+}
+
+fn print_transaction_status_as_text(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    network_config: &crate::config::NetworkConfig,
+    verbosity: crate::Verbosity,
+    decode_with_abi: bool,
 ) -> crate::CliResult {
     let near_usd_exchange_rate: Option<Result<f64, color_eyre::eyre::Error>> = network_config
         .coingecko_url
@@ -2161,86 +2279,69 @@ pub fn print_transaction_status(
     #[allow(unused_assignments)]
     let mut return_value = String::new();
     let mut returned_value_bytes: Vec<u8> = Vec::new();
-
-    let retries_number = 5;
-    let mut retries = (1..=retries_number).rev();
-    let mut status = transaction_info.status.clone();
-    let result = loop {
-        match &status {
-            near_primitives::views::FinalExecutionStatus::NotStarted
-            | near_primitives::views::FinalExecutionStatus::Started => {
-                let message =
-                    if let near_primitives::views::FinalExecutionStatus::NotStarted = &status {
-                        "The execution has not yet started."
-                    } else {
-                        "The execution has started and still going."
-                    };
-
-                if let Some(retries_left) = retries.next() {
-                    crate::transaction_signature_options::send::sleep_before_retry(format!(
-                        "{} ({} Will retry {} more times)",
-                        network_config.rpc_url,
-                        message.red(),
-                        retries_left
-                    ));
-                } else if let crate::Verbosity::Quiet = verbosity {
-                    return Ok(());
-                } else {
-                    tracing::warn!(
-                        parent: &tracing::Span::none(),
-                        "{}{}",
-                        message.red(),
-                        indent_payload(&format!(
-                            "\nPlease, check the transaction status later using the transaction ID: {}",
-                            transaction_info.transaction_outcome.id
-                        )).yellow()
-                    );
-                    return Ok(());
+    let mut returned_value_type: Option<String> = None;
+
+    let result = match &transaction_info.status {
+        near_primitives::views::FinalExecutionStatus::NotStarted
+        | near_primitives::views::FinalExecutionStatus::Started => {
+            let message = if let near_primitives::views::FinalExecutionStatus::NotStarted =
+                &transaction_info.status
+            {
+                "The execution has not yet started."
+            } else {
+                "The execution has started and still going."
+            };
+            if let crate::Verbosity::Quiet = verbosity {
+                return Ok(());
+            }
+            tracing::warn!(
+                parent: &tracing::Span::none(),
+                "{}{}",
+                message.red(),
+                indent_payload(&format!(
+                    "\nPlease, check the transaction status later using the transaction ID: {}",
+                    transaction_info.transaction_outcome.id
+                )).yellow()
+            );
+            return Ok(());
+        }
+        near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
+            return match tx_execution_error {
+                near_primitives::errors::TxExecutionError::ActionError(action_error) => {
+                    convert_action_error_to_cli_result(action_error)
                 }
-
-                let rpc_transaction_response =
-                    crate::commands::transaction::view_status::get_transaction_info(
-                        network_config,
-                        transaction_info.transaction_outcome.id,
-                    )?;
-
-                if let Some(final_execution_outcome) =
-                    &rpc_transaction_response.final_execution_outcome
-                {
-                    status = final_execution_outcome.clone().into_outcome().status;
+                near_primitives::errors::TxExecutionError::InvalidTxError(invalid_tx_error) => {
+                    convert_invalid_tx_error_to_cli_result(invalid_tx_error)
                 }
+            };
+        }
+        near_primitives::views::FinalExecutionStatus::SuccessValue(bytes_result) => {
+            if let crate::Verbosity::Quiet = verbosity {
+                std::io::stdout().write_all(bytes_result)?;
+                return Ok(());
+            };
+            returned_value_bytes.append(&mut bytes_result.clone());
+            return_value = if bytes_result.is_empty() {
+                "Empty return value".to_string()
+            } else if let Ok(json_result) =
+                serde_json::from_slice::<serde_json::Value>(bytes_result)
+            {
+                serde_json::to_string_pretty(&json_result)?
+            } else if let Ok(string_result) = String::from_utf8(bytes_result.clone()) {
+                string_result
+            } else {
+                "The returned value is not printable (binary data)".to_string()
+            };
+            if decode_with_abi
+                && let Some(decoded_return_value) =
+                    decode_transaction_return_value(transaction_info, network_config, bytes_result)
+            {
+                return_value = serde_json::to_string_pretty(&decoded_return_value.value)?;
+                returned_value_type = Some(decoded_return_value.type_name);
             }
-            near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
-                return match tx_execution_error {
-                    near_primitives::errors::TxExecutionError::ActionError(action_error) => {
-                        convert_action_error_to_cli_result(action_error)
-                    }
-                    near_primitives::errors::TxExecutionError::InvalidTxError(invalid_tx_error) => {
-                        convert_invalid_tx_error_to_cli_result(invalid_tx_error)
-                    }
-                };
-            }
-            near_primitives::views::FinalExecutionStatus::SuccessValue(bytes_result) => {
-                if let crate::Verbosity::Quiet = verbosity {
-                    std::io::stdout().write_all(bytes_result)?;
-                    return Ok(());
-                };
-                returned_value_bytes.append(&mut bytes_result.clone());
-                return_value = if bytes_result.is_empty() {
-                    "Empty return value".to_string()
-                } else if let Ok(json_result) =
-                    serde_json::from_slice::<serde_json::Value>(bytes_result)
-                {
-                    serde_json::to_string_pretty(&json_result)?
-                } else if let Ok(string_result) = String::from_utf8(bytes_result.clone()) {
-                    string_result
-                } else {
-                    "The returned value is not printable (binary data)".to_string()
-                };
-                success_data.push_str(&return_value);
-                break Ok(());
-            }
-        };
+            success_data.push_str(&return_value);
+            Ok(())
+        }
     };
 
     let mut transaction_execution_info = String::new();
@@ -2317,8 +2418,11 @@ pub fn print_transaction_status(
                     crate::common::indent_payload("Empty return value\n ")
                 );
             } else {
-                suspend_tracing_indicatif(|| {
-                    eprintln!("\nFunction execution return value (printed to stdout):")
+                suspend_tracing_indicatif(|| match &returned_value_type {
+                    Some(type_name) => eprintln!(
+                        "\nFunction execution return value of type `{type_name}` (printed to stdout):"
+                    ),
+                    None => eprintln!("\nFunction execution return value (printed to stdout):"),
                 });
                 suspend_tracing_indicatif(|| println!("{return_value}"));
             }
@@ -2337,6 +2441,137 @@ pub fn print_transaction_status(
     result
 }
 
+/// Decodes a function call return value for JSON output: JSON data is embedded as is,
+/// UTF-8 text becomes a string, empty or binary data becomes `null`
+pub fn return_value_to_json(bytes: &[u8]) -> serde_json::Value {
+    if bytes.is_empty() {
+        serde_json::Value::Null
+    } else if let Ok(json_result) = serde_json::from_slice::<serde_json::Value>(bytes) {
+        json_result
+    } else if let Ok(string_result) = std::str::from_utf8(bytes) {
+        serde_json::Value::String(string_result.to_string())
+    } else {
+        serde_json::Value::Null
+    }
+}
+
+fn execution_status_to_json(
+    status: &near_primitives::views::ExecutionStatusView,
+) -> serde_json::Value {
+    match status {
+        near_primitives::views::ExecutionStatusView::Unknown => serde_json::json!({
+            "type": "unknown",
+        }),
+        near_primitives::views::ExecutionStatusView::Failure(tx_execution_error) => {
+            serde_json::json!({
+                "type": "failure",
+                "failure": tx_execution_error,
+            })
+        }
+        near_primitives::views::ExecutionStatusView::SuccessValue(bytes) => serde_json::json!({
+            "type": "success_value",
+            "return_value": return_value_to_json(bytes),
+            "return_value_base64": near_primitives::serialize::to_base64(bytes),
+        }),
+        near_primitives::views::ExecutionStatusView::SuccessReceiptId(receipt_id) => {
+            serde_json::json!({
+                "type": "success_receipt_id",
+                "receipt_id": receipt_id,
+            })
+        }
+    }
+}
+
+pub fn transaction_status_to_json(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    network_config: &crate::config::NetworkConfig,
+    decode_with_abi: bool,
+) -> color_eyre::eyre::Result<serde_json::Value> {
+    let (status, failure, return_value_bytes) = match &transaction_info.status {
+        near_primitives::views::FinalExecutionStatus::NotStarted => ("not_started", None, None),
+        near_primitives::views::FinalExecutionStatus::Started => ("started", None, None),
+        near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
+            ("failure", Some(tx_execution_error), None)
+        }
+        near_primitives::views::FinalExecutionStatus::SuccessValue(bytes) => {
+            ("success", None, Some(bytes))
+        }
+    };
+
+    let decoded_return_value = return_value_bytes
+        .filter(|_| decode_with_abi)
+        .and_then(|bytes| decode_transaction_return_value(transaction_info, network_config, bytes));
+
+    let mut total_gas_burnt = transaction_info.transaction_outcome.outcome.gas_burnt;
+    let mut total_tokens_burnt = transaction_info.transaction_outcome.outcome.tokens_burnt;
+    let mut receipts = Vec::with_capacity(transaction_info.receipts_outcome.len());
+    for receipt in &transaction_info.receipts_outcome {
+        total_gas_burnt = total_gas_burnt
+            .checked_add(receipt.outcome.gas_burnt)
+            .context("overflow while adding transaction status total gas")?;
+        total_tokens_burnt = total_tokens_burnt
+            .checked_add(receipt.outcome.tokens_burnt)
+            .context("overflow while adding transaction status total tokens burnt")?;
+        receipts.push(serde_json::json!({
+            "receipt_id": receipt.id,
+            "executor_id": receipt.outcome.executor_id,
+            "status": execution_status_to_json(&receipt.outcome.status),
+            "gas_burnt": receipt.outcome.gas_burnt,
+            "tokens_burnt": receipt.outcome.tokens_burnt,
+            "logs": receipt.outcome.logs,
+        }));
+    }
+
+    Ok(serde_json::json!({
+        "transaction_hash": transaction_info.transaction_outcome.id,
+        "signer_id": transaction_info.transaction.signer_id,
+        "receiver_id": transaction_info.transaction.receiver_id,
+        "status": status,
+        "failure": failure,
+        "return_value": match &decoded_return_value {
+            Some(decoded_return_value) => Some(decoded_return_value.value.clone()),
+            None => return_value_bytes.map(|bytes| return_value_to_json(bytes)),
+        },
+        "return_value_abi_type": decoded_return_value.as_ref().map(|decoded_return_value| &decoded_return_value.type_name),
+        "return_value_base64": return_value_bytes.map(|bytes| near_primitives::serialize::to_base64(bytes)),
+        "gas_burnt": total_gas_burnt,
+        "tokens_burnt": total_tokens_burnt,
+        "transaction_outcome": {
+            "gas_burnt": transaction_info.transaction_outcome.outcome.gas_burnt,
+            "tokens_burnt": transaction_info.transaction_outcome.outcome.tokens_burnt,
+            "logs": transaction_info.transaction_outcome.outcome.logs,
+        },
+        "receipts": receipts,
+        "explorer_url": format!(
+            "{}{}",
+            network_config.explorer_transaction_url, transaction_info.transaction_outcome.id
+        ),
+    }))
+}
+
+/// Prints the transaction execution result to stdout as a single JSON document
+fn print_transaction_status_as_json(
+    transaction_info: &near_primitives::views::FinalExecutionOutcomeView,
+    network_config: &crate::config::NetworkConfig,
+    decode_with_abi: bool,
+) -> crate::CliResult {
+    let transaction_status =
+        transaction_status_to_json(transaction_info, network_config, decode_with_abi)?;
+    suspend_tracing_indicatif(|| {
+        serde_json::to_string_pretty(&transaction_status).map(|json| println!("{json}"))
+    })?;
+
+    match &transaction_info.status {
+        near_primitives::views::FinalExecutionStatus::Failure(
+            near_primitives::errors::TxExecutionError::ActionError(action_error),
+        ) => convert_action_error_to_cli_result(action_error),
+        near_primitives::views::FinalExecutionStatus::Failure(
+            near_primitives::errors::TxExecutionError::InvalidTxError(invalid_tx_error),
+        ) => convert_invalid_tx_error_to_cli_result(invalid_tx_error),
+        _ => Ok(()),
+    }
+}
+
 pub fn save_access_key_to_keychain_or_save_to_legacy_keychain(
     network_config: crate::config::NetworkConfig,
     credentials_home_dir: std::path::PathBuf,
@@ -2346,6 +2581,7 @@ pub fn save_access_key_to_keychain_or_save_to_legacy_keychain(
 ) -> color_eyre::eyre::Result<String> {
     match save_access_key_to_keychain(
         network_config.clone(),
+        &credentials_home_dir,
         key_pair_properties_buf,
         public_key_str,
         account_id,
@@ -2376,6 +2612,7 @@ pub fn save_access_key_to_keychain_or_save_to_legacy_keychain(
 
 pub fn save_access_key_to_keychain(
     network_config: crate::config::NetworkConfig,
+    credentials_home_dir: &std::path::Path,
     key_pair_properties_buf: &str,
     public_key_str: &str,
     account_id: &str,
@@ -2390,6 +2627,20 @@ pub fn save_access_key_to_keychain(
         .set_password(key_pair_properties_buf)
         .wrap_err("Failed to save password to keychain. You may need to install the secure keychain package by following this instruction: https://github.com/jaraco/keyring#using-keyring-on-headless-linux-systems")?;
 
+    if let Err(err) = add_to_keychain_key_list(
+        credentials_home_dir,
+        KeychainKey {
+            network_name: network_config.network_name.clone(),
+            account_id: account_id.parse()?,
+            public_key: public_key_str.to_string(),
+        },
+    ) {
+        tracing::warn!(
+            parent: &tracing::Span::none(),
+            "The access key <{public_key_str}> is saved in the keychain, but it could not be recorded in the list of the keychain keys: {err:#}"
+        );
+    }
+
     Ok("The data for the access key is saved in the keychain".to_string())
 }
 
@@ -2447,6 +2698,101 @@ pub fn save_access_key_to_legacy_keychain(
     }
 }
 
+pub fn get_encrypted_keystore_account_dir(
+    credentials_home_dir: &std::path::Path,
+    network_name: &str,
+    account_id: &str,
+) -> std::path::PathBuf {
+    credentials_home_dir
+        .join("encrypted-keystore")
+        .join(network_name)
+        .join(account_id)
+}
+
+/// Takes the passphrase from `NEAR_KEYSTORE_PASSPHRASE` or asks for it
+/// (twice when `confirm` is set, i.e. when a new key is encrypted).
+pub fn input_encrypted_keystore_passphrase(confirm: bool) -> color_eyre::eyre::Result<String> {
+    if let Ok(passphrase) = std::env::var(crate::types::encrypted_keystore::PASSPHRASE_ENV_VAR)
+        && !passphrase.is_empty()
+    {
+        return Ok(passphrase);
+    }
+    let mut passphrase_prompt = inquire::Password::new("Enter the encrypted keystore passphrase:")
+        .with_display_mode(inquire::PasswordDisplayMode::Masked)
+        .with_validator(inquire::validator::MinLengthValidator::new(1));
+    if confirm {
+        passphrase_prompt =
+            passphrase_prompt.with_custom_confirmation_message("Confirm the passphrase:");
+    } else {
+        passphrase_prompt = passphrase_prompt.without_confirmation();
+    }
+    Ok(suspend_tracing_indicatif(|| passphrase_prompt.prompt())?)
+}
+
+pub fn save_access_key_to_encrypted_keystore(
+    network_config: crate::config::NetworkConfig,
+    credentials_home_dir: std::path::PathBuf,
+    key_pair_properties_buf: &str,
+    public_key_str: &str,
+    account_id: &str,
+) -> color_eyre::eyre::Result<String> {
+    let account_dir = get_encrypted_keystore_account_dir(
+        &credentials_home_dir,
+        &network_config.network_name,
+        account_id,
+    );
+    std::fs::create_dir_all(&account_dir)?;
+    let path_with_key_name = account_dir.join(format!("{}.json", public_key_str.replace(':', "_")));
+    if path_with_key_name.exists() {
+        return Ok(format!(
+            "The file: {} already exists! Therefore it was not overwritten.",
+            path_with_key_name.display()
+        ));
+    }
+
+    let passphrase = input_encrypted_keystore_passphrase(true)?;
+    let encrypted_keystore_file = crate::types::encrypted_keystore::EncryptedKeystoreFile::encrypt(
+        &account_id.parse()?,
+        public_key_str,
+        key_pair_properties_buf,
+        &passphrase,
+    )?;
+    std::fs::File::create(&path_with_key_name)
+        .wrap_err_with(|| format!("Failed to create file: {path_with_key_name:?}"))?
+        .write(serde_json::to_string_pretty(&encrypted_keystore_file)?.as_bytes())
+        .wrap_err_with(|| format!("Failed to write to file: {path_with_key_name:?}"))?;
+    Ok(format!(
+        "The data for the access key is saved encrypted in a file {}",
+        path_with_key_name.display()
+    ))
+}
+
+/// Lists the encrypted access key files of the account (without decrypting them).
+pub fn get_access_keys_from_encrypted_keystore(
+    credentials_home_dir: &std::path::Path,
+    network_name: &str,
+    account_id: &near_primitives::types::AccountId,
+) -> Vec<(
+    std::path::PathBuf,
+    crate::types::encrypted_keystore::EncryptedKeystoreFile,
+)> {
+    let Ok(account_dir) =
+        get_encrypted_keystore_account_dir(credentials_home_dir, network_name, account_id.as_str())
+            .read_dir()
+    else {
+        return Vec::new();
+    };
+    account_dir
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter_map(|path| {
+            let encrypted_keystore_file =
+                serde_json::from_slice(&std::fs::read(&path).ok()?).ok()?;
+            Some((path, encrypted_keystore_file))
+        })
+        .collect()
+}
+
 pub fn try_external_subcommand_execution(error: clap::Error) -> CliResult {
     let (subcommand, args) = {
         let mut args = std::env::args().skip(1);
@@ -2690,6 +3036,25 @@ pub fn fetch_historically_delegated_staking_pools(
         .collect())
 }
 
+#[derive(Debug, serde::Deserialize)]
+struct PublicKeyAccountsResponse {
+    account_ids: Vec<near_primitives::types::AccountId>,
+}
+
+/// Accounts with the public key as a full access or function-call access key, as indexed by FastNEAR.
+#[tracing::instrument(name = "Getting the accounts of the public key ...", skip_all)]
+pub fn fetch_account_ids_by_public_key(
+    fastnear_url: &url::Url,
+    public_key: &near_crypto::PublicKey,
+) -> color_eyre::Result<std::collections::BTreeSet<near_primitives::types::AccountId>> {
+    tracing::info!(target: "near_teach_me", "Getting the accounts of the public key <{public_key}> ...");
+    let request =
+        reqwest::blocking::get(fastnear_url.join(&format!("v0/public_key/{public_key}/all"))?)?;
+    let response: PublicKeyAccountsResponse = request.error_for_status()?.json()?;
+
+    Ok(response.account_ids.into_iter().collect())
+}
+
 #[tracing::instrument(name = "Getting currently active staking pools ...", skip_all)]
 pub fn fetch_currently_active_staking_pools(
     json_rpc_client: &near_jsonrpc_client::JsonRpcClient,
@@ -2836,6 +3201,37 @@ async fn get_staking_pool_info(
     })
 }
 
+/// The contract of the account for the `--output json` documents (`contract.type` and the base58 `code_hash`).
+pub fn account_contract_json(
+    account_view: &near_primitives::views::AccountView,
+) -> serde_json::Value {
+    match (
+        &account_view.code_hash,
+        &account_view.global_contract_account_id,
+        &account_view.global_contract_hash,
+    ) {
+        (_, Some(global_contract_account_id), None) => serde_json::json!({
+            "type": "global_by_account_id",
+            "account_id": global_contract_account_id,
+        }),
+        (_, None, Some(global_contract_hash)) => serde_json::json!({
+            "type": "global_by_hash",
+            "code_hash": global_contract_hash,
+        }),
+        (hash, None, None) if *hash == CryptoHash::default() => serde_json::json!({
+            "type": "none",
+        }),
+        (code_hash, None, None) => serde_json::json!({
+            "type": "local",
+            "code_hash": code_hash,
+        }),
+        (_, Some(_), Some(_)) => serde_json::json!({
+            "type": "invalid",
+        }),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn display_account_info(
     viewed_at_block_hash: &CryptoHash,
     viewed_at_block_height: &near_primitives::types::BlockHeight,
@@ -2846,7 +3242,32 @@ pub fn display_account_info(
     account_view: &near_primitives::views::AccountView,
     access_key_list: Option<&near_primitives::views::AccessKeyList>,
     optional_account_profile: Option<&near_socialdb_client::types::socialdb_types::AccountProfile>,
-) {
+    output_format: OutputFormat,
+) -> crate::CliResult {
+    if let OutputFormat::Json = output_format {
+        let (delegated_stake, delegated_stake_error) = match delegated_stake {
+            Ok(delegated_stake) => (Some(delegated_stake), None),
+            Err(err) => (None, Some(format!("{err:#}"))),
+        };
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "account_id": account_id,
+                "block_height": viewed_at_block_height,
+                "block_hash": viewed_at_block_hash,
+                "balance": account_view.amount,
+                "locked": account_view.locked,
+                "storage_usage": account_view.storage_usage,
+                "delegated_stake": delegated_stake,
+                "delegated_stake_error": delegated_stake_error,
+                "contract": account_contract_json(account_view),
+                "access_keys": access_key_list.map(|access_key_list| &access_key_list.keys),
+                "profile": optional_account_profile.map(|account_profile| &account_profile.profile),
+            }))?
+        );
+        return Ok(());
+    }
+
     eprintln!();
     let mut table: Table = Table::new();
     table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
@@ -2958,6 +3379,7 @@ pub fn display_account_info(
         Fy->access_keys_summary
     ]);
     table.printstd();
+    Ok(())
 }
 
 pub fn display_account_profile(
@@ -3079,59 +3501,78 @@ fn profile_table(
     }
 }
 
-pub fn display_access_key_list(access_keys: &[near_primitives::views::AccessKeyInfoView]) {
-    let mut table = Table::new();
-    table.set_titles(prettytable::row![Fg=>"#", "Public Key", "Nonce", "Permissions"]);
-
-    for (index, access_key) in access_keys.iter().enumerate() {
-        let permissions_message = match &access_key.access_key.permission {
-            AccessKeyPermissionView::FullAccess => "full access".to_owned(),
-            AccessKeyPermissionView::FunctionCall {
-                allowance,
-                receiver_id,
-                method_names,
-            } => {
-                let allowance_message = match allowance {
-                    Some(allowance) => {
-                        format!("with an allowance of {}", allowance.exact_amount_display())
-                    }
-                    None => "with no limit".to_string(),
-                };
-                if method_names.is_empty() {
-                    format!("do any function calls on {receiver_id} {allowance_message}")
-                } else {
-                    format!(
-                        "only do {method_names:?} function calls on {receiver_id} {allowance_message}"
-                    )
+/// A human-readable description of what an access key with the permission can do.
+pub fn access_key_permission_description(permission: &AccessKeyPermissionView) -> String {
+    match permission {
+        AccessKeyPermissionView::FullAccess => "full access".to_owned(),
+        AccessKeyPermissionView::FunctionCall {
+            allowance,
+            receiver_id,
+            method_names,
+        } => {
+            let allowance_message = match allowance {
+                Some(allowance) => {
+                    format!("with an allowance of {}", allowance.exact_amount_display())
                 }
-            }
-            AccessKeyPermissionView::GasKeyFunctionCall {
-                balance,
-                num_nonces,
-                allowance: _,
-                receiver_id,
-                method_names,
-            } => {
-                let methods = if method_names.is_empty() {
-                    "any methods".to_string()
-                } else {
-                    format!("{method_names:?}")
-                };
-                format!(
-                    "gas key for function calls on {receiver_id} ({methods}), balance: {}, nonces: {num_nonces}",
-                    balance.exact_amount_display()
-                )
-            }
-            AccessKeyPermissionView::GasKeyFullAccess {
-                balance,
-                num_nonces,
-            } => {
+                None => "with no limit".to_string(),
+            };
+            if method_names.is_empty() {
+                format!("do any function calls on {receiver_id} {allowance_message}")
+            } else {
                 format!(
-                    "gas key with full access, balance: {}, nonces: {num_nonces}",
-                    balance.exact_amount_display()
+                    "only do {method_names:?} function calls on {receiver_id} {allowance_message}"
                 )
             }
-        };
+        }
+        AccessKeyPermissionView::GasKeyFunctionCall {
+            balance,
+            num_nonces,
+            allowance: _,
+            receiver_id,
+            method_names,
+        } => {
+            let methods = if method_names.is_empty() {
+                "any methods".to_string()
+            } else {
+                format!("{method_names:?}")
+            };
+            format!(
+                "gas key for function calls on {receiver_id} ({methods}), balance: {}, nonces: {num_nonces}",
+                balance.exact_amount_display()
+            )
+        }
+        AccessKeyPermissionView::GasKeyFullAccess {
+            balance,
+            num_nonces,
+        } => {
+            format!(
+                "gas key with full access, balance: {}, nonces: {num_nonces}",
+                balance.exact_amount_display()
+            )
+        }
+    }
+}
+
+pub fn display_access_key_list(
+    access_keys: &[near_primitives::views::AccessKeyInfoView],
+    output_format: OutputFormat,
+) -> crate::CliResult {
+    if let OutputFormat::Json = output_format {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "keys": access_keys,
+            }))?
+        );
+        return Ok(());
+    }
+
+    let mut table = Table::new();
+    table.set_titles(prettytable::row![Fg=>"#", "Public Key", "Nonce", "Permissions"]);
+
+    for (index, access_key) in access_keys.iter().enumerate() {
+        let permissions_message =
+            access_key_permission_description(&access_key.access_key.permission);
 
         table.add_row(prettytable::row![
             Fg->index + 1,
@@ -3143,12 +3584,25 @@ pub fn display_access_key_list(access_keys: &[near_primitives::views::AccessKeyI
 
     table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
     table.printstd();
+    Ok(())
 }
 
 pub fn display_gas_key_nonces(
     public_key: &near_crypto::PublicKey,
     nonces: &[near_primitives::types::Nonce],
-) {
+    output_format: OutputFormat,
+) -> crate::CliResult {
+    if let OutputFormat::Json = output_format {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "public_key": public_key,
+                "nonces": nonces,
+            }))?
+        );
+        return Ok(());
+    }
+
     eprintln!("Gas key nonces for public key {public_key}:");
     let mut table = Table::new();
     table.set_titles(prettytable::row![Fg=>"Nonce index", "Nonce"]);
@@ -3159,6 +3613,7 @@ pub fn display_gas_key_nonces(
 
     table.set_format(*prettytable::format::consts::FORMAT_NO_LINESEP_WITH_TITLE);
     table.printstd();
+    Ok(())
 }
 
 /// Interactive prompt for network name.
@@ -3211,6 +3666,41 @@ pub fn input_network_name(
     }
 }
 
+/// Returns the reason to retry the request against another RPC endpoint of the same network
+/// connection: connectivity issues, rate limiting, overloaded or not synced nodes.
+fn rpc_failover_reason<E: serde::Serialize>(
+    err: &near_jsonrpc_client::errors::JsonRpcError<E>,
+) -> Option<String> {
+    match err {
+        near_jsonrpc_client::errors::JsonRpcError::TransportError(transport_error) => {
+            Some(format!("transport error: {transport_error}"))
+        }
+        near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::ResponseStatusError(status_error),
+        ) => match status_error {
+            near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::TooManyRequests
+            | near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::TimeoutError
+            | near_jsonrpc_client::errors::JsonRpcServerResponseStatusError::ServiceUnavailable => {
+                Some(status_error.to_string())
+            }
+            _ => None,
+        },
+        near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(handler_error),
+        ) => serde_json::to_value(handler_error)
+            .ok()
+            .and_then(|handler_error| {
+                handler_error
+                    .get("name")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_owned)
+            })
+            .filter(|name| name == "NO_SYNCED_BLOCKS" || name == "NOT_SYNCED_YET")
+            .map(|_| "the node has not synced the latest blocks".to_string()),
+        _ => None,
+    }
+}
+
 pub trait JsonRpcClientExt {
     fn blocking_call<M>(&self, method: M) -> BoxedJsonRpcResult<M::Response, M::Error>
     where
@@ -3312,9 +3802,29 @@ impl JsonRpcClientExt for near_jsonrpc_client::JsonRpcClient {
             }
         }
 
-        tokio::runtime::Runtime::new()
-            .unwrap()
-            .block_on(self.call(method))
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let mut result = runtime.block_on(self.call(&method));
+        let mut server_addr = self.server_addr().to_string();
+        while let Err(err) = &result {
+            let Some(reason) = rpc_failover_reason(err) else {
+                break;
+            };
+            let Some(rpc_endpoint) = crate::config::rpc_failover::fail_over_from(&server_addr)
+                .into_iter()
+                .next()
+            else {
+                break;
+            };
+            tracing::warn!(
+                parent: &tracing::Span::none(),
+                "RPC {server_addr} is unavailable ({reason}), switching to {}",
+                rpc_endpoint.url
+            );
+            server_addr = rpc_endpoint.url.to_string();
+            result = runtime.block_on(rpc_endpoint.json_rpc_client().call(&method));
+        }
+
+        result
             .inspect_err(|err| match err {
                 near_jsonrpc_client::errors::JsonRpcError::TransportError(transport_error) => {
                     tracing::info!(
@@ -3895,12 +4405,224 @@ pub fn is_used_account_list_exist(credentials_home_dir: &std::path::Path) -> boo
     get_used_account_list_path(credentials_home_dir).exists()
 }
 
-pub fn input_signer_account_id_from_used_account_list(
+/// An access key saved in the keychain. Keychain entries cannot be enumerated, so the saved keys
+/// are recorded in a list to find them even after they were deleted on-chain.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct KeychainKey {
+    pub network_name: String,
+    pub account_id: near_primitives::types::AccountId,
+    pub public_key: String,
+}
+
+fn get_keychain_key_list_path(credentials_home_dir: &std::path::Path) -> std::path::PathBuf {
+    credentials_home_dir.join("keychain-keys.json")
+}
+
+pub fn get_keychain_key_list(credentials_home_dir: &std::path::Path) -> Vec<KeychainKey> {
+    let keychain_key_list_path = get_keychain_key_list_path(credentials_home_dir);
+    serde_json::from_str(
+        std::fs::read_to_string(keychain_key_list_path)
+            .as_deref()
+            .unwrap_or("[]"),
+    )
+    .unwrap_or_default()
+}
+
+fn write_keychain_key_list(
+    credentials_home_dir: &std::path::Path,
+    keychain_key_list: &[KeychainKey],
+) -> color_eyre::eyre::Result<()> {
+    let keychain_key_list_path = get_keychain_key_list_path(credentials_home_dir);
+    std::fs::create_dir_all(credentials_home_dir)?;
+    std::fs::write(
+        &keychain_key_list_path,
+        serde_json::to_string(keychain_key_list)?,
+    )
+    .wrap_err_with(|| format!("Failed to write to file: {keychain_key_list_path:?}"))
+}
+
+pub fn add_to_keychain_key_list(
+    credentials_home_dir: &std::path::Path,
+    keychain_key: KeychainKey,
+) -> color_eyre::eyre::Result<()> {
+    let mut keychain_key_list = get_keychain_key_list(credentials_home_dir);
+    if keychain_key_list.contains(&keychain_key) {
+        return Ok(());
+    }
+    keychain_key_list.push(keychain_key);
+    write_keychain_key_list(credentials_home_dir, &keychain_key_list)
+}
+
+pub fn remove_from_keychain_key_list(
+    credentials_home_dir: &std::path::Path,
+    keychain_key: &KeychainKey,
+) -> color_eyre::eyre::Result<()> {
+    let mut keychain_key_list = get_keychain_key_list(credentials_home_dir);
+    let keys_count = keychain_key_list.len();
+    keychain_key_list.retain(|known| known != keychain_key);
+    if keychain_key_list.len() == keys_count {
+        return Ok(());
+    }
+    write_keychain_key_list(credentials_home_dir, &keychain_key_list)
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TransactionHistoryEntry {
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub network_name: String,
+    pub signer_id: near_primitives::types::AccountId,
+    pub receiver_id: near_primitives::types::AccountId,
+    pub actions: Vec<String>,
+    pub transaction_hash: CryptoHash,
+    /// `success`, `failure`, `sent` (the transaction was not waited for) or `error` (it was not accepted by RPC)
+    pub outcome: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+fn get_transaction_history_path(credentials_home_dir: &std::path::Path) -> std::path::PathBuf {
+    credentials_home_dir.join("transaction-history.jsonl")
+}
+
+/// Appends the transaction to the local journal of the sent transactions (one JSON object per line).
+pub fn append_transaction_history_entry(
+    credentials_home_dir: &std::path::Path,
+    entry: &TransactionHistoryEntry,
+) -> color_eyre::eyre::Result<()> {
+    let transaction_history_path = get_transaction_history_path(credentials_home_dir);
+    std::fs::create_dir_all(credentials_home_dir)?;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&transaction_history_path)
+        .wrap_err_with(|| {
+            format!("Failed to open the transaction history file: {transaction_history_path:?}")
+        })?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    Ok(())
+}
+
+/// Reads the local journal of the sent transactions, skipping (with a warning) the lines that cannot be parsed.
+/// A missing journal is an empty history.
+pub fn get_transaction_history(
     credentials_home_dir: &std::path::Path,
+) -> color_eyre::eyre::Result<Vec<TransactionHistoryEntry>> {
+    let transaction_history_path = get_transaction_history_path(credentials_home_dir);
+    let transaction_history = match std::fs::read_to_string(&transaction_history_path) {
+        Ok(transaction_history) => transaction_history,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(err).wrap_err_with(|| {
+                format!("Failed to read the transaction history file: {transaction_history_path:?}")
+            });
+        }
+    };
+    Ok(transaction_history
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| match serde_json::from_str(line) {
+            Ok(entry) => Some(entry),
+            Err(err) => {
+                tracing::warn!(
+                    parent: &tracing::Span::none(),
+                    "Skipping the line {} of the transaction history file {transaction_history_path:?}: {err}",
+                    index + 1
+                );
+                None
+            }
+        })
+        .collect())
+}
+
+/// A one-line description of the action for the transaction history.
+pub fn action_summary(action: &near_primitives::transaction::Action) -> String {
+    match action {
+        near_primitives::transaction::Action::CreateAccount(_) => "create account".to_string(),
+        near_primitives::transaction::Action::DeployContract(code) => {
+            format!("deploy contract <{}>", CryptoHash::hash_bytes(&code.code))
+        }
+        near_primitives::transaction::Action::FunctionCall(function_call_action) => format!(
+            "call <{}> (deposit: {}, gas: {})",
+            function_call_action.method_name,
+            function_call_action.deposit.exact_amount_display(),
+            function_call_action.gas
+        ),
+        near_primitives::transaction::Action::Transfer(transfer_action) => format!(
+            "transfer {}",
+            transfer_action.deposit.exact_amount_display()
+        ),
+        near_primitives::transaction::Action::Stake(stake_action) => format!(
+            "stake {} with <{}>",
+            stake_action.stake.exact_amount_display(),
+            stake_action.public_key
+        ),
+        near_primitives::transaction::Action::AddKey(add_key_action) => format!(
+            "add {} key <{}>",
+            match add_key_action.access_key.permission {
+                near_primitives::account::AccessKeyPermission::FullAccess => "full access",
+                _ => "function call",
+            },
+            add_key_action.public_key
+        ),
+        near_primitives::transaction::Action::DeleteKey(delete_key_action) => {
+            format!("delete key <{}>", delete_key_action.public_key)
+        }
+        near_primitives::transaction::Action::DeleteAccount(delete_account_action) => format!(
+            "delete account (beneficiary: <{}>)",
+            delete_account_action.beneficiary_id
+        ),
+        near_primitives::transaction::Action::Delegate(signed_delegate_action) => format!(
+            "delegate action to <{}> ({} actions)",
+            signed_delegate_action.delegate_action.receiver_id,
+            signed_delegate_action.delegate_action.actions.len()
+        ),
+        near_primitives::transaction::Action::DelegateV2(_) => "delegate action".to_string(),
+        near_primitives::transaction::Action::DeployGlobalContract(deploy) => format!(
+            "deploy global contract <{}>",
+            CryptoHash::hash_bytes(&deploy.code)
+        ),
+        near_primitives::transaction::Action::UseGlobalContract(use_global_contract) => {
+            match &use_global_contract.contract_identifier {
+                GlobalContractIdentifier::CodeHash(hash) => {
+                    format!("use global contract <{hash}>")
+                }
+                GlobalContractIdentifier::AccountId(account_id) => {
+                    format!("use global contract deployed by <{account_id}>")
+                }
+            }
+        }
+        near_primitives::transaction::Action::DeterministicStateInit(state_init) => format!(
+            "deterministic state init (deposit: {})",
+            state_init.deposit.exact_amount_display()
+        ),
+        near_primitives::transaction::Action::TransferToGasKey(transfer_to_gas_key) => format!(
+            "transfer {} to gas key <{}>",
+            transfer_to_gas_key.deposit.exact_amount_display(),
+            transfer_to_gas_key.public_key
+        ),
+        near_primitives::transaction::Action::WithdrawFromGasKey(withdraw_from_gas_key) => {
+            format!(
+                "withdraw {} from gas key <{}>",
+                withdraw_from_gas_key.amount.exact_amount_display(),
+                withdraw_from_gas_key.public_key
+            )
+        }
+    }
+}
+
+/// Suggests `default_signer_account_id` from the config (e.g. pinned in a project-local `near.toml`), if set.
+pub fn input_signer_account_id_from_used_account_list(
+    config: &crate::config::Config,
     message: &str,
 ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
     let account_is_signer = true;
-    input_account_id_from_used_account_list(credentials_home_dir, message, account_is_signer)
+    input_account_id_from_used_account_list(
+        &config.credentials_home_dir,
+        message,
+        account_is_signer,
+        config.default_signer_account_id.as_ref(),
+    )
 }
 
 pub fn input_non_signer_account_id_from_used_account_list(
@@ -3908,20 +4630,26 @@ pub fn input_non_signer_account_id_from_used_account_list(
     message: &str,
 ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
     let account_is_signer = false;
-    input_account_id_from_used_account_list(credentials_home_dir, message, account_is_signer)
+    input_account_id_from_used_account_list(credentials_home_dir, message, account_is_signer, None)
 }
 
 fn input_account_id_from_used_account_list(
     credentials_home_dir: &std::path::Path,
     message: &str,
     account_is_signer: bool,
+    default_account_id: Option<&near_primitives::types::AccountId>,
 ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
     let used_account_list = get_used_account_list(credentials_home_dir)
         .into_iter()
         .filter(|account| !account_is_signer || account.used_as_signer)
         .map(|account| account.account_id.to_string())
         .collect::<Vec<_>>();
-    let account_id_str = match Text::new(message)
+    let default_account_id = default_account_id.map(ToString::to_string);
+    let mut account_id_prompt = Text::new(message);
+    if let Some(default_account_id) = &default_account_id {
+        account_id_prompt = account_id_prompt.with_default(default_account_id);
+    }
+    let account_id_str = match account_id_prompt
         .with_autocomplete(move |val: &str| {
             Ok(used_account_list
                 .iter()
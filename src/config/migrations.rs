@@ -1,5 +1,5 @@
-use crate::config::Config as ConfigV5;
-use crate::config::NetworkConfig as NetworkConfigV5;
+use crate::config::Config as ConfigV6;
+use crate::config::NetworkConfig as NetworkConfigV6;
 use NetworkConfigV3 as NetworkConfigV2;
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -26,6 +26,12 @@ pub struct ConfigV4 {
     pub network_connection: linked_hash_map::LinkedHashMap<String, NetworkConfigV4>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ConfigV5 {
+    pub credentials_home_dir: std::path::PathBuf,
+    pub network_connection: linked_hash_map::LinkedHashMap<String, NetworkConfigV5>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct NetworkConfigV1 {
     pub network_name: String,
@@ -75,6 +81,25 @@ pub struct NetworkConfigV4 {
     pub tx_wait_until: Option<crate::types::tx_execution_status::TxExecutionStatus>,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct NetworkConfigV5 {
+    pub network_name: String,
+    pub rpc_url: url::Url,
+    pub rpc_api_key: Option<crate::types::api_key::ApiKey>,
+    pub wallet_url: url::Url,
+    pub explorer_transaction_url: url::Url,
+    pub linkdrop_account_id: Option<near_primitives::types::AccountId>,
+    pub near_social_db_contract_account_id: Option<near_primitives::types::AccountId>,
+    pub faucet_url: Option<url::Url>,
+    pub meta_transaction_relayer_url: Option<url::Url>,
+    pub fastnear_url: Option<url::Url>,
+    pub staking_pools_factory_account_id: Option<near_primitives::types::AccountId>,
+    pub coingecko_url: Option<url::Url>,
+    pub mpc_contract_account_id: Option<near_primitives::types::AccountId>,
+    pub tx_wait_until: Option<crate::types::tx_execution_status::TxExecutionStatus>,
+    pub nearblocks_url: Option<url::Url>,
+}
+
 impl From<ConfigV1> for ConfigV2 {
     fn from(config: ConfigV1) -> Self {
         ConfigV2 {
@@ -133,6 +158,73 @@ impl From<ConfigV4> for ConfigV5 {
     }
 }
 
+impl From<ConfigV5> for ConfigV6 {
+    fn from(config: ConfigV5) -> Self {
+        ConfigV6 {
+            credentials_home_dir: config.credentials_home_dir,
+            network_connection: config
+                .network_connection
+                .into_iter()
+                .map(|(connection_name, network_config)| {
+                    // Only connections that still use the RPC that near CLI shipped with get the
+                    // public fallbacks, so requests to a private or paid RPC stay there.
+                    let rpc_fallback_endpoints = if connection_name == network_config.network_name
+                        && network_config.rpc_api_key.is_none()
+                        && is_stock_rpc_url(&network_config.network_name, &network_config.rpc_url)
+                    {
+                        crate::config::default_rpc_fallback_endpoints(&network_config.network_name)
+                    } else {
+                        Vec::new()
+                    };
+                    (
+                        connection_name,
+                        NetworkConfigV6 {
+                            network_name: network_config.network_name,
+                            rpc_url: network_config.rpc_url,
+                            wallet_url: network_config.wallet_url,
+                            explorer_transaction_url: network_config.explorer_transaction_url,
+                            rpc_api_key: network_config.rpc_api_key,
+                            linkdrop_account_id: network_config.linkdrop_account_id,
+                            near_social_db_contract_account_id: network_config
+                                .near_social_db_contract_account_id,
+                            faucet_url: network_config.faucet_url,
+                            meta_transaction_relayer_url: network_config
+                                .meta_transaction_relayer_url,
+                            fastnear_url: network_config.fastnear_url,
+                            staking_pools_factory_account_id: network_config
+                                .staking_pools_factory_account_id,
+                            coingecko_url: network_config.coingecko_url,
+                            mpc_contract_account_id: network_config.mpc_contract_account_id,
+                            tx_wait_until: network_config.tx_wait_until,
+                            nearblocks_url: network_config.nearblocks_url,
+                            rpc_fallback_endpoints,
+                        },
+                    )
+                })
+                .collect(),
+            default_signer_account_id: None,
+        }
+    }
+}
+
+/// The RPC URLs that near CLI has used by default for the network.
+fn is_stock_rpc_url(network_name: &str, rpc_url: &url::Url) -> bool {
+    let stock_rpc_urls: &[&str] = match network_name {
+        "mainnet" => &[
+            "https://archival-rpc.mainnet.fastnear.com/",
+            "https://archival-rpc.mainnet.near.org/",
+            "https://rpc.mainnet.near.org/",
+        ],
+        "testnet" => &[
+            "https://archival-rpc.testnet.fastnear.com/",
+            "https://archival-rpc.testnet.near.org/",
+            "https://rpc.testnet.near.org/",
+        ],
+        _ => &[],
+    };
+    stock_rpc_urls.contains(&rpc_url.as_str())
+}
+
 impl From<NetworkConfigV1> for NetworkConfigV2 {
     fn from(network_config: NetworkConfigV1) -> Self {
         match network_config.network_name.as_str() {
@@ -256,17 +348,87 @@ pub enum ConfigVersion {
     // Adds nearblocks_url to the mainnet and testnet
     #[serde(rename = "5")]
     V5(ConfigV5),
+    // Adds rpc_fallback_endpoints to the mainnet and testnet
+    #[serde(rename = "6")]
+    V6(ConfigV6),
 }
 
 impl ConfigVersion {
     pub fn is_latest_version(&self) -> bool {
         // Used match instead of matches! to compile fail if new version is added
         match self {
-            ConfigVersion::V5(_) => true,
-            ConfigVersion::V4(_)
+            ConfigVersion::V6(_) => true,
+            ConfigVersion::V5(_)
+            | ConfigVersion::V4(_)
             | ConfigVersion::V3(_)
             | ConfigVersion::V2(_)
             | ConfigVersion::V1(_) => false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_v5_to_v6_with_default_rpc_fallback_endpoints() {
+        let config_version: ConfigVersion = toml::from_str(
+            r#"
+            version = "5"
+            credentials_home_dir = "/home/alice/.near-credentials"
+
+            [network_connection.mainnet]
+            network_name = "mainnet"
+            rpc_url = "https://archival-rpc.mainnet.near.org/"
+            wallet_url = "https://app.mynearwallet.com/"
+            explorer_transaction_url = "https://explorer.near.org/transactions/"
+
+            [network_connection.testnet]
+            network_name = "testnet"
+            rpc_url = "https://paid-rpc.example.com/"
+            rpc_api_key = "0b5c33bb-6f4b-4a4c-8e9a-04a6dd9b1a3e"
+            wallet_url = "https://testnet.mynearwallet.com/"
+            explorer_transaction_url = "https://explorer.testnet.near.org/transactions/"
+
+            [network_connection.mainnet-private]
+            network_name = "mainnet"
+            rpc_url = "https://private-rpc.example.com/"
+            wallet_url = "https://app.mynearwallet.com/"
+            explorer_transaction_url = "https://explorer.near.org/transactions/"
+            "#,
+        )
+        .unwrap();
+        assert!(!config_version.is_latest_version());
+
+        let config: ConfigV6 = config_version.into();
+        let mainnet = &config.network_connection["mainnet"];
+        assert_eq!(
+            mainnet.rpc_url.as_str(),
+            "https://archival-rpc.mainnet.near.org/"
+        );
+        assert_eq!(
+            mainnet
+                .rpc_fallback_endpoints
+                .iter()
+                .map(|rpc_endpoint| (rpc_endpoint.url.as_str(), rpc_endpoint.priority))
+                .collect::<Vec<_>>(),
+            [
+                ("https://rpc.mainnet.fastnear.com/", 1),
+                ("https://near.lava.build/", 2)
+            ]
+        );
+        // Custom connections and custom RPCs keep their single RPC endpoint.
+        assert!(
+            config.network_connection["testnet"]
+                .rpc_fallback_endpoints
+                .is_empty()
+        );
+        assert!(
+            config.network_connection["mainnet-private"]
+                .rpc_fallback_endpoints
+                .is_empty()
+        );
+        assert!(config.into_latest_version().is_latest_version());
+    }
+}
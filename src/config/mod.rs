@@ -1,4 +1,5 @@
 mod migrations;
+pub(crate) mod rpc_failover;
 
 pub type CliResult = color_eyre::eyre::Result<()>;
 
@@ -10,6 +11,9 @@ use tracing_indicatif::span_ext::IndicatifSpanExt;
 pub struct Config {
     pub credentials_home_dir: std::path::PathBuf,
     pub network_connection: linked_hash_map::LinkedHashMap<String, NetworkConfig>,
+    /// Suggested as the signer account ID in interactive mode
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_signer_account_id: Option<near_primitives::types::AccountId>,
 }
 
 impl Default for Config {
@@ -41,6 +45,7 @@ impl Default for Config {
                 mpc_contract_account_id: Some("v1.signer".parse().unwrap()),
                 tx_wait_until: None,
                 nearblocks_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+                rpc_fallback_endpoints: default_rpc_fallback_endpoints("mainnet"),
             },
         );
         network_connection.insert(
@@ -63,6 +68,7 @@ impl Default for Config {
                 mpc_contract_account_id: Some("v1.signer".parse().unwrap()),
                 tx_wait_until: None,
                 nearblocks_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+                rpc_fallback_endpoints: Vec::new(),
             },
         );
         network_connection.insert(
@@ -85,6 +91,7 @@ impl Default for Config {
                 mpc_contract_account_id: Some("v1.signer".parse().unwrap()),
                 tx_wait_until: None,
                 nearblocks_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+                rpc_fallback_endpoints: Vec::new(),
             },
         );
 
@@ -110,6 +117,7 @@ impl Default for Config {
                 mpc_contract_account_id: Some("v1.signer-prod.testnet".parse().unwrap()),
                 tx_wait_until: None,
                 nearblocks_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+                rpc_fallback_endpoints: default_rpc_fallback_endpoints("testnet"),
             },
         );
         network_connection.insert(
@@ -132,6 +140,7 @@ impl Default for Config {
                 mpc_contract_account_id: Some("v1.signer-prod.testnet".parse().unwrap()),
                 tx_wait_until: None,
                 nearblocks_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+                rpc_fallback_endpoints: Vec::new(),
             },
         );
         network_connection.insert(
@@ -154,12 +163,14 @@ impl Default for Config {
                 mpc_contract_account_id: Some("v1.signer-prod.testnet".parse().unwrap()),
                 tx_wait_until: None,
                 nearblocks_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+                rpc_fallback_endpoints: Vec::new(),
             },
         );
 
         Self {
             credentials_home_dir,
             network_connection,
+            default_signer_account_id: None,
         }
     }
 }
@@ -178,18 +189,35 @@ impl Config {
     }
 
     pub fn into_latest_version(self) -> migrations::ConfigVersion {
-        migrations::ConfigVersion::V5(self)
+        migrations::ConfigVersion::V6(self)
     }
 
+    /// Returns the user config merged with the project-local config (`near.toml`), if any.
     pub fn get_config_toml() -> color_eyre::eyre::Result<Self> {
-        if let Some(mut path_config_toml) = dirs::config_dir() {
-            path_config_toml.extend(&["near-cli", "config.toml"]);
+        let mut config = Self::get_user_config_toml()?;
+        if let Some(path_project_config_toml) = project_config_toml_path() {
+            ProjectConfig::from_file(&path_project_config_toml)?
+                .merge_into(&mut config, &path_project_config_toml);
+        }
+        Ok(config)
+    }
 
+    /// Returns the user config only, e.g. to modify it with `near config` commands.
+    pub fn get_user_config_toml() -> color_eyre::eyre::Result<Self> {
+        if let Some(path_config_toml) = config_toml_path() {
             if !path_config_toml.is_file() {
+                // Only the default location is created on the first run; a missing file given
+                // explicitly is most likely a typo.
+                if explicit_config_toml_path().is_some() {
+                    return Err(color_eyre::eyre::eyre!(
+                        "The configuration file {path_config_toml:?} (given with `--config` or `NEAR_CLI_CONFIG`) does not exist"
+                    ));
+                }
                 Self::write_config_toml(crate::config::Config::default())?;
             };
 
-            let config_toml = std::fs::read_to_string(&path_config_toml)?;
+            let config_toml = std::fs::read_to_string(&path_config_toml)
+                .wrap_err_with(|| format!("Failed to read file: {path_config_toml:?}"))?;
 
             let config_version = toml::from_str::<migrations::ConfigVersion>(&config_toml).or_else::<color_eyre::eyre::Report, _>(|err| {
                 if let Ok(config_v1) = toml::from_str::<migrations::ConfigV1>(&config_toml) {
@@ -218,12 +246,11 @@ impl Config {
 
     pub fn write_config_toml(self) -> CliResult {
         let config_toml = toml::to_string(&self.into_latest_version())?;
-        let mut path_config_toml =
-            dirs::config_dir().wrap_err("Impossible to get your config dir!")?;
+        let path_config_toml = config_toml_path().wrap_err("Impossible to get your config dir!")?;
 
-        path_config_toml.push("near-cli");
-        std::fs::create_dir_all(&path_config_toml)?;
-        path_config_toml.push("config.toml");
+        if let Some(config_dir) = path_config_toml.parent() {
+            std::fs::create_dir_all(config_dir)?;
+        }
 
         std::fs::File::create(&path_config_toml)
             .wrap_err_with(|| format!("Failed to create file: {path_config_toml:?}"))?
@@ -236,6 +263,90 @@ impl Config {
     }
 }
 
+static CONFIG_TOML_PATH: std::sync::OnceLock<std::path::PathBuf> = std::sync::OnceLock::new();
+
+/// Overrides the location of the user config (the global `--config <path>` flag).
+pub fn set_config_toml_path(path_config_toml: std::path::PathBuf) {
+    let _ = CONFIG_TOML_PATH.set(path_config_toml);
+}
+
+/// The user config is looked up in this order: the `--config <path>` flag, the `NEAR_CLI_CONFIG`
+/// environment variable and `<config dir>/near-cli/config.toml`.
+pub fn config_toml_path() -> Option<std::path::PathBuf> {
+    explicit_config_toml_path().or_else(|| {
+        dirs::config_dir().map(|config_dir| config_dir.join("near-cli").join("config.toml"))
+    })
+}
+
+/// The user config location given with the `--config <path>` flag or the `NEAR_CLI_CONFIG` environment variable.
+fn explicit_config_toml_path() -> Option<std::path::PathBuf> {
+    if let Some(path_config_toml) = CONFIG_TOML_PATH.get() {
+        return Some(path_config_toml.clone());
+    }
+    std::env::var_os("NEAR_CLI_CONFIG")
+        .filter(|path_config_toml| !path_config_toml.is_empty())
+        .map(Into::into)
+}
+
+/// Finds `near.toml` in the current directory or the closest of its parents.
+pub fn project_config_toml_path() -> Option<std::path::PathBuf> {
+    let current_dir = std::env::current_dir().ok()?;
+    current_dir
+        .ancestors()
+        .map(|dir| dir.join("near.toml"))
+        .find(|path_project_config_toml| path_project_config_toml.is_file())
+}
+
+/// A project-local config (`near.toml`) that is merged on top of the user config, e.g.:
+///
+/// ```toml
+/// credentials_home_dir = ".near-credentials"
+/// default_signer_account_id = "alice.test.near"
+///
+/// [network_connection.sandbox]
+/// network_name = "sandbox"
+/// rpc_url = "http://127.0.0.1:3030/"
+/// wallet_url = "http://127.0.0.1:3030/"
+/// explorer_transaction_url = "http://127.0.0.1:3030/"
+/// ```
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProjectConfig {
+    /// Relative paths are resolved against the directory of `near.toml`
+    credentials_home_dir: Option<std::path::PathBuf>,
+    #[serde(default)]
+    network_connection: linked_hash_map::LinkedHashMap<String, NetworkConfig>,
+    default_signer_account_id: Option<near_primitives::types::AccountId>,
+}
+
+impl ProjectConfig {
+    fn from_file(path_project_config_toml: &std::path::Path) -> color_eyre::eyre::Result<Self> {
+        let project_config_toml = std::fs::read_to_string(path_project_config_toml)
+            .wrap_err_with(|| format!("Failed to read file: {path_project_config_toml:?}"))?;
+        toml::from_str(&project_config_toml).wrap_err_with(|| {
+            format!("Failed to parse the project config file: {path_project_config_toml:?}")
+        })
+    }
+
+    /// Network connections with the same name as in the user config replace them entirely.
+    fn merge_into(self, config: &mut Config, path_project_config_toml: &std::path::Path) {
+        if let Some(credentials_home_dir) = self.credentials_home_dir {
+            config.credentials_home_dir = path_project_config_toml
+                .parent()
+                .map(|project_dir| project_dir.join(&credentials_home_dir))
+                .unwrap_or(credentials_home_dir);
+        }
+        for (connection_name, network_config) in self.network_connection {
+            config
+                .network_connection
+                .insert(connection_name, network_config);
+        }
+        if self.default_signer_account_id.is_some() {
+            config.default_signer_account_id = self.default_signer_account_id;
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct NetworkConfig {
     pub network_name: String,
@@ -255,6 +366,54 @@ pub struct NetworkConfig {
     pub mpc_contract_account_id: Option<near_primitives::types::AccountId>,
     pub tx_wait_until: Option<crate::types::tx_execution_status::TxExecutionStatus>,
     pub nearblocks_url: Option<url::Url>,
+    /// RPC endpoints to fail over to when `rpc_url` is unavailable, rate-limited or not synced
+    #[serde(default)]
+    pub rpc_fallback_endpoints: Vec<RpcEndpoint>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RpcEndpoint {
+    pub url: url::Url,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<crate::types::api_key::ApiKey>,
+    /// Endpoints with lower values are tried first (`rpc_url` always has priority 0)
+    #[serde(default)]
+    pub priority: u32,
+}
+
+impl RpcEndpoint {
+    pub fn json_rpc_client(&self) -> near_jsonrpc_client::JsonRpcClient {
+        let mut json_rpc_client = near_jsonrpc_client::JsonRpcClient::connect(self.url.as_ref());
+        if let Some(api_key) = &self.api_key {
+            json_rpc_client =
+                json_rpc_client.header(near_jsonrpc_client::auth::ApiKey::from(api_key.clone()))
+        };
+        json_rpc_client
+    }
+}
+
+/// The public RPC endpoints of the default connections (`mainnet-fastnear`, `mainnet-lava`, ...)
+/// used as fallbacks for the default `mainnet` and `testnet` connections.
+pub(crate) fn default_rpc_fallback_endpoints(network_name: &str) -> Vec<RpcEndpoint> {
+    let urls: &[&str] = match network_name {
+        "mainnet" => &[
+            "https://rpc.mainnet.fastnear.com/",
+            "https://near.lava.build/",
+        ],
+        "testnet" => &[
+            "https://test.rpc.fastnear.com/",
+            "https://neart.lava.build/",
+        ],
+        _ => &[],
+    };
+    urls.iter()
+        .zip(1..)
+        .map(|(url, priority)| RpcEndpoint {
+            url: url.parse().unwrap(),
+            api_key: None,
+            priority,
+        })
+        .collect()
 }
 
 impl NetworkConfig {
@@ -269,17 +428,26 @@ impl NetworkConfig {
             .collect())
     }
 
+    /// `rpc_url` followed by the fallback endpoints, in the order they are tried.
+    pub fn rpc_endpoints(&self) -> Vec<RpcEndpoint> {
+        let mut rpc_endpoints = vec![RpcEndpoint {
+            url: self.rpc_url.clone(),
+            api_key: self.rpc_api_key.clone(),
+            priority: 0,
+        }];
+        rpc_endpoints.extend(self.rpc_fallback_endpoints.iter().cloned());
+        rpc_endpoints.sort_by_key(|rpc_endpoint| rpc_endpoint.priority);
+        rpc_endpoints
+    }
+
+    /// Connects to the first RPC endpoint that has not failed during this run
+    /// (`blocking_call` switches to the next endpoint on its own).
     #[tracing::instrument(name = "Connecting to RPC", skip_all)]
     pub fn json_rpc_client(&self) -> near_jsonrpc_client::JsonRpcClient {
-        tracing::Span::current().pb_set_message(self.rpc_url.as_str());
-        tracing::info!(target: "near_teach_me", "Connecting to RPC {}", self.rpc_url.as_str());
-        let mut json_rpc_client =
-            near_jsonrpc_client::JsonRpcClient::connect(self.rpc_url.as_ref());
-        if let Some(rpc_api_key) = &self.rpc_api_key {
-            json_rpc_client =
-                json_rpc_client.header(near_jsonrpc_client::auth::ApiKey::from(rpc_api_key.clone()))
-        };
-        json_rpc_client
+        let rpc_endpoint = rpc_failover::select_rpc_endpoint(self.rpc_endpoints());
+        tracing::Span::current().pb_set_message(rpc_endpoint.url.as_str());
+        tracing::info!(target: "near_teach_me", "Connecting to RPC {}", rpc_endpoint.url.as_str());
+        rpc_endpoint.json_rpc_client()
     }
 
     pub fn get_near_social_account_id_from_network(
@@ -340,9 +508,122 @@ impl From<migrations::ConfigVersion> for Config {
                     migrations::ConfigVersion::V5(config_v4.into())
                 }
                 migrations::ConfigVersion::V5(config_v5) => {
-                    break config_v5;
+                    eprintln!("Migrating config.toml from V5 to V6...");
+                    migrations::ConfigVersion::V6(config_v5.into())
+                }
+                migrations::ConfigVersion::V6(config_v6) => {
+                    break config_v6;
                 }
             };
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn network_config(network_name: &str, rpc_url: &str) -> NetworkConfig {
+        NetworkConfig {
+            network_name: network_name.to_string(),
+            rpc_url: rpc_url.parse().unwrap(),
+            rpc_api_key: None,
+            wallet_url: rpc_url.parse().unwrap(),
+            explorer_transaction_url: rpc_url.parse().unwrap(),
+            linkdrop_account_id: None,
+            near_social_db_contract_account_id: None,
+            faucet_url: None,
+            meta_transaction_relayer_url: None,
+            fastnear_url: None,
+            staking_pools_factory_account_id: None,
+            coingecko_url: None,
+            mpc_contract_account_id: None,
+            tx_wait_until: None,
+            nearblocks_url: None,
+            rpc_fallback_endpoints: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn merges_project_config_into_user_config() {
+        let mut config = Config {
+            credentials_home_dir: "/home/alice/.near-credentials".into(),
+            network_connection: linked_hash_map::LinkedHashMap::new(),
+            default_signer_account_id: Some("alice.near".parse().unwrap()),
+        };
+        config.network_connection.insert(
+            "testnet".to_string(),
+            network_config("testnet", "https://rpc.testnet.near.org/"),
+        );
+        config.network_connection.insert(
+            "mainnet".to_string(),
+            network_config("mainnet", "https://rpc.mainnet.near.org/"),
+        );
+
+        let project_config: ProjectConfig = toml::from_str(
+            r#"
+            default_signer_account_id = "bob.test.near"
+
+            [network_connection.testnet]
+            network_name = "testnet"
+            rpc_url = "http://127.0.0.1:3030/"
+            wallet_url = "http://127.0.0.1:3030/"
+            explorer_transaction_url = "http://127.0.0.1:3030/"
+
+            [network_connection.sandbox]
+            network_name = "sandbox"
+            rpc_url = "http://127.0.0.1:3031/"
+            wallet_url = "http://127.0.0.1:3031/"
+            explorer_transaction_url = "http://127.0.0.1:3031/"
+            "#,
+        )
+        .unwrap();
+        project_config.merge_into(
+            &mut config,
+            std::path::Path::new("/home/alice/project/near.toml"),
+        );
+
+        assert_eq!(
+            config.credentials_home_dir,
+            std::path::Path::new("/home/alice/.near-credentials")
+        );
+        assert_eq!(
+            config.default_signer_account_id,
+            Some("bob.test.near".parse().unwrap())
+        );
+        assert_eq!(
+            config.network_connection.keys().collect::<Vec<_>>(),
+            ["mainnet", "testnet", "sandbox"]
+        );
+        assert_eq!(
+            config.network_connection["testnet"].rpc_url.as_str(),
+            "http://127.0.0.1:3030/"
+        );
+        assert_eq!(
+            config.network_connection["mainnet"].rpc_url.as_str(),
+            "https://rpc.mainnet.near.org/"
+        );
+    }
+
+    #[test]
+    fn resolves_relative_credentials_home_dir_against_project_dir() {
+        let project_config = |credentials_home_dir: &str| ProjectConfig {
+            credentials_home_dir: Some(credentials_home_dir.into()),
+            ..Default::default()
+        };
+        let path_project_config_toml = std::path::Path::new("/home/alice/project/near.toml");
+
+        let mut config = Config::default();
+        project_config(".near-credentials").merge_into(&mut config, path_project_config_toml);
+        assert_eq!(
+            config.credentials_home_dir,
+            std::path::Path::new("/home/alice/project/.near-credentials")
+        );
+
+        project_config("/srv/near-credentials").merge_into(&mut config, path_project_config_toml);
+        assert_eq!(
+            config.credentials_home_dir,
+            std::path::Path::new("/srv/near-credentials")
+        );
+    }
+}
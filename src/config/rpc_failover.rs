@@ -0,0 +1,333 @@
+//! The RPC endpoints of a network connection are registered here when a client is created,
+//! so that a failed call can be retried against the next endpoint of the same connection,
+//! and the endpoints that failed are skipped for the rest of the run.
+//! When a connection has fallback endpoints, an endpoint is also skipped if its latest block
+//! is stale or the node is syncing.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Mutex;
+
+use near_jsonrpc_client::methods::status::RpcStatusError;
+
+use super::RpcEndpoint;
+
+/// An endpoint whose latest block is older than this is considered stale.
+pub(crate) const STALE_BLOCK_AGE_SECS: i64 = 60;
+const SYNC_STATUS_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+static RPC_FAILOVER_GROUPS: Mutex<BTreeMap<String, Vec<RpcEndpoint>>> = Mutex::new(BTreeMap::new());
+static FAILED_RPC_URLS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+static SYNC_CHECKED_RPC_URLS: Mutex<BTreeSet<String>> = Mutex::new(BTreeSet::new());
+
+pub(crate) fn select_rpc_endpoint(rpc_endpoints: Vec<RpcEndpoint>) -> RpcEndpoint {
+    if rpc_endpoints.len() > 1 {
+        let mut rpc_failover_groups = RPC_FAILOVER_GROUPS.lock().unwrap();
+        for endpoint in &rpc_endpoints {
+            rpc_failover_groups.insert(endpoint.url.to_string(), rpc_endpoints.clone());
+        }
+    }
+    loop {
+        let rpc_endpoint = {
+            let failed_rpc_urls = FAILED_RPC_URLS.lock().unwrap();
+            match rpc_endpoints
+                .iter()
+                .find(|rpc_endpoint| !failed_rpc_urls.contains(rpc_endpoint.url.as_str()))
+            {
+                Some(rpc_endpoint) => rpc_endpoint.clone(),
+                None => return rpc_endpoints[0].clone(),
+            }
+        };
+        if rpc_endpoints.len() == 1
+            || !SYNC_CHECKED_RPC_URLS
+                .lock()
+                .unwrap()
+                .insert(rpc_endpoint.url.to_string())
+        {
+            return rpc_endpoint;
+        }
+        // An endpoint that cannot be reached is left to the failover of the call itself.
+        let Some(reason) = get_sync_status(&rpc_endpoint, SYNC_STATUS_TIMEOUT)
+            .ok()
+            .and_then(|sync_status| sync_status.lag_reason())
+        else {
+            return rpc_endpoint;
+        };
+        tracing::warn!(
+            parent: &tracing::Span::none(),
+            "RPC {} is behind the chain ({reason}), skipping it",
+            rpc_endpoint.url
+        );
+        FAILED_RPC_URLS
+            .lock()
+            .unwrap()
+            .insert(rpc_endpoint.url.to_string());
+    }
+}
+
+/// Marks the endpoint as failed and returns the endpoints of the same network connection
+/// that have not failed yet, in the order of priority.
+pub(crate) fn fail_over_from(server_addr: &str) -> Vec<RpcEndpoint> {
+    let mut failed_rpc_urls = FAILED_RPC_URLS.lock().unwrap();
+    failed_rpc_urls.insert(server_addr.to_string());
+    RPC_FAILOVER_GROUPS
+        .lock()
+        .unwrap()
+        .get(server_addr)
+        .map(|rpc_endpoints| {
+            rpc_endpoints
+                .iter()
+                .filter(|rpc_endpoint| !failed_rpc_urls.contains(rpc_endpoint.url.as_str()))
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[derive(Debug, serde::Deserialize)]
+struct PartialStatusView {
+    sync_info: PartialSyncInfoView,
+}
+
+impl near_jsonrpc_client::methods::RpcHandlerResponse for PartialStatusView {}
+
+#[derive(Debug, serde::Deserialize)]
+struct PartialSyncInfoView {
+    latest_block_height: near_primitives::types::BlockHeight,
+    latest_block_time: chrono::DateTime<chrono::Utc>,
+    syncing: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct RpcEndpointSyncStatus {
+    pub latest_block_height: near_primitives::types::BlockHeight,
+    pub latest_block_age_secs: i64,
+    pub syncing: bool,
+}
+
+impl RpcEndpointSyncStatus {
+    /// Why the endpoint cannot be relied on to serve the current state of the chain.
+    pub(crate) fn lag_reason(&self) -> Option<String> {
+        if self.syncing {
+            Some("the node is syncing".to_string())
+        } else if self.latest_block_age_secs > STALE_BLOCK_AGE_SECS {
+            Some(format!(
+                "its latest block is {} s old",
+                self.latest_block_age_secs
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Asks the endpoint for the latest block it knows about.
+pub(crate) fn get_sync_status(
+    rpc_endpoint: &RpcEndpoint,
+    timeout: std::time::Duration,
+) -> color_eyre::eyre::Result<RpcEndpointSyncStatus> {
+    let json_rpc_client = rpc_endpoint.json_rpc_client();
+    // A separate thread, so that this also works when called from within a Tokio runtime.
+    let status = std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                tokio::runtime::Runtime::new().map(|runtime| {
+                    runtime.block_on(async {
+                        tokio::time::timeout(
+                            timeout,
+                            json_rpc_client.call(near_jsonrpc_client::methods::any::<
+                                Result<PartialStatusView, RpcStatusError>,
+                            >(
+                                "status", serde_json::Value::Null
+                            )),
+                        )
+                        .await
+                    })
+                })
+            })
+            .join()
+            .expect("the thread querying the RPC status panicked")
+    })?
+    .map_err(|_| color_eyre::eyre::eyre!("No response within {} seconds", timeout.as_secs()))??;
+    Ok(RpcEndpointSyncStatus {
+        latest_block_height: status.sync_info.latest_block_height,
+        latest_block_age_secs: chrono::Utc::now().timestamp()
+            - status.sync_info.latest_block_time.timestamp(),
+        syncing: status.sync_info.syncing,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+    use crate::common::JsonRpcClientExt;
+
+    fn rpc_endpoint(url: &str, priority: u32) -> RpcEndpoint {
+        RpcEndpoint {
+            url: url.parse().unwrap(),
+            api_key: None,
+            priority,
+        }
+    }
+
+    fn network_config(
+        rpc_url: &str,
+        rpc_fallback_endpoints: Vec<RpcEndpoint>,
+    ) -> crate::config::NetworkConfig {
+        crate::config::NetworkConfig {
+            network_name: "mainnet".to_string(),
+            rpc_url: rpc_url.parse().unwrap(),
+            rpc_api_key: None,
+            wallet_url: "http://127.0.0.1:2/".parse().unwrap(),
+            explorer_transaction_url: "http://127.0.0.1:3/".parse().unwrap(),
+            linkdrop_account_id: None,
+            near_social_db_contract_account_id: None,
+            faucet_url: None,
+            meta_transaction_relayer_url: None,
+            fastnear_url: None,
+            staking_pools_factory_account_id: None,
+            coingecko_url: None,
+            mpc_contract_account_id: None,
+            tx_wait_until: None,
+            nearblocks_url: None,
+            rpc_fallback_endpoints,
+        }
+    }
+
+    /// A local address that refuses connections.
+    fn unavailable_rpc_url() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        format!(
+            "http://127.0.0.1:{}/",
+            listener.local_addr().unwrap().port()
+        )
+    }
+
+    fn spawn_mock_rpc_server(body: &'static str) -> (String, thread::JoinHandle<()>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0u8; 1024];
+            let _ = stream.read(&mut buffer).unwrap();
+
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+
+        (format!("http://127.0.0.1:{}/", addr.port()), handle)
+    }
+
+    #[test]
+    fn orders_rpc_endpoints_by_priority() {
+        let network_config = network_config(
+            "http://rpc-priority-0.test/",
+            vec![
+                rpc_endpoint("http://rpc-priority-2.test/", 2),
+                rpc_endpoint("http://rpc-priority-1.test/", 1),
+            ],
+        );
+        let rpc_endpoints = network_config.rpc_endpoints();
+        assert_eq!(
+            rpc_endpoints
+                .iter()
+                .map(|rpc_endpoint| rpc_endpoint.url.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "http://rpc-priority-0.test/",
+                "http://rpc-priority-1.test/",
+                "http://rpc-priority-2.test/"
+            ]
+        );
+        assert_eq!(
+            select_rpc_endpoint(rpc_endpoints).url.as_str(),
+            "http://rpc-priority-0.test/"
+        );
+
+        // Once an endpoint failed, the next one in the order of priority is used.
+        assert_eq!(
+            fail_over_from("http://rpc-priority-0.test/")
+                .iter()
+                .map(|rpc_endpoint| rpc_endpoint.url.as_str())
+                .collect::<Vec<_>>(),
+            ["http://rpc-priority-1.test/", "http://rpc-priority-2.test/"]
+        );
+        assert_eq!(
+            select_rpc_endpoint(network_config.rpc_endpoints())
+                .url
+                .as_str(),
+            "http://rpc-priority-1.test/"
+        );
+    }
+
+    #[test]
+    fn fails_over_to_the_next_endpoint_on_transport_error() {
+        let (fallback_rpc_url, server_handle) = spawn_mock_rpc_server(
+            r#"{"jsonrpc": "2.0", "id": "dontcare", "result": {"gas_price": "100000000"}}"#,
+        );
+        let unavailable_rpc_url = unavailable_rpc_url();
+        let network_config = network_config(
+            &unavailable_rpc_url,
+            vec![rpc_endpoint(&fallback_rpc_url, 1)],
+        );
+
+        let gas_price = network_config
+            .json_rpc_client()
+            .blocking_call(
+                near_jsonrpc_client::methods::gas_price::RpcGasPriceRequest { block_id: None },
+            )
+            .unwrap();
+        assert_eq!(gas_price.gas_price.as_yoctonear(), 100_000_000);
+        server_handle.join().unwrap();
+
+        assert_eq!(
+            network_config.json_rpc_client().server_addr(),
+            fallback_rpc_url
+        );
+    }
+
+    #[test]
+    fn skips_an_endpoint_whose_latest_block_is_stale() {
+        let (stale_rpc_url, server_handle) = spawn_mock_rpc_server(
+            r#"{"jsonrpc": "2.0", "id": "dontcare", "result": {"sync_info": {"latest_block_height": 100, "latest_block_time": "2020-01-01T00:00:00.000000000Z", "syncing": false}}}"#,
+        );
+        let fallback_rpc_url = unavailable_rpc_url();
+        let network_config =
+            network_config(&stale_rpc_url, vec![rpc_endpoint(&fallback_rpc_url, 1)]);
+
+        assert_eq!(
+            network_config.json_rpc_client().server_addr(),
+            fallback_rpc_url
+        );
+        server_handle.join().unwrap();
+        assert!(FAILED_RPC_URLS.lock().unwrap().contains(&stale_rpc_url));
+    }
+
+    #[test]
+    fn reports_why_an_endpoint_lags_behind() {
+        let sync_status = |latest_block_age_secs, syncing| RpcEndpointSyncStatus {
+            latest_block_height: 100,
+            latest_block_age_secs,
+            syncing,
+        };
+        assert_eq!(sync_status(5, false).lag_reason(), None);
+        assert_eq!(
+            sync_status(STALE_BLOCK_AGE_SECS + 1, false).lag_reason(),
+            Some(format!(
+                "its latest block is {} s old",
+                STALE_BLOCK_AGE_SECS + 1
+            ))
+        );
+        assert_eq!(
+            sync_status(5, true).lag_reason(),
+            Some("the node is syncing".to_string())
+        );
+    }
+}
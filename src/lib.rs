@@ -19,6 +19,9 @@ pub struct GlobalContext {
     pub config: crate::config::Config,
     pub offline: bool,
     pub verbosity: Verbosity,
+    pub output_format: crate::common::OutputFormat,
+    /// Decode function call return values with the result types of the contract ABI
+    pub decode_with_abi: bool,
 }
 
 #[derive(Debug, Copy, Clone, Default)]
@@ -29,6 +29,10 @@ type ConfigContext = (crate::config::Config,);
 #[interactive_clap(input_context = ConfigContext)]
 #[interactive_clap(output_context = CmdContext)]
 struct Cmd {
+    /// Path to the config file (default: $NEAR_CLI_CONFIG or <config dir>/near-cli/config.toml)
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    config: Option<crate::types::path_buf::PathBuf>,
     /// Offline mode
     #[interactive_clap(long)]
     offline: bool,
@@ -38,6 +42,13 @@ struct Cmd {
     /// TEACH-ME mode
     #[interactive_clap(long)]
     teach_me: bool,
+    /// Output format for view commands: plaintext (default) or json
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    output: Option<crate::common::OutputFormat>,
+    /// Decode function call return values with the result types of the contract ABI (fetches the ABI)
+    #[interactive_clap(long)]
+    decode_with_abi: bool,
     #[interactive_clap(subcommand)]
     top_level: crate::commands::TopLevelCommand,
 }
@@ -61,6 +72,8 @@ impl CmdContext {
             config: previous_context.0,
             offline: scope.offline,
             verbosity,
+            output_format: scope.output.unwrap_or_default(),
+            decode_with_abi: scope.decode_with_abi,
         }))
     }
 }
@@ -74,8 +87,6 @@ impl From<CmdContext> for crate::GlobalContext {
 fn main() -> crate::common::CliResult {
     inquire::set_global_render_config(near_cli_rs::get_global_render_config());
 
-    let config = crate::config::Config::get_config_toml()?;
-
     #[cfg(not(debug_assertions))]
     let display_env_section = false;
     #[cfg(debug_assertions)]
@@ -141,6 +152,11 @@ fn main() -> crate::common::CliResult {
     };
     near_cli_rs::setup_tracing(verbosity)?;
 
+    if let Some(path_config_toml) = &cli.config {
+        crate::config::set_config_toml_path(path_config_toml.0.clone());
+    }
+    let config = crate::config::Config::get_config_toml()?;
+
     if !crate::common::is_used_account_list_exist(&config.credentials_home_dir) {
         let _ = crate::common::create_used_account_list_from_legacy_keychain(
             &config.credentials_home_dir,
@@ -224,9 +240,12 @@ fn main() -> crate::common::CliResult {
                 "\n`near` CLI has a new update available \x1b[2m{current_version}\x1b[0m →  \x1b[32m{latest_version}\x1b[0m"
             );
             let self_update_cli_cmd = CliCmd {
+                config: None,
                 offline: false,
                 quiet: false,
                 teach_me: false,
+                output: None,
+                decode_with_abi: false,
                 top_level: Some(crate::commands::CliTopLevelCommand::Extensions(
                     crate::commands::extensions::CliExtensionsCommands {
                         extensions_actions: Some(
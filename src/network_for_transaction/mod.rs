@@ -1,3 +1,5 @@
+use std::io::IsTerminal;
+
 use color_eyre::eyre::ContextCompat;
 
 #[derive(Debug, Clone, interactive_clap::InteractiveClap)]
@@ -133,6 +135,26 @@ impl interactive_clap::FromCli for NetworkForTransactionArgs {
                 &new_context.prepopulated_transaction,
             ))
         );
+        // The estimate takes a few extra RPC calls, so it is only shown to a user at the terminal.
+        if !new_context.global_context.offline
+            && !matches!(
+                new_context.global_context.verbosity,
+                crate::Verbosity::Quiet
+            )
+            && std::io::stdin().is_terminal()
+        {
+            match crate::types::transaction_cost::get_transaction_cost_summary(
+                &new_context.network_config,
+                &new_context.prepopulated_transaction,
+                new_context.sign_as_delegate_action,
+            ) {
+                Ok(cost_summary) => tracing::info!(
+                    "Estimated cost:{}",
+                    crate::common::indent_payload(&cost_summary)
+                ),
+                Err(err) => tracing::warn!("The transaction cost was not estimated: {err}"),
+            }
+        }
 
         match <crate::transaction_signature_options::SignWith as interactive_clap::FromCli>::from_cli(
                 clap_variant.transaction_signature_options.take(),
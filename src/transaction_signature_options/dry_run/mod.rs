@@ -0,0 +1,545 @@
+use color_eyre::eyre::eyre;
+use color_eyre::owo_colors::OwoColorize;
+use prettytable::Table;
+
+#[derive(Debug, Clone, interactive_clap_derive::InteractiveClap)]
+#[interactive_clap(input_context = super::SubmitContext)]
+#[interactive_clap(output_context = DryRunContext)]
+pub struct DryRun;
+
+#[derive(Debug, Clone)]
+pub struct DryRunContext;
+
+impl DryRunContext {
+    pub fn from_previous_context(
+        previous_context: super::SubmitContext,
+        _scope: &<DryRun as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let network_config = &previous_context.network_config;
+        // The relayer pays for the gas and the deposits of a meta-transaction.
+        let (signer_id, public_key, receiver_id, actions, is_delegate_action) =
+            match &previous_context.signed_transaction_or_signed_delegate_action {
+                super::SignedTransactionOrSignedDelegateAction::SignedTransaction(
+                    signed_transaction,
+                ) => (
+                    signed_transaction.transaction.signer_id().clone(),
+                    signed_transaction.transaction.public_key().clone(),
+                    signed_transaction.transaction.receiver_id().clone(),
+                    signed_transaction.transaction.actions().to_vec(),
+                    false,
+                ),
+                super::SignedTransactionOrSignedDelegateAction::SignedDelegateAction(
+                    signed_delegate_action,
+                ) => (
+                    signed_delegate_action.delegate_action.sender_id.clone(),
+                    signed_delegate_action.delegate_action.public_key.clone(),
+                    signed_delegate_action.delegate_action.receiver_id.clone(),
+                    signed_delegate_action.delegate_action.get_actions(),
+                    true,
+                ),
+            };
+
+        let runtime_config = crate::types::transaction_cost::get_runtime_config(network_config)?;
+        let gas_price = crate::types::transaction_cost::get_gas_price(network_config)?;
+        let transaction_cost = crate::types::transaction_cost::estimate_transaction_cost(
+            &runtime_config.fees,
+            gas_price,
+            &signer_id,
+            &receiver_id,
+            &actions,
+        )?;
+        let cost_to_signer = transaction_cost.cost_to_signer(is_delegate_action);
+
+        let mut issues = Vec::new();
+        if !transaction_cost.is_complete() {
+            issues.push(DryRunIssue::warning(
+                "The fees of some actions are not estimated, so the cost may be higher",
+            ));
+        }
+        check_access_key(
+            network_config,
+            &signer_id,
+            &public_key,
+            &receiver_id,
+            &actions,
+            (!is_delegate_action).then(|| transaction_cost.gas_cost()),
+            &mut issues,
+        )?;
+        let runtime = tokio::runtime::Runtime::new()?;
+        check_receiver(
+            &runtime,
+            network_config,
+            &receiver_id,
+            &actions,
+            &mut issues,
+        );
+        check_balance(
+            &runtime,
+            network_config,
+            &signer_id,
+            cost_to_signer,
+            &mut issues,
+        );
+
+        let errors_count = issues
+            .iter()
+            .filter(|issue| issue.severity == DryRunSeverity::Error)
+            .count();
+
+        if let crate::common::OutputFormat::Json = previous_context.global_context.output_format {
+            let messages = |severity: DryRunSeverity| {
+                issues
+                    .iter()
+                    .filter(|issue| issue.severity == severity)
+                    .map(|issue| issue.message.clone())
+                    .collect::<Vec<_>>()
+            };
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "signer_id": signer_id,
+                    "receiver_id": receiver_id,
+                    "is_delegate_action": is_delegate_action,
+                    "cost": transaction_cost.to_json(),
+                    "cost_to_signer": cost_to_signer,
+                    "errors": messages(DryRunSeverity::Error),
+                    "warnings": messages(DryRunSeverity::Warning),
+                }))?
+            );
+        } else {
+            print_dry_run_report(
+                &signer_id,
+                &receiver_id,
+                &transaction_cost,
+                is_delegate_action,
+                &issues,
+            );
+        }
+
+        if errors_count > 0 {
+            return Err(eyre!(
+                "The transaction would fail: {errors_count} issue(s) found (nothing was sent)"
+            ));
+        }
+        Ok(Self)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+pub enum DryRunSeverity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone)]
+pub struct DryRunIssue {
+    pub severity: DryRunSeverity,
+    pub message: String,
+}
+
+impl DryRunIssue {
+    fn error(message: impl Into<String>) -> Self {
+        Self {
+            severity: DryRunSeverity::Error,
+            message: message.into(),
+        }
+    }
+
+    fn warning(message: impl Into<String>) -> Self {
+        Self {
+            severity: DryRunSeverity::Warning,
+            message: message.into(),
+        }
+    }
+}
+
+/// Checks that the access key exists and that its permission allows the actions
+/// (and, for a function-call key, that its allowance covers the gas cost).
+fn check_access_key(
+    network_config: &crate::config::NetworkConfig,
+    signer_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+    receiver_id: &near_primitives::types::AccountId,
+    actions: &[near_primitives::transaction::Action],
+    gas_cost: Option<near_token::NearToken>,
+    issues: &mut Vec<DryRunIssue>,
+) -> crate::CliResult {
+    match crate::common::verify_account_access_key(
+        signer_id.clone(),
+        public_key.clone(),
+        network_config.clone(),
+    ) {
+        Ok(access_key_view) => issues.extend(check_access_key_permission(
+            &access_key_view.permission,
+            receiver_id,
+            actions,
+            gas_cost,
+        )),
+        Err(crate::common::AccountStateError::JsonRpcError(
+            near_jsonrpc_client::errors::JsonRpcError::ServerError(
+                near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                    near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccessKey {
+                        ..
+                    },
+                ),
+            ),
+        )) => issues.push(DryRunIssue::error(format!(
+            "The access key {public_key} does not exist on the account <{signer_id}>"
+        ))),
+        Err(crate::common::AccountStateError::Cancel) => {
+            return Err(eyre!("Operation was canceled by the user"));
+        }
+        Err(err) => issues.push(DryRunIssue::warning(format!(
+            "The access key {public_key} of <{signer_id}> was not checked: {err}"
+        ))),
+    }
+    Ok(())
+}
+
+fn check_access_key_permission(
+    permission: &near_primitives::views::AccessKeyPermissionView,
+    receiver_id: &near_primitives::types::AccountId,
+    actions: &[near_primitives::transaction::Action],
+    gas_cost: Option<near_token::NearToken>,
+) -> Vec<DryRunIssue> {
+    let mut issues = Vec::new();
+    let (allowance, permission_receiver_id, method_names) = match permission {
+        near_primitives::views::AccessKeyPermissionView::FullAccess => return issues,
+        near_primitives::views::AccessKeyPermissionView::GasKeyFullAccess { balance, .. } => {
+            if let Some(gas_cost) = gas_cost
+                && *balance < gas_cost
+            {
+                issues.push(DryRunIssue::error(format!(
+                    "The gas key balance ({balance}) does not cover the gas cost ({gas_cost})"
+                )));
+            }
+            return issues;
+        }
+        near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance,
+            receiver_id,
+            method_names,
+        } => (allowance, receiver_id, method_names),
+        near_primitives::views::AccessKeyPermissionView::GasKeyFunctionCall {
+            balance,
+            allowance,
+            receiver_id,
+            method_names,
+            ..
+        } => {
+            if let Some(gas_cost) = gas_cost
+                && *balance < gas_cost
+            {
+                issues.push(DryRunIssue::error(format!(
+                    "The gas key balance ({balance}) does not cover the gas cost ({gas_cost})"
+                )));
+            }
+            (allowance, receiver_id, method_names)
+        }
+    };
+
+    if receiver_id.as_str() != permission_receiver_id {
+        issues.push(DryRunIssue::error(format!(
+            "The function-call access key only allows calls to <{permission_receiver_id}>, not to <{receiver_id}>"
+        )));
+    }
+    for action in actions {
+        let near_primitives::transaction::Action::FunctionCall(function_call_action) = action
+        else {
+            issues.push(DryRunIssue::error(format!(
+                "A function-call access key can only sign function calls, but the transaction has the action: {}",
+                crate::common::action_summary(action)
+            )));
+            continue;
+        };
+        if !method_names.is_empty() && !method_names.contains(&function_call_action.method_name) {
+            issues.push(DryRunIssue::error(format!(
+                "The function-call access key does not allow the method \"{}\" (allowed: {})",
+                function_call_action.method_name,
+                method_names.join(", ")
+            )));
+        }
+        if !function_call_action.deposit.is_zero() {
+            issues.push(DryRunIssue::error(format!(
+                "A function-call access key cannot attach a deposit ({} is attached to \"{}\")",
+                function_call_action.deposit.exact_amount_display(),
+                function_call_action.method_name
+            )));
+        }
+    }
+    if let (Some(allowance), Some(gas_cost)) = (allowance, gas_cost)
+        && *allowance < gas_cost
+    {
+        issues.push(DryRunIssue::error(format!(
+            "The allowance of the function-call access key ({allowance}) does not cover the gas cost ({gas_cost})"
+        )));
+    }
+    issues
+}
+
+/// Checks that the receiver exists, unless the transaction creates it.
+fn check_receiver(
+    runtime: &tokio::runtime::Runtime,
+    network_config: &crate::config::NetworkConfig,
+    receiver_id: &near_primitives::types::AccountId,
+    actions: &[near_primitives::transaction::Action],
+    issues: &mut Vec<DryRunIssue>,
+) {
+    match runtime.block_on(crate::common::get_account_state(
+        network_config,
+        receiver_id,
+        near_primitives::types::BlockReference::latest(),
+    )) {
+        Ok(_) => {}
+        Err(near_jsonrpc_client::errors::JsonRpcError::ServerError(
+            near_jsonrpc_client::errors::JsonRpcServerError::HandlerError(
+                near_jsonrpc_primitives::types::query::RpcQueryError::UnknownAccount { .. },
+            ),
+        )) => {
+            let creates_receiver = actions.iter().any(|action| match action {
+                near_primitives::transaction::Action::CreateAccount(_)
+                | near_primitives::transaction::Action::DeterministicStateInit(_) => true,
+                near_primitives::transaction::Action::Transfer(_) => {
+                    receiver_id.get_account_type().is_implicit()
+                }
+                _ => false,
+            });
+            if !creates_receiver {
+                issues.push(DryRunIssue::error(format!(
+                    "The receiver account <{receiver_id}> does not exist on network <{}>",
+                    network_config.network_name
+                )));
+            }
+        }
+        Err(err) => issues.push(DryRunIssue::warning(format!(
+            "The receiver account <{receiver_id}> was not checked: {err}"
+        ))),
+    }
+}
+
+/// Checks that the balance of the signer that is available for transfer covers the cost.
+fn check_balance(
+    runtime: &tokio::runtime::Runtime,
+    network_config: &crate::config::NetworkConfig,
+    signer_id: &near_primitives::types::AccountId,
+    cost_to_signer: near_token::NearToken,
+    issues: &mut Vec<DryRunIssue>,
+) {
+    match runtime.block_on(crate::common::get_account_transfer_allowance(
+        network_config,
+        signer_id.clone(),
+        near_primitives::types::BlockReference::latest(),
+    )) {
+        Ok(transfer_allowance) if transfer_allowance.transfer_allowance() < cost_to_signer => {
+            issues.push(DryRunIssue::error(format!(
+                "Insufficient balance: the transaction may cost up to {cost_to_signer}, but {transfer_allowance}"
+            )));
+        }
+        Ok(_) => {}
+        Err(err) => issues.push(DryRunIssue::warning(format!(
+            "The balance of <{signer_id}> was not checked: {err}"
+        ))),
+    }
+}
+
+fn print_dry_run_report(
+    signer_id: &near_primitives::types::AccountId,
+    receiver_id: &near_primitives::types::AccountId,
+    transaction_cost: &crate::types::transaction_cost::TransactionCost,
+    is_delegate_action: bool,
+    issues: &[DryRunIssue],
+) {
+    eprintln!(
+        "\nDry run of the {} from <{signer_id}> to <{receiver_id}> (nothing was sent):\n",
+        if is_delegate_action {
+            "delegate action"
+        } else {
+            "transaction"
+        }
+    );
+
+    let mut table = Table::new();
+    table.set_format(*prettytable::format::consts::FORMAT_NO_COLSEP);
+    table.set_titles(prettytable::row![Fg->"Action", Fg->"Fees", Fg->"Prepaid gas", Fg->"Deposit"]);
+    table.add_row(prettytable::row![
+        "action receipt",
+        transaction_cost.receipt_fees,
+        "",
+        ""
+    ]);
+    for action in &transaction_cost.actions {
+        table.add_row(prettytable::row![
+            action.summary,
+            action
+                .fees
+                .map_or_else(|| "not estimated".to_string(), |fees| fees.to_string()),
+            action.prepaid_gas,
+            action.deposit.exact_amount_display()
+        ]);
+    }
+    table.printstd();
+
+    eprintln!(
+        "\nGas: {} of fees and {} of prepaid gas at the gas price of {} per gas unit",
+        transaction_cost.fees(),
+        transaction_cost.prepaid_gas(),
+        transaction_cost.gas_price.exact_amount_display()
+    );
+    if transaction_cost.storage_usage() > 0 {
+        eprintln!(
+            "Storage staking: {} ({} bytes locked on <{receiver_id}>)",
+            transaction_cost.storage_stake(),
+            transaction_cost.storage_usage()
+        );
+    }
+    let signer_storage_stake = if transaction_cost.is_storage_staked_by_signer {
+        format!(" + {} of storage staking", transaction_cost.storage_stake())
+    } else {
+        String::new()
+    };
+    if is_delegate_action {
+        eprintln!(
+            "Expected cost: up to {} of gas + {} of deposits (paid by the relayer){signer_storage_stake}",
+            transaction_cost.gas_cost(),
+            transaction_cost.deposit()
+        );
+    } else {
+        eprintln!(
+            "Expected cost: up to {} ({} of gas + {} of deposits{signer_storage_stake}; the unused prepaid gas is refunded)",
+            transaction_cost.total_cost(),
+            transaction_cost.gas_cost(),
+            transaction_cost.deposit()
+        );
+    }
+
+    if issues.is_empty() {
+        eprintln!("\n{}", "No problems found.".green());
+        return;
+    }
+    eprintln!();
+    for issue in issues {
+        match issue.severity {
+            DryRunSeverity::Error => eprintln!("{}: {}", issue.severity.red(), issue.message),
+            DryRunSeverity::Warning => {
+                eprintln!("{}: {}", issue.severity.yellow(), issue.message)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function_call(method_name: &str, deposit: u128) -> near_primitives::transaction::Action {
+        near_primitives::transaction::Action::FunctionCall(Box::new(
+            near_primitives::transaction::FunctionCallAction {
+                method_name: method_name.to_string(),
+                args: vec![],
+                gas: near_primitives::types::Gas::from_teragas(30),
+                deposit: near_token::NearToken::from_yoctonear(deposit),
+            },
+        ))
+    }
+
+    #[test]
+    fn checks_function_call_key_permission() {
+        let permission = near_primitives::views::AccessKeyPermissionView::FunctionCall {
+            allowance: Some(near_token::NearToken::from_millinear(1)),
+            receiver_id: "contract.near".to_string(),
+            method_names: vec!["add".to_string()],
+        };
+        let receiver_id: near_primitives::types::AccountId = "contract.near".parse().unwrap();
+        assert!(
+            check_access_key_permission(
+                &permission,
+                &receiver_id,
+                &[function_call("add", 0)],
+                Some(near_token::NearToken::from_micronear(1)),
+            )
+            .is_empty()
+        );
+
+        let issues = check_access_key_permission(
+            &permission,
+            &"other.near".parse().unwrap(),
+            &[
+                function_call("remove", 1),
+                near_primitives::transaction::Action::Transfer(
+                    near_primitives::transaction::TransferAction {
+                        deposit: near_token::NearToken::from_near(1),
+                    },
+                ),
+            ],
+            Some(near_token::NearToken::from_near(1)),
+        );
+        let messages = issues
+            .iter()
+            .map(|issue| issue.message.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(messages.len(), 5, "{messages:#?}");
+        assert!(messages[0].contains("only allows calls to <contract.near>"));
+        assert!(messages[1].contains("does not allow the method \"remove\""));
+        assert!(messages[2].contains("cannot attach a deposit"));
+        assert!(messages[3].contains("can only sign function calls"));
+        assert!(messages[4].contains("allowance"));
+        assert!(
+            issues
+                .iter()
+                .all(|issue| issue.severity == DryRunSeverity::Error)
+        );
+    }
+
+    #[test]
+    fn charges_the_sender_of_a_delegate_action_only_for_the_storage() {
+        let fees_config = near_parameters::RuntimeFeesConfig::test();
+        let sender_id: near_primitives::types::AccountId = "alice.near".parse().unwrap();
+        let transfer = near_primitives::transaction::Action::Transfer(
+            near_primitives::transaction::TransferAction {
+                deposit: near_token::NearToken::from_near(1),
+            },
+        );
+        let transaction_cost = crate::types::transaction_cost::estimate_transaction_cost(
+            &fees_config,
+            near_token::NearToken::from_yoctonear(100_000_000),
+            &sender_id,
+            &"bob.near".parse().unwrap(),
+            std::slice::from_ref(&transfer),
+        )
+        .unwrap();
+        assert_eq!(
+            transaction_cost.deposit(),
+            near_token::NearToken::from_near(1)
+        );
+        // The relayer pays for the gas and the transferred deposit
+        assert_eq!(
+            transaction_cost.cost_to_signer(true),
+            near_token::NearToken::ZERO
+        );
+        assert_eq!(
+            transaction_cost.cost_to_signer(false),
+            transaction_cost.total_cost()
+        );
+
+        let add_key = near_primitives::transaction::Action::AddKey(Box::new(
+            near_primitives::transaction::AddKeyAction {
+                public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+                access_key: near_primitives::account::AccessKey::full_access(),
+            },
+        ));
+        let transaction_cost = crate::types::transaction_cost::estimate_transaction_cost(
+            &fees_config,
+            near_token::NearToken::from_yoctonear(100_000_000),
+            &sender_id,
+            &sender_id,
+            &[transfer, add_key],
+        )
+        .unwrap();
+        // The key added to the sender account stakes its storage on the sender
+        assert_eq!(
+            transaction_cost.cost_to_signer(true),
+            transaction_cost.storage_stake()
+        );
+        assert!(!transaction_cost.storage_stake().is_zero());
+    }
+}
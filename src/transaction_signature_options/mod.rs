@@ -2,10 +2,12 @@ use serde::Deserialize;
 use strum::{EnumDiscriminants, EnumIter, EnumMessage};
 
 pub mod display;
+pub mod dry_run;
 pub mod save_to_file;
 pub mod send;
 pub mod sign_later;
 pub mod sign_with_access_key_file;
+pub mod sign_with_encrypted_keystore;
 pub mod sign_with_keychain;
 #[cfg(feature = "ledger")]
 pub mod sign_with_ledger;
@@ -14,6 +16,7 @@ pub mod sign_with_mpc;
 pub mod sign_with_private_key;
 pub mod sign_with_seed_phrase;
 pub mod submit_dao_proposal;
+pub mod submit_multisig_request;
 
 pub const META_TRANSACTION_VALID_FOR_DEFAULT: u64 = 1000;
 
@@ -32,6 +35,11 @@ pub enum SignWith {
     ))]
     /// Sign the transaction with a key saved in legacy keychain (compatible with the old near CLI)
     SignWithLegacyKeychain(self::sign_with_legacy_keychain::SignLegacyKeychain),
+    #[strum_discriminants(strum(
+        message = "sign-with-encrypted-keystore     - Sign the transaction with a key saved in the passphrase-encrypted keystore"
+    ))]
+    /// Sign the transaction with a key saved in the passphrase-encrypted keystore
+    SignWithEncryptedKeystore(self::sign_with_encrypted_keystore::SignEncryptedKeystore),
     #[cfg(feature = "ledger")]
     #[strum_discriminants(strum(
         message = "sign-with-ledger                 - Sign the transaction with Ledger Nano device"
@@ -68,6 +76,11 @@ pub enum SignWith {
     ))]
     /// Prepare transaction as dao proposal
     SubmitAsDaoProposal(self::submit_dao_proposal::DaoProposal),
+    #[strum_discriminants(strum(
+        message = "submit-as-multisig-request       - Convert current transaction to a multisig request"
+    ))]
+    /// Add the transaction as a request to the multisig contract of the signer account (and confirm it)
+    SubmitAsMultisigRequest(self::submit_multisig_request::SubmitMultisigRequest),
 }
 
 #[derive(Debug, EnumDiscriminants, Clone, interactive_clap::InteractiveClap)]
@@ -90,6 +103,11 @@ pub enum Submit {
     ))]
     /// Print the signed transaction to terminal (if you want to send it later)
     Display(self::display::Display),
+    #[strum_discriminants(strum(
+        message = "dry-run          - Check the transaction and estimate its cost without sending it"
+    ))]
+    /// Check the transaction and estimate its cost without sending it
+    DryRun(self::dry_run::DryRun),
 }
 
 #[derive(Debug, Deserialize)]
@@ -36,6 +36,7 @@ impl SendContext {
             .or_else(|| previous_context.network_config.tx_wait_until.clone())
             .map(|s| s.into())
             .unwrap_or(near_primitives::views::TxExecutionStatus::Final);
+        let output_format = previous_context.global_context.output_format;
 
         let storage_message = (previous_context.on_before_sending_transaction_callback)(
             &previous_context.signed_transaction_or_signed_delegate_action,
@@ -51,12 +52,15 @@ impl SendContext {
                     &previous_context.network_config,
                     &signed_transaction,
                     wait_until.clone(),
+                    &previous_context.global_context.config.credentials_home_dir,
                 )? {
                     Some(transaction_info) => {
                         crate::common::print_transaction_status(
                             &transaction_info,
                             &previous_context.network_config,
                             previous_context.global_context.verbosity,
+                            output_format,
+                            previous_context.global_context.decode_with_abi,
                         )?;
 
                         (previous_context.on_after_sending_transaction_callback)(
@@ -67,12 +71,32 @@ impl SendContext {
                     }
                     None => {
                         let tx_hash = signed_transaction.get_hash();
-                        eprintln!("\nTransaction sent successfully (wait level: {wait_until:?}).");
-                        eprintln!("Transaction ID: {tx_hash}");
-                        eprintln!(
-                            "To see the transaction in the transaction explorer, please open this url in your browser:\n{}{}\n",
-                            previous_context.network_config.explorer_transaction_url, tx_hash,
-                        );
+                        if let crate::common::OutputFormat::Json = output_format {
+                            println!(
+                                "{}",
+                                serde_json::to_string_pretty(&serde_json::json!({
+                                    "transaction_hash": tx_hash,
+                                    "signer_id": signed_transaction.transaction.signer_id(),
+                                    "receiver_id": signed_transaction.transaction.receiver_id(),
+                                    "status": "sent",
+                                    "wait_until": wait_until,
+                                    "explorer_url": format!(
+                                        "{}{}",
+                                        previous_context.network_config.explorer_transaction_url,
+                                        tx_hash
+                                    ),
+                                }))?
+                            );
+                        } else {
+                            eprintln!(
+                                "\nTransaction sent successfully (wait level: {wait_until:?})."
+                            );
+                            eprintln!("Transaction ID: {tx_hash}");
+                            eprintln!(
+                                "To see the transaction in the transaction explorer, please open this url in your browser:\n{}{}\n",
+                                previous_context.network_config.explorer_transaction_url, tx_hash,
+                            );
+                        }
                     }
                 }
             }
@@ -132,11 +156,66 @@ impl SendContext {
     }
 }
 
-#[tracing::instrument(name = "Broadcasting transaction via RPC", skip_all)]
+/// Sends the transaction and records it in the local transaction history
+/// (see `near transaction history`).
+///
+/// Delegate actions sent through a meta-transaction relayer are not recorded: the relayer
+/// wraps them into its own transaction, whose hash is not known to the CLI.
 pub fn sending_signed_transaction(
     network_config: &crate::config::NetworkConfig,
     signed_transaction: &near_primitives::transaction::SignedTransaction,
     wait_until: near_primitives::views::TxExecutionStatus,
+    credentials_home_dir: &std::path::Path,
+) -> color_eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
+    crate::commands::batch::record_step_output("transaction_hash", signed_transaction.get_hash());
+
+    let transaction_info =
+        broadcasting_signed_transaction(network_config, signed_transaction, wait_until);
+
+    let (outcome, error) = match &transaction_info {
+        Ok(Some(transaction_info)) => match &transaction_info.status {
+            near_primitives::views::FinalExecutionStatus::Failure(tx_execution_error) => {
+                ("failure", Some(tx_execution_error.to_string()))
+            }
+            near_primitives::views::FinalExecutionStatus::SuccessValue(_) => ("success", None),
+            near_primitives::views::FinalExecutionStatus::NotStarted
+            | near_primitives::views::FinalExecutionStatus::Started => ("sent", None),
+        },
+        Ok(None) => ("sent", None),
+        Err(err) => ("error", Some(err.to_string())),
+    };
+    if let Err(err) = crate::common::append_transaction_history_entry(
+        credentials_home_dir,
+        &crate::common::TransactionHistoryEntry {
+            timestamp: chrono::Utc::now(),
+            network_name: network_config.network_name.clone(),
+            signer_id: signed_transaction.transaction.signer_id().clone(),
+            receiver_id: signed_transaction.transaction.receiver_id().clone(),
+            actions: signed_transaction
+                .transaction
+                .actions()
+                .iter()
+                .map(crate::common::action_summary)
+                .collect(),
+            transaction_hash: signed_transaction.get_hash(),
+            outcome: outcome.to_string(),
+            error,
+        },
+    ) {
+        tracing::warn!(
+            parent: &tracing::Span::none(),
+            "Failed to record the transaction in the transaction history: {err}"
+        );
+    }
+
+    transaction_info
+}
+
+#[tracing::instrument(name = "Broadcasting transaction via RPC", skip_all)]
+fn broadcasting_signed_transaction(
+    network_config: &crate::config::NetworkConfig,
+    signed_transaction: &near_primitives::transaction::SignedTransaction,
+    wait_until: near_primitives::views::TxExecutionStatus,
 ) -> color_eyre::Result<Option<near_primitives::views::FinalExecutionOutcomeView>> {
     tracing::Span::current().pb_set_message(network_config.rpc_url.as_str());
     tracing::info!(target: "near_teach_me", "Broadcasting transaction via RPC {}", network_config.rpc_url.as_str());
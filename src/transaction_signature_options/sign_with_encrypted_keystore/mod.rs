@@ -0,0 +1,329 @@
+use std::str::FromStr;
+
+use color_eyre::eyre::{ContextCompat, WrapErr};
+use inquire::{CustomType, Select};
+use near_primitives::transaction::TransactionV0;
+
+use crate::common::JsonRpcClientExt;
+use crate::common::RpcQueryResponseExt;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::commands::TransactionContext)]
+#[interactive_clap(output_context = SignEncryptedKeystoreContext)]
+pub struct SignEncryptedKeystore {
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    signer_public_key: Option<crate::types::public_key::PublicKey>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    nonce: Option<u64>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    pub block_hash: Option<crate::types::crypto_hash::CryptoHash>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_default_input_arg)]
+    pub block_height: Option<near_primitives::types::BlockHeight>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    nonce_index: Option<u64>,
+    #[interactive_clap(long)]
+    #[interactive_clap(skip_interactive_input)]
+    meta_transaction_valid_for: Option<u64>,
+    #[interactive_clap(subcommand)]
+    submit: super::Submit,
+}
+
+#[derive(Clone)]
+pub struct SignEncryptedKeystoreContext {
+    pub(crate) network_config: crate::config::NetworkConfig,
+    pub(crate) global_context: crate::GlobalContext,
+    pub(crate) signed_transaction_or_signed_delegate_action:
+        super::SignedTransactionOrSignedDelegateAction,
+    pub(crate) on_before_sending_transaction_callback:
+        crate::transaction_signature_options::OnBeforeSendingTransactionCallback,
+    pub(crate) on_after_sending_transaction_callback:
+        crate::transaction_signature_options::OnAfterSendingTransactionCallback,
+    pub(crate) on_sending_delegate_action_callback:
+        Option<crate::transaction_signature_options::OnSendingDelegateActionCallback>,
+}
+
+impl SignEncryptedKeystoreContext {
+    #[tracing::instrument(
+        name = "Signing the transaction with a key saved in the encrypted keystore ...",
+        skip_all
+    )]
+    pub fn from_previous_context(
+        previous_context: crate::commands::TransactionContext,
+        scope: &<SignEncryptedKeystore as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        tracing::info!(target: "near_teach_me", "Signing the transaction with a key saved in the encrypted keystore ...");
+
+        let network_config = previous_context.network_config.clone();
+
+        // A `--nonce-index` means the user wants to sign with a gas key, so match
+        // gas-key credential files (gas keys are excluded from the ordinary
+        // full-access selection); otherwise keep the full-access-only behavior.
+        let want_gas_key = scope.nonce_index.is_some();
+
+        let encrypted_access_keys = crate::common::get_access_keys_from_encrypted_keystore(
+            &previous_context.global_context.config.credentials_home_dir,
+            &network_config.network_name,
+            &previous_context.prepopulated_transaction.signer_id,
+        );
+        let signer_public_keys: Vec<String> = if previous_context.global_context.offline {
+            vec![
+                scope
+                    .signer_public_key
+                    .clone()
+                    .wrap_err(
+                        "Signer public key is required to sign a transaction in offline mode",
+                    )?
+                    .to_string(),
+            ]
+        } else {
+            network_config
+                .json_rpc_client()
+                .blocking_call_view_access_key_list(
+                    &previous_context.prepopulated_transaction.signer_id,
+                    near_primitives::types::Finality::Final.into(),
+                )
+                .wrap_err_with(|| {
+                    format!(
+                        "Failed to fetch access KeyList for {}",
+                        previous_context.prepopulated_transaction.signer_id
+                    )
+                })?
+                .access_key_list_view()?
+                .keys
+                .into_iter()
+                .filter(|access_key_info| {
+                    if want_gas_key {
+                        super::is_gas_key_permission(&access_key_info.access_key.permission)
+                    } else {
+                        matches!(
+                            access_key_info.access_key.permission,
+                            near_primitives::views::AccessKeyPermissionView::FullAccess
+                        )
+                    }
+                })
+                .map(|access_key_info| access_key_info.public_key.to_string())
+                .collect()
+        };
+        let (signer_access_key_file_path, encrypted_keystore_file) = signer_public_keys
+            .iter()
+            .find_map(|public_key| {
+                encrypted_access_keys
+                    .iter()
+                    .find(|(_, encrypted_keystore_file)| {
+                        &encrypted_keystore_file.public_key == public_key
+                    })
+            })
+            .wrap_err_with(|| {
+                format!(
+                    "There are no access keys for account <{}> on network <{}> in the encrypted keystore ({:?}). Import an access key for the account before signing transactions with the encrypted keystore.",
+                    previous_context.prepopulated_transaction.signer_id,
+                    network_config.network_name,
+                    crate::common::get_encrypted_keystore_account_dir(
+                        &previous_context.global_context.config.credentials_home_dir,
+                        &network_config.network_name,
+                        previous_context.prepopulated_transaction.signer_id.as_str(),
+                    )
+                )
+            })?;
+        let passphrase = crate::common::input_encrypted_keystore_passphrase(false)?;
+        let signer_access_key: super::AccountKeyPair = serde_json::from_str(
+            &encrypted_keystore_file.decrypt(&passphrase)?,
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Error reading data from file: {:?}",
+                signer_access_key_file_path
+            )
+        })?;
+
+        let nonce_index = scope
+            .nonce_index
+            .map(super::nonce_index_from_cli)
+            .transpose()?;
+
+        let (nonce_resolution, block_hash, block_height) =
+            if previous_context.global_context.offline {
+                (
+                    super::resolve_offline_nonce(
+                        scope
+                            .nonce
+                            .wrap_err("Nonce is required to sign a transaction in offline mode")?,
+                        nonce_index,
+                    ),
+                    scope
+                        .block_hash
+                        .wrap_err("Block Hash is required to sign a transaction in offline mode")?
+                        .0,
+                    scope.block_height.wrap_err(
+                        "Block Height is required to sign a transaction in offline mode",
+                    )?,
+                )
+            } else {
+                super::resolve_online_nonce(
+                    &network_config.json_rpc_client(),
+                    &previous_context.prepopulated_transaction.signer_id,
+                    &signer_access_key.public_key,
+                    nonce_index,
+                    &network_config.network_name,
+                )?
+            };
+
+        let mut unsigned_transaction = TransactionV0 {
+            public_key: signer_access_key.public_key.clone(),
+            block_hash,
+            nonce: nonce_resolution.nonce(),
+            signer_id: previous_context.prepopulated_transaction.signer_id,
+            receiver_id: previous_context.prepopulated_transaction.receiver_id,
+            actions: previous_context.prepopulated_transaction.actions,
+        };
+
+        (previous_context.on_before_signing_callback)(&mut unsigned_transaction, &network_config)?;
+
+        let unsigned_transaction =
+            super::build_unsigned_transaction(unsigned_transaction, nonce_resolution);
+
+        if previous_context.sign_as_delegate_action {
+            let max_block_height = block_height
+                + scope
+                    .meta_transaction_valid_for
+                    .unwrap_or(super::META_TRANSACTION_VALID_FOR_DEFAULT);
+
+            let signed_delegate_action = super::get_signed_delegate_action(
+                unsigned_transaction,
+                &signer_access_key.public_key,
+                signer_access_key.private_key,
+                max_block_height,
+            )?;
+
+            return Ok(Self {
+                network_config: previous_context.network_config,
+                global_context: previous_context.global_context,
+                signed_transaction_or_signed_delegate_action: signed_delegate_action.into(),
+                on_before_sending_transaction_callback: previous_context
+                    .on_before_sending_transaction_callback,
+                on_after_sending_transaction_callback: previous_context
+                    .on_after_sending_transaction_callback,
+                on_sending_delegate_action_callback: previous_context
+                    .on_sending_delegate_action_callback,
+            });
+        }
+
+        let signature = signer_access_key
+            .private_key
+            .sign(unsigned_transaction.get_hash_and_size().0.as_ref());
+
+        let mut signed_transaction = near_primitives::transaction::SignedTransaction::new(
+            signature.clone(),
+            unsigned_transaction,
+        );
+
+        tracing::info!(
+            parent: &tracing::Span::none(),
+            "Your transaction was signed successfully.{}",
+            crate::common::indent_payload(&format!(
+                "\nPublic key: {}\nSignature:  {}\n ",
+                signer_access_key.public_key,
+                signature
+            ))
+        );
+
+        (previous_context.on_after_signing_callback)(
+            &mut signed_transaction,
+            &previous_context.network_config,
+        )?;
+
+        Ok(Self {
+            network_config: previous_context.network_config,
+            global_context: previous_context.global_context,
+            signed_transaction_or_signed_delegate_action: signed_transaction.into(),
+            on_before_sending_transaction_callback: previous_context
+                .on_before_sending_transaction_callback,
+            on_after_sending_transaction_callback: previous_context
+                .on_after_sending_transaction_callback,
+            on_sending_delegate_action_callback: previous_context
+                .on_sending_delegate_action_callback,
+        })
+    }
+}
+
+impl From<SignEncryptedKeystoreContext> for super::SubmitContext {
+    fn from(item: SignEncryptedKeystoreContext) -> Self {
+        Self {
+            network_config: item.network_config,
+            global_context: item.global_context,
+            signed_transaction_or_signed_delegate_action: item
+                .signed_transaction_or_signed_delegate_action,
+            on_before_sending_transaction_callback: item.on_before_sending_transaction_callback,
+            on_after_sending_transaction_callback: item.on_after_sending_transaction_callback,
+            on_sending_delegate_action_callback: item.on_sending_delegate_action_callback,
+        }
+    }
+}
+
+impl SignEncryptedKeystore {
+    fn input_signer_public_key(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::public_key::PublicKey>> {
+        if context.global_context.offline {
+            let key_list = crate::common::get_access_keys_from_encrypted_keystore(
+                &context.global_context.config.credentials_home_dir,
+                &context.network_config.network_name,
+                &context.prepopulated_transaction.signer_id,
+            )
+            .into_iter()
+            .map(|(_, encrypted_keystore_file)| encrypted_keystore_file.public_key)
+            .collect::<Vec<_>>();
+
+            let selected_input = Select::new("Choose a public key:", key_list).prompt()?;
+
+            return Ok(Some(crate::types::public_key::PublicKey::from_str(
+                &selected_input,
+            )?));
+        }
+        Ok(None)
+    }
+
+    fn input_nonce(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<u64>> {
+        if context.global_context.offline {
+            return Ok(Some(
+                CustomType::<u64>::new("Enter a nonce for the access key:").prompt()?,
+            ));
+        }
+        Ok(None)
+    }
+
+    fn input_block_hash(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<crate::types::crypto_hash::CryptoHash>> {
+        if context.global_context.offline {
+            return Ok(Some(
+                CustomType::<crate::types::crypto_hash::CryptoHash>::new(
+                    "Enter recent block hash:",
+                )
+                .prompt()?,
+            ));
+        }
+        Ok(None)
+    }
+
+    fn input_block_height(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<near_primitives::types::BlockHeight>> {
+        if context.global_context.offline {
+            return Ok(Some(
+                CustomType::<near_primitives::types::BlockHeight>::new(
+                    "Enter recent block height:",
+                )
+                .prompt()?,
+            ));
+        }
+        Ok(None)
+    }
+}
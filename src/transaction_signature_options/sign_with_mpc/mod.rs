@@ -64,7 +64,7 @@ impl SignMpc {
         context: &crate::commands::TransactionContext,
     ) -> color_eyre::eyre::Result<Option<crate::types::account_id::AccountId>> {
         crate::common::input_signer_account_id_from_used_account_list(
-            &context.global_context.config.credentials_home_dir,
+            &context.global_context.config,
             "What is the Admin AccountId?",
         )
     }
@@ -516,6 +516,7 @@ impl From<DepositContext> for crate::commands::TransactionContext {
         let original_transaction_for_signing = item.original_payload_transaction.clone();
         let original_transaction_for_after_send = item.original_payload_transaction.clone();
         let global_context_for_after_send = item.global_context.clone();
+        let credentials_home_dir = item.global_context.config.credentials_home_dir.clone();
 
         let on_after_signing_callback: crate::commands::OnAfterSigningCallback =
             std::sync::Arc::new({
@@ -542,6 +543,7 @@ impl From<DepositContext> for crate::commands::TransactionContext {
                             network_config,
                             &sign_request_tx,
                             near_primitives::views::TxExecutionStatus::Final,
+                            &credentials_home_dir,
                         ) {
                             Ok(Some(outcome_view)) => outcome_view,
                             Ok(None) => {
@@ -658,9 +660,48 @@ pub fn dao_sign_with_mpc_after_send_flow(
     unsigned_mpc_transaction: &near_primitives::transaction::Transaction,
     original_sign_request: &mpc_sign_request::MpcSignRequest,
 ) -> color_eyre::eyre::Result<()> {
+    let Some(sign_result) =
+        prompt_for_dao_mpc_sign_result(network_config, outcome_view, original_sign_request)?
+    else {
+        return Ok(());
+    };
+    let signature: near_crypto::Signature = sign_result.into();
+    let signed_transaction = near_primitives::transaction::SignedTransaction::new(
+        signature,
+        unsigned_mpc_transaction.clone(),
+    );
+
+    let submit_context = super::SubmitContext {
+        network_config: network_config.clone(),
+        global_context: global_context.clone(),
+        signed_transaction_or_signed_delegate_action:
+            crate::transaction_signature_options::SignedTransactionOrSignedDelegateAction::SignedTransaction(
+                signed_transaction
+            ),
+        on_before_sending_transaction_callback:
+            std::sync::Arc::new(
+                |_signed_transaction, _network_config| Ok(String::new()),
+        ),
+        on_after_sending_transaction_callback:
+            std::sync::Arc::new(
+                |_outcome_view, _network_config| Ok(()),
+        ),
+        on_sending_delegate_action_callback: None,
+    };
+
+    prompt_and_submit(submit_context)
+}
+
+/// Asks for the executed DAO proposal with the MPC sign request and returns the MPC contract
+/// response; `None` if the user cancels.
+pub fn prompt_for_dao_mpc_sign_result(
+    network_config: &crate::config::NetworkConfig,
+    outcome_view: &near_primitives::views::FinalExecutionOutcomeView,
+    original_sign_request: &mpc_sign_request::MpcSignRequest,
+) -> color_eyre::eyre::Result<Option<mpc_sign_result::SignResult>> {
     use tracing_indicatif::suspend_tracing_indicatif;
 
-    let signed_transaction = loop {
+    loop {
         let transaction_hash = match suspend_tracing_indicatif(|| {
             inquire::CustomType::new("Enter the transaction hash of the executed DAO proposal:")
                 .prompt()
@@ -670,7 +711,7 @@ pub fn dao_sign_with_mpc_after_send_flow(
                 inquire::error::InquireError::OperationCanceled
                 | inquire::error::InquireError::OperationInterrupted,
             ) => {
-                return Ok(());
+                return Ok(None);
             }
             Err(err) => {
                 eprintln!("{}", format!("{err}").red());
@@ -678,52 +719,22 @@ pub fn dao_sign_with_mpc_after_send_flow(
             }
         };
 
-        let signed_transaction = match fetch_mpc_contract_response_from_dao_tx(
+        match fetch_mpc_contract_response_from_dao_tx(
             network_config,
             original_sign_request,
             transaction_hash,
             "near".parse()?,
             outcome_view.transaction.receiver_id.clone(),
         ) {
-            Ok(sign_result) => {
-                let signature: near_crypto::Signature = sign_result.into();
-
-                near_primitives::transaction::SignedTransaction::new(
-                    signature,
-                    unsigned_mpc_transaction.clone(),
-                )
-            }
+            Ok(sign_result) => return Ok(Some(sign_result)),
             Err(err) => {
                 eprintln!(
                     "{}",
                     format!("Failed to get signature from MPC contract:\n   {err}").red()
                 );
-                continue;
             }
-        };
-
-        break signed_transaction;
-    };
-
-    let submit_context = super::SubmitContext {
-        network_config: network_config.clone(),
-        global_context: global_context.clone(),
-        signed_transaction_or_signed_delegate_action:
-            crate::transaction_signature_options::SignedTransactionOrSignedDelegateAction::SignedTransaction(
-                signed_transaction
-            ),
-        on_before_sending_transaction_callback:
-            std::sync::Arc::new(
-                |_signed_transaction, _network_config| Ok(String::new()),
-        ),
-        on_after_sending_transaction_callback:
-            std::sync::Arc::new(
-                |_outcome_view, _network_config| Ok(()),
-        ),
-        on_sending_delegate_action_callback: None,
-    };
-
-    prompt_and_submit(submit_context)
+        }
+    }
 }
 
 #[tracing::instrument(name = "Fetching executed DAO proposal ...", skip_all)]
@@ -864,6 +875,12 @@ fn prompt_and_submit(submit_context: super::SubmitContext) -> color_eyre::eyre::
                 &super::display::InteractiveClapContextScopeForDisplay {},
             )?;
         }
+        super::SubmitDiscriminants::DryRun => {
+            super::dry_run::DryRunContext::from_previous_context(
+                submit_context,
+                &super::dry_run::InteractiveClapContextScopeForDryRun {},
+            )?;
+        }
         super::SubmitDiscriminants::SaveToFile => {
             let file_path: crate::types::path_buf::PathBuf = loop {
                 match tracing_indicatif::suspend_tracing_indicatif(|| {
@@ -0,0 +1,135 @@
+use inquire::CustomType;
+
+pub mod multisig_request;
+pub mod multisig_sign_with;
+
+/// The gas needed by the multisig contract itself to add and confirm a request.
+const MULTISIG_OVERHEAD_TGAS: u64 = 50;
+
+#[derive(Debug, Clone, interactive_clap::InteractiveClap)]
+#[interactive_clap(input_context = crate::commands::TransactionContext)]
+#[interactive_clap(output_context = SubmitMultisigRequestContext)]
+pub struct SubmitMultisigRequest {
+    #[interactive_clap(skip_default_input_arg)]
+    /// Enter gas amount for the multisig request:
+    prepaid_gas: crate::common::NearGas,
+    #[interactive_clap(subcommand)]
+    transaction_signature_options: multisig_sign_with::MultisigSignWith,
+}
+
+#[derive(Clone)]
+pub struct SubmitMultisigRequestContext(crate::commands::TransactionContext);
+
+impl SubmitMultisigRequestContext {
+    pub fn from_previous_context(
+        previous_context: crate::commands::TransactionContext,
+        scope: &<SubmitMultisigRequest as interactive_clap::ToInteractiveClapContextScope>::InteractiveClapContextScope,
+    ) -> color_eyre::eyre::Result<Self> {
+        let request = multisig_request::MultisigRequest::try_from(
+            &previous_context.prepopulated_transaction,
+        )?;
+        // The multisig contract is called by the multisig account itself
+        // with one of the member keys.
+        let multisig_account_id = previous_context.prepopulated_transaction.signer_id.clone();
+        let new_prepopulated_transaction = crate::commands::PrepopulatedTransaction {
+            signer_id: multisig_account_id.clone(),
+            receiver_id: multisig_account_id.clone(),
+            actions: vec![near_primitives::transaction::Action::FunctionCall(
+                Box::new(near_primitives::transaction::FunctionCallAction {
+                    method_name: "add_request_and_confirm".to_string(),
+                    args: serde_json::to_vec(&serde_json::json!({ "request": request }))?,
+                    gas: near_primitives::gas::Gas::from_gas(scope.prepaid_gas.as_gas()),
+                    deposit: near_token::NearToken::from_yoctonear(0),
+                }),
+            )],
+        };
+
+        tracing::info!(
+            "{}{}",
+            "Unsigned multisig request",
+            crate::common::indent_payload(&crate::common::print_unsigned_transaction(
+                &new_prepopulated_transaction,
+            ))
+        );
+
+        let on_after_sending_transaction_callback: crate::transaction_signature_options::OnAfterSendingTransactionCallback = std::sync::Arc::new({
+            let verbosity = previous_context.global_context.verbosity;
+
+            move |outcome_view, _network_config| {
+                if let near_primitives::views::FinalExecutionStatus::SuccessValue(result) = &outcome_view.status
+                    && let Ok(request_id) = serde_json::from_slice::<multisig_request::RequestId>(result)
+                    && let crate::Verbosity::Interactive | crate::Verbosity::TeachMe = verbosity {
+                        tracing_indicatif::suspend_tracing_indicatif(|| {
+                            eprintln!(
+                                "Request #{request_id} was added to the multisig account <{multisig_account_id}> and confirmed with the signer key."
+                            );
+                        });
+                    }
+                Ok(())
+            }
+        });
+
+        Ok(Self(crate::commands::TransactionContext {
+            global_context: previous_context.global_context,
+            network_config: previous_context.network_config,
+            prepopulated_transaction: new_prepopulated_transaction,
+            on_before_signing_callback: std::sync::Arc::new(
+                |_prepopulated_unsigned_transaction, _network_config| Ok(()),
+            ),
+            on_after_signing_callback: std::sync::Arc::new(
+                |_signed_transaction, _network_config| Ok(()),
+            ),
+            on_before_sending_transaction_callback: std::sync::Arc::new(
+                |_signed_transaction, _network_config| Ok(String::new()),
+            ),
+            on_after_sending_transaction_callback,
+            on_sending_delegate_action_callback: previous_context
+                .on_sending_delegate_action_callback,
+            sign_as_delegate_action: previous_context.sign_as_delegate_action,
+        }))
+    }
+}
+
+impl From<SubmitMultisigRequestContext> for crate::commands::TransactionContext {
+    fn from(item: SubmitMultisigRequestContext) -> Self {
+        item.0
+    }
+}
+
+impl SubmitMultisigRequest {
+    pub fn input_prepaid_gas(
+        context: &crate::commands::TransactionContext,
+    ) -> color_eyre::eyre::Result<Option<crate::common::NearGas>> {
+        // The function calls of the request get their gas from the call that executes it.
+        let request_gas = context
+            .prepopulated_transaction
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                near_primitives::transaction::Action::FunctionCall(function_call_action) => {
+                    Some(function_call_action.gas.as_gas())
+                }
+                _ => None,
+            })
+            .sum::<u64>();
+        let suggested_gas = near_gas::NearGas::from_gas(request_gas)
+            .saturating_add(near_gas::NearGas::from_tgas(MULTISIG_OVERHEAD_TGAS));
+        Ok(Some(
+            CustomType::new("What is the gas limit for adding the multisig request?")
+                .with_starting_input(&format!("{} Tgas", suggested_gas.as_tgas()))
+                .with_validator(move |gas: &crate::common::NearGas| {
+                    if gas > &near_gas::NearGas::from_tgas(1000) {
+                        Ok(inquire::validator::Validation::Invalid(
+                            inquire::validator::ErrorMessage::Custom(
+                                "You need to enter a value of no more than 1000 TeraGas"
+                                    .to_string(),
+                            ),
+                        ))
+                    } else {
+                        Ok(inquire::validator::Validation::Valid)
+                    }
+                })
+                .prompt()?,
+        ))
+    }
+}
@@ -0,0 +1,241 @@
+use color_eyre::eyre::eyre;
+use near_primitives::action::Action;
+use serde::{Deserialize, Serialize};
+use serde_with::{DisplayFromStr, base64::Base64, serde_as};
+
+/// The methods of the multisig contract that the member keys are allowed to call.
+pub const MULTISIG_MEMBER_METHOD_NAMES: [&str; 4] = [
+    "add_request",
+    "add_request_and_confirm",
+    "confirm",
+    "delete_request",
+];
+
+pub type RequestId = u32;
+
+/// A request of the multisig contract (<https://github.com/near/core-contracts/tree/master/multisig>)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MultisigRequest {
+    pub receiver_id: near_primitives::types::AccountId,
+    pub actions: Vec<MultisigRequestAction>,
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum MultisigRequestAction {
+    Transfer {
+        #[serde_as(as = "DisplayFromStr")]
+        amount: u128,
+    },
+    CreateAccount,
+    DeployContract {
+        #[serde_as(as = "Base64")]
+        code: Vec<u8>,
+    },
+    AddKey {
+        public_key: near_crypto::PublicKey,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        permission: Option<FunctionCallPermission>,
+    },
+    DeleteKey {
+        public_key: near_crypto::PublicKey,
+    },
+    FunctionCall {
+        method_name: String,
+        #[serde_as(as = "Base64")]
+        args: Vec<u8>,
+        #[serde_as(as = "DisplayFromStr")]
+        deposit: u128,
+        #[serde_as(as = "DisplayFromStr")]
+        gas: u64,
+    },
+    SetNumConfirmations {
+        num_confirmations: u32,
+    },
+    SetActiveRequestsLimit {
+        active_requests_limit: u32,
+    },
+}
+
+#[serde_as]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FunctionCallPermission {
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    allowance: Option<u128>,
+    receiver_id: near_primitives::types::AccountId,
+    method_names: Vec<String>,
+}
+
+impl TryFrom<&crate::commands::PrepopulatedTransaction> for MultisigRequest {
+    type Error = color_eyre::eyre::Error;
+
+    fn try_from(
+        transaction: &crate::commands::PrepopulatedTransaction,
+    ) -> Result<Self, Self::Error> {
+        if transaction.actions.is_empty() {
+            return Err(eyre!("No actions were found in transaction!"));
+        }
+        Ok(Self {
+            receiver_id: transaction.receiver_id.clone(),
+            actions: transaction
+                .actions
+                .iter()
+                .map(MultisigRequestAction::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<&Action> for MultisigRequestAction {
+    type Error = color_eyre::eyre::Error;
+
+    fn try_from(action: &Action) -> Result<Self, Self::Error> {
+        match action {
+            Action::Transfer(transfer_action) => Ok(Self::Transfer {
+                amount: transfer_action.deposit.as_yoctonear(),
+            }),
+            Action::CreateAccount(_) => Ok(Self::CreateAccount),
+            Action::DeployContract(deploy_contract_action) => Ok(Self::DeployContract {
+                code: deploy_contract_action.code.clone(),
+            }),
+            Action::AddKey(add_key_action) => {
+                let permission = match &add_key_action.access_key.permission {
+                    near_primitives::account::AccessKeyPermission::FullAccess => None,
+                    near_primitives::account::AccessKeyPermission::FunctionCall(permission) => {
+                        Some(FunctionCallPermission {
+                            allowance: permission
+                                .allowance
+                                .map(|allowance| allowance.as_yoctonear()),
+                            receiver_id: permission.receiver_id.parse()?,
+                            method_names: permission.method_names.clone(),
+                        })
+                    }
+                    _ => {
+                        return Err(eyre!("Gas keys cannot be added with a multisig request"));
+                    }
+                };
+                Ok(Self::AddKey {
+                    public_key: add_key_action.public_key.clone(),
+                    permission,
+                })
+            }
+            Action::DeleteKey(delete_key_action) => Ok(Self::DeleteKey {
+                public_key: delete_key_action.public_key.clone(),
+            }),
+            Action::FunctionCall(function_call_action) => Ok(Self::FunctionCall {
+                method_name: function_call_action.method_name.clone(),
+                args: function_call_action.args.clone(),
+                deposit: function_call_action.deposit.as_yoctonear(),
+                gas: function_call_action.gas.as_gas(),
+            }),
+            _action => Err(eyre!(
+                "Passed action type is not supported for multisig requests (supported: transfer, create-account, deploy-contract, add-key, delete-key, function-call)"
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for MultisigRequestAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Transfer { amount } => write!(
+                f,
+                "transfer {}",
+                near_token::NearToken::from_yoctonear(*amount)
+            ),
+            Self::CreateAccount => write!(f, "create account"),
+            Self::DeployContract { code } => {
+                write!(
+                    f,
+                    "deploy contract ({})",
+                    bytesize::ByteSize(code.len() as u64)
+                )
+            }
+            Self::AddKey {
+                public_key,
+                permission: None,
+            } => write!(f, "add full access key {public_key}"),
+            Self::AddKey {
+                public_key,
+                permission: Some(permission),
+            } => write!(
+                f,
+                "add function call access key {public_key} for <{}>",
+                permission.receiver_id
+            ),
+            Self::DeleteKey { public_key } => write!(f, "delete key {public_key}"),
+            Self::FunctionCall {
+                method_name,
+                deposit,
+                ..
+            } => write!(
+                f,
+                "call {method_name} (deposit {})",
+                near_token::NearToken::from_yoctonear(*deposit)
+            ),
+            Self::SetNumConfirmations { num_confirmations } => {
+                write!(f, "set number of confirmations to {num_confirmations}")
+            }
+            Self::SetActiveRequestsLimit {
+                active_requests_limit,
+            } => write!(f, "set active requests limit to {active_requests_limit}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serializes_in_the_multisig_contract_format() {
+        let transaction = crate::commands::PrepopulatedTransaction {
+            signer_id: "multisig.testnet".parse().unwrap(),
+            receiver_id: "bob.testnet".parse().unwrap(),
+            actions: vec![
+                Action::Transfer(near_primitives::transaction::TransferAction {
+                    deposit: near_token::NearToken::from_near(1),
+                }),
+                Action::FunctionCall(Box::new(near_primitives::transaction::FunctionCallAction {
+                    method_name: "ping".to_string(),
+                    args: b"{}".to_vec(),
+                    gas: near_primitives::gas::Gas::from_teragas(10),
+                    deposit: near_token::NearToken::from_yoctonear(0),
+                })),
+            ],
+        };
+        let request = MultisigRequest::try_from(&transaction).unwrap();
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            serde_json::json!({
+                "receiver_id": "bob.testnet",
+                "actions": [
+                    {"type": "Transfer", "amount": "1000000000000000000000000"},
+                    {
+                        "type": "FunctionCall",
+                        "method_name": "ping",
+                        "args": "e30=",
+                        "deposit": "0",
+                        "gas": "10000000000000"
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_unsupported_actions() {
+        let transaction = crate::commands::PrepopulatedTransaction {
+            signer_id: "multisig.testnet".parse().unwrap(),
+            receiver_id: "multisig.testnet".parse().unwrap(),
+            actions: vec![Action::DeleteAccount(
+                near_primitives::transaction::DeleteAccountAction {
+                    beneficiary_id: "bob.testnet".parse().unwrap(),
+                },
+            )],
+        };
+        assert!(MultisigRequest::try_from(&transaction).is_err());
+    }
+}
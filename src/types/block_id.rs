@@ -0,0 +1,36 @@
+/// A block height or a block hash.
+#[derive(Debug, Clone)]
+pub struct BlockId(pub near_primitives::types::BlockId);
+
+impl From<BlockId> for near_primitives::types::BlockId {
+    fn from(item: BlockId) -> Self {
+        item.0
+    }
+}
+
+impl std::fmt::Display for BlockId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match &self.0 {
+            near_primitives::types::BlockId::Height(height) => height.fmt(f),
+            near_primitives::types::BlockId::Hash(hash) => hash.fmt(f),
+        }
+    }
+}
+
+impl std::str::FromStr for BlockId {
+    type Err = color_eyre::eyre::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Ok(height) = s.parse::<near_primitives::types::BlockHeight>() {
+            return Ok(Self(near_primitives::types::BlockId::Height(height)));
+        }
+        let hash = near_primitives::hash::CryptoHash::from_str(s).map_err(|_| {
+            color_eyre::eyre::eyre!("\"{s}\" is neither a block height nor a block hash")
+        })?;
+        Ok(Self(near_primitives::types::BlockId::Hash(hash)))
+    }
+}
+
+impl interactive_clap::ToCli for BlockId {
+    type CliVariant = BlockId;
+}
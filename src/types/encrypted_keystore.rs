@@ -0,0 +1,235 @@
+use color_eyre::eyre::WrapErr;
+
+/// The environment variable to take the keystore passphrase from (for unattended use, e.g. on CI).
+pub const PASSPHRASE_ENV_VAR: &str = "NEAR_KEYSTORE_PASSPHRASE";
+
+const SCRYPT_LOG_N: u8 = 15;
+const SCRYPT_R: u32 = 8;
+const SCRYPT_P: u32 = 1;
+/// The limits for the scrypt parameters read from a keystore file, so that a corrupted or
+/// crafted file can't make the key derivation overflow or exhaust the memory.
+const SCRYPT_MAX_LOG_N: u8 = 20;
+const SCRYPT_MAX_R: u32 = 32;
+const SCRYPT_MAX_P: u32 = 16;
+const SALT_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+
+/// An access key file of the encrypted keystore:
+/// `<credentials_home_dir>/encrypted-keystore/<network>/<account_id>/<public_key>.json`.
+///
+/// The key pair properties (the same JSON as in the legacy keychain) are encrypted with
+/// AES-256-GCM using a key derived from the passphrase with scrypt. The account ID and the
+/// public key are stored in plaintext (to find the key without the passphrase) and are
+/// authenticated as additional data.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct EncryptedKeystoreFile {
+    pub version: u32,
+    pub account_id: near_primitives::types::AccountId,
+    pub public_key: String,
+    pub kdf: KdfParams,
+    pub cipher: String,
+    /// Base64
+    pub nonce: String,
+    /// Base64
+    pub ciphertext: String,
+    /// Base64
+    pub tag: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "name", rename_all = "snake_case")]
+pub enum KdfParams {
+    Scrypt {
+        log_n: u8,
+        r: u32,
+        p: u32,
+        /// Base64
+        salt: String,
+    },
+}
+
+impl EncryptedKeystoreFile {
+    pub fn encrypt(
+        account_id: &near_primitives::types::AccountId,
+        public_key: &str,
+        key_pair_properties_buf: &str,
+        passphrase: &str,
+    ) -> color_eyre::eyre::Result<Self> {
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        openssl::rand::rand_bytes(&mut salt)?;
+        openssl::rand::rand_bytes(&mut nonce)?;
+
+        let kdf = KdfParams::Scrypt {
+            log_n: SCRYPT_LOG_N,
+            r: SCRYPT_R,
+            p: SCRYPT_P,
+            salt: near_primitives::serialize::to_base64(&salt),
+        };
+        let key = kdf.derive_key(passphrase)?;
+        let mut tag = [0u8; TAG_LEN];
+        let ciphertext = openssl::symm::encrypt_aead(
+            openssl::symm::Cipher::aes_256_gcm(),
+            &key,
+            Some(&nonce),
+            &additional_data(account_id, public_key),
+            key_pair_properties_buf.as_bytes(),
+            &mut tag,
+        )
+        .wrap_err("Failed to encrypt the access key")?;
+
+        Ok(Self {
+            version: 1,
+            account_id: account_id.clone(),
+            public_key: public_key.to_string(),
+            kdf,
+            cipher: "aes-256-gcm".to_string(),
+            nonce: near_primitives::serialize::to_base64(&nonce),
+            ciphertext: near_primitives::serialize::to_base64(&ciphertext),
+            tag: near_primitives::serialize::to_base64(&tag),
+        })
+    }
+
+    /// Returns the key pair properties (the same JSON as in the legacy keychain).
+    pub fn decrypt(&self, passphrase: &str) -> color_eyre::eyre::Result<String> {
+        if self.version != 1 || self.cipher != "aes-256-gcm" {
+            return Err(color_eyre::eyre::eyre!(
+                "Unsupported encrypted keystore file (version {}, cipher {})",
+                self.version,
+                self.cipher
+            ));
+        }
+        let key = self.kdf.derive_key(passphrase)?;
+        let key_pair_properties_buf = openssl::symm::decrypt_aead(
+            openssl::symm::Cipher::aes_256_gcm(),
+            &key,
+            Some(&from_base64(&self.nonce)?),
+            &additional_data(&self.account_id, &self.public_key),
+            &from_base64(&self.ciphertext)?,
+            &from_base64(&self.tag)?,
+        )
+        .map_err(|_| {
+            color_eyre::eyre::eyre!(
+                "Failed to decrypt the access key <{}>: wrong passphrase or the file is corrupted",
+                self.public_key
+            )
+        })?;
+        String::from_utf8(key_pair_properties_buf)
+            .wrap_err("The decrypted access key is not a valid UTF-8 string")
+    }
+}
+
+impl KdfParams {
+    fn derive_key(&self, passphrase: &str) -> color_eyre::eyre::Result<[u8; KEY_LEN]> {
+        match self {
+            Self::Scrypt { log_n, r, p, salt } => {
+                if *log_n == 0 || *log_n > SCRYPT_MAX_LOG_N {
+                    return Err(color_eyre::eyre::eyre!(
+                        "Invalid scrypt parameter log_n {log_n}: expected 1 to {SCRYPT_MAX_LOG_N}"
+                    ));
+                }
+                if *r == 0 || *r > SCRYPT_MAX_R {
+                    return Err(color_eyre::eyre::eyre!(
+                        "Invalid scrypt parameter r {r}: expected 1 to {SCRYPT_MAX_R}"
+                    ));
+                }
+                if *p == 0 || *p > SCRYPT_MAX_P {
+                    return Err(color_eyre::eyre::eyre!(
+                        "Invalid scrypt parameter p {p}: expected 1 to {SCRYPT_MAX_P}"
+                    ));
+                }
+                let n = 1u64 << log_n;
+                let (r, p) = (u64::from(*r), u64::from(*p));
+                // scrypt needs 128 * N * r bytes; leave some headroom
+                let max_memory = 256u64
+                    .checked_mul(n)
+                    .and_then(|memory| memory.checked_mul(r))
+                    .and_then(|memory| memory.checked_mul(p))
+                    .ok_or_else(|| {
+                        color_eyre::eyre::eyre!("The scrypt parameters need too much memory")
+                    })?;
+                let mut key = [0u8; KEY_LEN];
+                openssl::pkcs5::scrypt(
+                    passphrase.as_bytes(),
+                    &from_base64(salt)?,
+                    n,
+                    r,
+                    p,
+                    max_memory,
+                    &mut key,
+                )
+                .wrap_err("Failed to derive the encryption key from the passphrase")?;
+                Ok(key)
+            }
+        }
+    }
+}
+
+fn additional_data(account_id: &near_primitives::types::AccountId, public_key: &str) -> Vec<u8> {
+    format!("{account_id}:{public_key}").into_bytes()
+}
+
+fn from_base64(encoded: &str) -> color_eyre::eyre::Result<Vec<u8>> {
+    near_primitives::serialize::from_base64(encoded)
+        .wrap_err("The encrypted keystore file contains an invalid base64 value")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decrypts_with_the_right_passphrase_only() {
+        let account_id: near_primitives::types::AccountId = "alice.testnet".parse().unwrap();
+        let key_pair_properties_buf = r#"{"public_key":"ed25519:abc","private_key":"ed25519:def"}"#;
+        let keystore_file = EncryptedKeystoreFile::encrypt(
+            &account_id,
+            "ed25519:abc",
+            key_pair_properties_buf,
+            "correct horse",
+        )
+        .unwrap();
+
+        assert_eq!(
+            keystore_file.decrypt("correct horse").unwrap(),
+            key_pair_properties_buf
+        );
+        assert!(keystore_file.decrypt("battery staple").is_err());
+
+        let mut tampered_keystore_file = keystore_file.clone();
+        tampered_keystore_file.account_id = "mallory.testnet".parse().unwrap();
+        assert!(tampered_keystore_file.decrypt("correct horse").is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_kdf_params() {
+        for (log_n, r, p) in [
+            (64, 8, 1),
+            (SCRYPT_MAX_LOG_N + 1, 8, 1),
+            (15, u32::MAX, 1),
+            (15, 8, u32::MAX),
+            (15, 0, 1),
+        ] {
+            let kdf = KdfParams::Scrypt {
+                log_n,
+                r,
+                p,
+                salt: near_primitives::serialize::to_base64(&[0u8; 16]),
+            };
+            assert!(kdf.derive_key("correct horse").is_err());
+        }
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let account_id: near_primitives::types::AccountId = "alice.testnet".parse().unwrap();
+        let keystore_file =
+            EncryptedKeystoreFile::encrypt(&account_id, "ed25519:abc", "secret", "passphrase")
+                .unwrap();
+        let keystore_file: EncryptedKeystoreFile =
+            serde_json::from_str(&serde_json::to_string(&keystore_file).unwrap()).unwrap();
+        assert_eq!(keystore_file.decrypt("passphrase").unwrap(), "secret");
+    }
+}
@@ -120,26 +120,40 @@ pub fn get_account_ft_inventory(
     }
 }
 
+/// Serves a single NearBlocks API response with the body.
+#[cfg(test)]
+pub(crate) fn spawn_mock_nearblocks_server(
+    body: &'static str,
+) -> (String, std::thread::JoinHandle<()>) {
+    use std::io::{Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let handle = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut buffer = [0u8; 1024];
+        let _ = stream.read(&mut buffer).unwrap();
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+    });
+
+    (format!("http://127.0.0.1:{}/", addr.port()), handle)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::io::{Read, Write};
-    use std::net::TcpListener;
     use std::str::FromStr;
-    use std::thread;
 
     use crate::config::NetworkConfig;
     use crate::types::account_id::AccountId;
 
-    fn spawn_mock_nearblocks_server() -> (String, thread::JoinHandle<()>) {
-        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
-        let addr = listener.local_addr().unwrap();
-        let handle = thread::spawn(move || {
-            let (mut stream, _) = listener.accept().unwrap();
-            let mut buffer = [0u8; 1024];
-            let _ = stream.read(&mut buffer).unwrap();
-
-            let body = r#"{"inventory": {
+    const INVENTORY_RESPONSE: &str = r#"{"inventory": {
                 "fts": [
                 {
                     "contract": "wrap.near",
@@ -202,16 +216,6 @@ mod tests {
                 ]
             }
             }"#;
-            let response = format!(
-                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
-                body.len(),
-                body
-            );
-            stream.write_all(response.as_bytes()).unwrap();
-        });
-
-        (format!("http://127.0.0.1:{}/", addr.port()), handle)
-    }
 
     fn network_config(base_url: url::Url) -> NetworkConfig {
         NetworkConfig {
@@ -230,6 +234,7 @@ mod tests {
             mpc_contract_account_id: None,
             tx_wait_until: None,
             nearblocks_url: Some(base_url),
+            rpc_fallback_endpoints: Vec::new(),
         }
     }
 
@@ -258,7 +263,7 @@ mod tests {
     }
     #[test]
     fn get_account_ft_nft_token_inventory_parses_mocked_response() {
-        let (base_url, server_handle) = spawn_mock_nearblocks_server();
+        let (base_url, server_handle) = super::spawn_mock_nearblocks_server(INVENTORY_RESPONSE);
 
         let network_config = network_config(base_url.parse().unwrap());
         let account_id = near_primitives::types::AccountId::from_str("test.near").unwrap();
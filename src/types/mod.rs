@@ -1,9 +1,11 @@
 pub mod account_id;
 pub mod api_key;
 pub mod base64_bytes;
+pub mod block_id;
 #[cfg(feature = "verify_contract")]
 pub mod contract_properties;
 pub mod crypto_hash;
+pub mod encrypted_keystore;
 pub mod file_bytes;
 pub mod ft_inventory;
 pub mod ft_properties;
@@ -21,6 +23,7 @@ pub mod signed_delegate_action;
 pub mod signed_transaction;
 pub mod slip10;
 pub mod transaction;
+pub mod transaction_cost;
 pub mod tx_execution_status;
 pub mod url;
 pub mod vec_string;
@@ -2,6 +2,9 @@ use near_jsonrpc_client::methods::EXPERIMENTAL_protocol_config::RpcProtocolConfi
 
 #[derive(Debug, serde::Deserialize)]
 pub struct PartialProtocolConfigView {
+    #[serde(default)]
+    pub chain_id: Option<String>,
+    pub protocol_version: near_primitives::types::ProtocolVersion,
     pub runtime_config: PartialRuntimeConfigView,
 }
 
@@ -12,6 +15,18 @@ pub struct PartialRuntimeConfigView {
     /// Amount of yN per byte required to have on the account.  See
     /// <https://nomicon.io/Economics/Economic#state-stake> for details.
     pub storage_amount_per_byte: near_token::NearToken,
+    pub wasm_config: PartialVMConfigView,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PartialVMConfigView {
+    pub limit_config: PartialLimitConfigView,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct PartialLimitConfigView {
+    /// Max contract size in bytes.
+    pub max_contract_size: u64,
 }
 
 pub async fn get_partial_protocol_config(
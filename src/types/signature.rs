@@ -21,3 +21,7 @@ impl From<Signature> for near_crypto::Signature {
         item.0
     }
 }
+
+impl interactive_clap::ToCli for Signature {
+    type CliVariant = Signature;
+}
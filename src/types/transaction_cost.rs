@@ -0,0 +1,696 @@
+use color_eyre::eyre::Context;
+use near_parameters::{ActionCosts, RuntimeFeesConfig};
+use near_primitives::types::Gas;
+
+use crate::common::JsonRpcClientExt;
+
+/// The estimated cost of a transaction: the gas fees of the runtime fee config, the gas
+/// attached to function calls, the attached deposits and the storage staking.
+#[derive(Debug, Clone)]
+pub struct TransactionCost {
+    pub gas_price: near_token::NearToken,
+    pub storage_amount_per_byte: near_token::NearToken,
+    /// Whether the storage is staked on the signer account (the signer is the receiver).
+    pub is_storage_staked_by_signer: bool,
+    /// The fees of creating and executing the action receipt itself.
+    pub receipt_fees: Gas,
+    pub actions: Vec<ActionCost>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ActionCost {
+    pub summary: String,
+    /// The send and execution fees of the action; `None` if this action kind is not estimated.
+    pub fees: Option<Gas>,
+    /// The gas attached to a function call (the unused part is refunded).
+    pub prepaid_gas: Gas,
+    pub deposit: near_token::NearToken,
+    /// The bytes of the receiver account storage that the action takes.
+    pub storage_usage: u64,
+}
+
+impl TransactionCost {
+    pub fn fees(&self) -> Gas {
+        self.actions
+            .iter()
+            .filter_map(|action| action.fees)
+            .fold(self.receipt_fees, Gas::saturating_add)
+    }
+
+    pub fn prepaid_gas(&self) -> Gas {
+        self.actions
+            .iter()
+            .map(|action| action.prepaid_gas)
+            .fold(Gas::ZERO, Gas::saturating_add)
+    }
+
+    /// The gas fees and the prepaid gas in NEAR at the gas price.
+    pub fn gas_cost(&self) -> near_token::NearToken {
+        self.gas_price.saturating_mul(u128::from(
+            self.fees().saturating_add(self.prepaid_gas()).as_gas(),
+        ))
+    }
+
+    pub fn deposit(&self) -> near_token::NearToken {
+        self.actions
+            .iter()
+            .fold(near_token::NearToken::ZERO, |deposit, action| {
+                deposit.saturating_add(action.deposit)
+            })
+    }
+
+    pub fn storage_usage(&self) -> u64 {
+        self.actions
+            .iter()
+            .map(|action| action.storage_usage)
+            .fold(0, u64::saturating_add)
+    }
+
+    /// The balance that gets locked on the receiver account to pay for the storage.
+    pub fn storage_stake(&self) -> near_token::NearToken {
+        self.storage_amount_per_byte
+            .saturating_mul(u128::from(self.storage_usage()))
+    }
+
+    /// The most that the transaction can take from the signer: the gas cost, the deposits and
+    /// the storage staking if the storage is staked on the signer account.
+    pub fn total_cost(&self) -> near_token::NearToken {
+        let total_cost = self.gas_cost().saturating_add(self.deposit());
+        if self.is_storage_staked_by_signer {
+            total_cost.saturating_add(self.storage_stake())
+        } else {
+            total_cost
+        }
+    }
+
+    /// The part of the cost that is taken from the signer. The relayer of a meta-transaction
+    /// pays for the gas and the deposits of the delegated actions, so the sender only stakes
+    /// the storage if it is staked on the sender account.
+    pub fn cost_to_signer(&self, is_delegate_action: bool) -> near_token::NearToken {
+        match (is_delegate_action, self.is_storage_staked_by_signer) {
+            (false, _) => self.total_cost(),
+            (true, true) => self.storage_stake(),
+            (true, false) => near_token::NearToken::ZERO,
+        }
+    }
+
+    /// Whether the fees of every action are estimated.
+    pub fn is_complete(&self) -> bool {
+        self.actions.iter().all(|action| action.fees.is_some())
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "gas_price": self.gas_price,
+            "actions": self.actions.iter().map(|action| serde_json::json!({
+                "action": action.summary,
+                "fees": action.fees,
+                "prepaid_gas": action.prepaid_gas,
+                "deposit": action.deposit,
+                "storage_usage": action.storage_usage,
+            })).collect::<Vec<_>>(),
+            "fees": self.fees(),
+            "prepaid_gas": self.prepaid_gas(),
+            "gas_cost": self.gas_cost(),
+            "deposit": self.deposit(),
+            "storage_usage": self.storage_usage(),
+            "storage_stake": self.storage_stake(),
+            "is_storage_staked_by_signer": self.is_storage_staked_by_signer,
+            "total_cost": self.total_cost(),
+        })
+    }
+}
+
+/// Estimates the cost of the actions sent from `signer_id` to `receiver_id`.
+pub fn estimate_transaction_cost(
+    fees_config: &RuntimeFeesConfig,
+    gas_price: near_token::NearToken,
+    signer_id: &near_primitives::types::AccountId,
+    receiver_id: &near_primitives::types::AccountId,
+    actions: &[near_primitives::transaction::Action],
+) -> color_eyre::eyre::Result<TransactionCost> {
+    let sir = signer_id == receiver_id;
+    Ok(TransactionCost {
+        gas_price,
+        storage_amount_per_byte: fees_config.storage_usage_config.storage_amount_per_byte,
+        is_storage_staked_by_signer: sir,
+        receipt_fees: action_fee(fees_config, ActionCosts::new_action_receipt, sir, 1),
+        actions: actions
+            .iter()
+            .map(|action| {
+                Ok(ActionCost {
+                    summary: crate::common::action_summary(action),
+                    fees: action_fees(fees_config, sir, receiver_id, action),
+                    prepaid_gas: match action {
+                        near_primitives::transaction::Action::FunctionCall(
+                            function_call_action,
+                        ) => function_call_action.gas,
+                        _ => Gas::ZERO,
+                    },
+                    deposit: action_deposit(action),
+                    storage_usage: action_storage_usage(fees_config, action)?,
+                })
+            })
+            .collect::<color_eyre::eyre::Result<_>>()?,
+    })
+}
+
+/// The send and execution fee of `count` units of the cost.
+fn action_fee(fees_config: &RuntimeFeesConfig, cost: ActionCosts, sir: bool, count: u64) -> Gas {
+    let fee = fees_config.fee(cost);
+    fee.send_fee(sir)
+        .gas
+        .saturating_add(fee.exec_fee().gas)
+        .saturating_mul(count)
+}
+
+fn action_fees(
+    fees_config: &RuntimeFeesConfig,
+    sir: bool,
+    receiver_id: &near_primitives::types::AccountId,
+    action: &near_primitives::transaction::Action,
+) -> Option<Gas> {
+    use near_primitives::transaction::Action;
+
+    let fee = |cost, count| action_fee(fees_config, cost, sir, count);
+    let fees = match action {
+        Action::CreateAccount(_) => fee(ActionCosts::create_account, 1),
+        Action::DeployContract(deploy_contract_action) => fee(ActionCosts::deploy_contract_base, 1)
+            .saturating_add(fee(
+                ActionCosts::deploy_contract_byte,
+                deploy_contract_action.code.len() as u64,
+            )),
+        Action::FunctionCall(function_call_action) => fee(ActionCosts::function_call_base, 1)
+            .saturating_add(fee(
+                ActionCosts::function_call_byte,
+                (function_call_action.method_name.len() + function_call_action.args.len()) as u64,
+            )),
+        Action::Transfer(_) => {
+            let receiver_account_type = receiver_id.get_account_type();
+            near_parameters::transfer_send_fee(fees_config, sir, true, receiver_account_type)
+                .gas
+                .saturating_add(
+                    near_parameters::transfer_exec_fee(fees_config, true, receiver_account_type)
+                        .gas,
+                )
+        }
+        Action::Stake(_) => fee(ActionCosts::stake, 1),
+        Action::AddKey(add_key_action) => {
+            let function_call_key_fee = |method_names: &[String]| {
+                fee(ActionCosts::add_function_call_key_base, 1).saturating_add(fee(
+                    ActionCosts::add_function_call_key_byte,
+                    method_names.iter().map(|name| name.len() as u64 + 1).sum(),
+                ))
+            };
+            let gas_key_fee = |num_nonces| {
+                near_parameters::gas_key_add_key_send_fee(fees_config, sir)
+                    .gas
+                    .saturating_add(
+                        near_parameters::gas_key_add_key_exec_fee(
+                            fees_config,
+                            receiver_id.len(),
+                            add_key_action.public_key.trie_id_len(),
+                            num_nonces,
+                        )
+                        .total()
+                        .gas,
+                    )
+            };
+            match &add_key_action.access_key.permission {
+                near_primitives::account::AccessKeyPermission::FullAccess => {
+                    fee(ActionCosts::add_full_access_key, 1)
+                }
+                near_primitives::account::AccessKeyPermission::FunctionCall(permission) => {
+                    function_call_key_fee(&permission.method_names)
+                }
+                near_primitives::account::AccessKeyPermission::GasKeyFullAccess(gas_key_info) => {
+                    fee(ActionCosts::add_full_access_key, 1)
+                        .saturating_add(gas_key_fee(gas_key_info.num_nonces))
+                }
+                near_primitives::account::AccessKeyPermission::GasKeyFunctionCall(
+                    gas_key_info,
+                    permission,
+                ) => function_call_key_fee(&permission.method_names)
+                    .saturating_add(gas_key_fee(gas_key_info.num_nonces)),
+            }
+        }
+        Action::DeleteKey(_) => fee(ActionCosts::delete_key, 1),
+        Action::DeleteAccount(_) => fee(ActionCosts::delete_account, 1),
+        Action::Delegate(signed_delegate_action) => {
+            // The inner actions are sent and executed as a separate receipt
+            let delegate_action = &signed_delegate_action.delegate_action;
+            let inner_sir = delegate_action.sender_id == delegate_action.receiver_id;
+            delegate_action.get_actions().iter().try_fold(
+                fee(ActionCosts::delegate, 1).saturating_add(action_fee(
+                    fees_config,
+                    ActionCosts::new_action_receipt,
+                    inner_sir,
+                    1,
+                )),
+                |fees, action| {
+                    Some(fees.saturating_add(action_fees(
+                        fees_config,
+                        inner_sir,
+                        &delegate_action.receiver_id,
+                        action,
+                    )?))
+                },
+            )?
+        }
+        Action::DeployGlobalContract(deploy_global_contract_action) => {
+            fee(ActionCosts::deploy_global_contract_base, 1).saturating_add(fee(
+                ActionCosts::deploy_global_contract_byte,
+                deploy_global_contract_action.code.len() as u64,
+            ))
+        }
+        Action::UseGlobalContract(use_global_contract_action) => {
+            let identifier_len = match &use_global_contract_action.contract_identifier {
+                near_primitives::action::GlobalContractIdentifier::CodeHash(code_hash) => {
+                    code_hash.as_bytes().len()
+                }
+                near_primitives::action::GlobalContractIdentifier::AccountId(account_id) => {
+                    account_id.len()
+                }
+            };
+            fee(ActionCosts::use_global_contract_base, 1).saturating_add(fee(
+                ActionCosts::use_global_contract_byte,
+                identifier_len as u64,
+            ))
+        }
+        Action::TransferToGasKey(transfer_to_gas_key_action) => gas_key_transfer_fee(
+            fees_config,
+            sir,
+            receiver_id,
+            &transfer_to_gas_key_action.public_key,
+        ),
+        Action::WithdrawFromGasKey(withdraw_from_gas_key_action) => gas_key_transfer_fee(
+            fees_config,
+            sir,
+            receiver_id,
+            &withdraw_from_gas_key_action.public_key,
+        ),
+        Action::DeterministicStateInit(_) | Action::DelegateV2(_) => return None,
+    };
+    Some(fees)
+}
+
+fn gas_key_transfer_fee(
+    fees_config: &RuntimeFeesConfig,
+    sir: bool,
+    receiver_id: &near_primitives::types::AccountId,
+    public_key: &near_crypto::PublicKey,
+) -> Gas {
+    near_parameters::gas_key_transfer_send_fee(fees_config, sir, public_key.len())
+        .total()
+        .gas
+        .saturating_add(
+            near_parameters::gas_key_transfer_exec_fee(
+                fees_config,
+                receiver_id.len(),
+                public_key.trie_id_len(),
+            )
+            .total()
+            .gas,
+        )
+}
+
+fn action_deposit(action: &near_primitives::transaction::Action) -> near_token::NearToken {
+    use near_primitives::transaction::Action;
+
+    match action {
+        Action::FunctionCall(function_call_action) => function_call_action.deposit,
+        Action::Transfer(transfer_action) => transfer_action.deposit,
+        Action::TransferToGasKey(transfer_to_gas_key_action) => transfer_to_gas_key_action.deposit,
+        Action::DeterministicStateInit(state_init) => state_init.deposit,
+        // The signer of the transaction that carries the delegate action pays for the deposits
+        Action::Delegate(signed_delegate_action) => signed_delegate_action
+            .delegate_action
+            .get_actions()
+            .iter()
+            .fold(near_token::NearToken::ZERO, |deposit, action| {
+                deposit.saturating_add(action_deposit(action))
+            }),
+        _ => near_token::NearToken::ZERO,
+    }
+}
+
+/// The storage that the action adds to the receiver account. A deployed contract replaces the
+/// current code, so the actual increase can be smaller.
+fn action_storage_usage(
+    fees_config: &RuntimeFeesConfig,
+    action: &near_primitives::transaction::Action,
+) -> color_eyre::eyre::Result<u64> {
+    use near_primitives::transaction::Action;
+
+    let storage_usage_config = &fees_config.storage_usage_config;
+    Ok(match action {
+        Action::CreateAccount(_) => storage_usage_config.num_bytes_account,
+        Action::DeployContract(deploy_contract_action) => deploy_contract_action.code.len() as u64,
+        Action::AddKey(add_key_action) => {
+            let access_key_len = borsh::object_length(&add_key_action.access_key)?;
+            storage_usage_config.num_extra_bytes_record
+                + add_key_action.public_key.len() as u64
+                + access_key_len as u64
+        }
+        _ => 0,
+    })
+}
+
+/// The runtime config of the protocol version that the network runs (testnet has its own
+/// genesis config, so the chain id of the network is taken into account).
+#[tracing::instrument(name = "Getting the runtime config ...", skip_all)]
+pub fn get_runtime_config(
+    network_config: &crate::config::NetworkConfig,
+) -> color_eyre::eyre::Result<std::sync::Arc<near_parameters::RuntimeConfig>> {
+    tracing::info!(target: "near_teach_me", "Getting the runtime config ...");
+    let protocol_config = tokio::runtime::Runtime::new()?.block_on(
+        super::partial_protocol_config::get_partial_protocol_config(
+            &network_config.json_rpc_client(),
+            &near_primitives::types::BlockReference::latest(),
+        ),
+    )?;
+    let chain_id = protocol_config
+        .chain_id
+        .as_deref()
+        .unwrap_or(&network_config.network_name);
+    Ok(near_parameters::RuntimeConfigStore::for_chain_id(chain_id)
+        .get_config(protocol_config.protocol_version)
+        .clone())
+}
+
+#[tracing::instrument(name = "Getting the gas price ...", skip_all)]
+pub fn get_gas_price(
+    network_config: &crate::config::NetworkConfig,
+) -> color_eyre::eyre::Result<near_token::NearToken> {
+    tracing::info!(target: "near_teach_me", "Getting the gas price ...");
+    Ok(network_config
+        .json_rpc_client()
+        .blocking_call(
+            near_jsonrpc_client::methods::gas_price::RpcGasPriceRequest { block_id: None },
+        )
+        .wrap_err_with(|| {
+            format!(
+                "Failed to get the gas price on network <{}>",
+                network_config.network_name
+            )
+        })?
+        .gas_price)
+}
+
+/// Estimates the cost of the prepared transaction at the current gas price and the transfer
+/// allowance of the signer that remains after it.
+pub fn get_transaction_cost_summary(
+    network_config: &crate::config::NetworkConfig,
+    prepopulated_transaction: &crate::commands::PrepopulatedTransaction,
+    is_delegate_action: bool,
+) -> color_eyre::eyre::Result<String> {
+    let runtime_config = get_runtime_config(network_config)?;
+    let gas_price = get_gas_price(network_config)?;
+    let transaction_cost = estimate_transaction_cost(
+        &runtime_config.fees,
+        gas_price,
+        &prepopulated_transaction.signer_id,
+        &prepopulated_transaction.receiver_id,
+        &prepopulated_transaction.actions,
+    )?;
+    let account_transfer_allowance =
+        tokio::runtime::Runtime::new()?.block_on(crate::common::get_account_transfer_allowance(
+            network_config,
+            prepopulated_transaction.signer_id.clone(),
+            near_primitives::types::BlockReference::latest(),
+        ))?;
+    Ok(transaction_cost_summary(
+        &transaction_cost,
+        &prepopulated_transaction.receiver_id,
+        is_delegate_action,
+        account_transfer_allowance.transfer_allowance(),
+    ))
+}
+
+fn transaction_cost_summary(
+    transaction_cost: &TransactionCost,
+    receiver_id: &near_primitives::types::AccountId,
+    is_delegate_action: bool,
+    transfer_allowance: near_token::NearToken,
+) -> String {
+    let mut info_str = String::new();
+    info_str.push_str(&format!(
+        "\n{:<17} {}{}",
+        "deposits:",
+        transaction_cost.deposit().exact_amount_display(),
+        if is_delegate_action {
+            " (paid by the relayer)"
+        } else {
+            ""
+        }
+    ));
+    info_str.push_str(&format!(
+        "\n{:<17} up to {} ({} of fees and {} of prepaid gas at {} per gas unit; the unused gas is refunded){}",
+        "gas:",
+        transaction_cost.gas_cost().exact_amount_display(),
+        transaction_cost.fees(),
+        transaction_cost.prepaid_gas(),
+        transaction_cost.gas_price.exact_amount_display(),
+        if is_delegate_action {
+            ", paid by the relayer"
+        } else {
+            ""
+        }
+    ));
+    if transaction_cost.storage_usage() > 0 {
+        info_str.push_str(&format!(
+            "\n{:<17} {} ({} bytes locked on <{receiver_id}>{})",
+            "storage staking:",
+            transaction_cost.storage_stake().exact_amount_display(),
+            transaction_cost.storage_usage(),
+            if transaction_cost.is_storage_staked_by_signer {
+                ""
+            } else {
+                ", covered by its balance"
+            }
+        ));
+    }
+    let cost_to_signer = transaction_cost.cost_to_signer(is_delegate_action);
+    info_str.push_str(&format!(
+        "\n{:<17} ~{}{}",
+        "total:",
+        cost_to_signer.exact_amount_display(),
+        if transaction_cost.is_complete() {
+            ""
+        } else {
+            " (the fees of some actions are not estimated, so the cost may be higher)"
+        }
+    ));
+    info_str.push_str(&format!(
+        "\n{:<17} {}",
+        "remaining:",
+        if cost_to_signer <= transfer_allowance {
+            format!(
+                "~{} of {} available for transfer",
+                transfer_allowance.saturating_sub(cost_to_signer),
+                transfer_allowance
+            )
+        } else {
+            format!("not enough balance, only {transfer_allowance} is available for transfer")
+        }
+    ));
+    info_str
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_transaction_cost() {
+        let fees_config = RuntimeFeesConfig::test();
+        let gas_price = near_token::NearToken::from_yoctonear(100_000_000);
+        let signer_id: near_primitives::types::AccountId = "alice.near".parse().unwrap();
+        let receiver_id: near_primitives::types::AccountId = "contract.near".parse().unwrap();
+        let transaction_cost = estimate_transaction_cost(
+            &fees_config,
+            gas_price,
+            &signer_id,
+            &receiver_id,
+            &[
+                near_primitives::transaction::Action::Transfer(
+                    near_primitives::transaction::TransferAction {
+                        deposit: near_token::NearToken::from_near(1),
+                    },
+                ),
+                near_primitives::transaction::Action::FunctionCall(Box::new(
+                    near_primitives::transaction::FunctionCallAction {
+                        method_name: "add".to_string(),
+                        args: b"{}".to_vec(),
+                        gas: Gas::from_teragas(30),
+                        deposit: near_token::NearToken::from_yoctonear(1),
+                    },
+                )),
+            ],
+        )
+        .unwrap();
+        let fee = |cost, count| action_fee(&fees_config, cost, false, count);
+        assert_eq!(
+            transaction_cost.fees(),
+            fee(ActionCosts::new_action_receipt, 1)
+                .saturating_add(fee(ActionCosts::transfer, 1))
+                .saturating_add(fee(ActionCosts::function_call_base, 1))
+                .saturating_add(fee(ActionCosts::function_call_byte, 5))
+        );
+        assert_eq!(transaction_cost.prepaid_gas(), Gas::from_teragas(30));
+        assert_eq!(
+            transaction_cost.deposit(),
+            near_token::NearToken::from_yoctonear(10u128.pow(24) + 1)
+        );
+        assert_eq!(
+            transaction_cost.total_cost(),
+            transaction_cost.deposit().saturating_add(
+                gas_price.saturating_mul(u128::from(
+                    transaction_cost
+                        .fees()
+                        .saturating_add(Gas::from_teragas(30))
+                        .as_gas()
+                ))
+            )
+        );
+        assert!(transaction_cost.is_complete());
+    }
+
+    #[test]
+    fn estimates_storage_staking() {
+        let fees_config = RuntimeFeesConfig::test();
+        let storage_usage_config = &fees_config.storage_usage_config;
+        let signer_id: near_primitives::types::AccountId = "alice.near".parse().unwrap();
+        let receiver_id: near_primitives::types::AccountId = "sub.alice.near".parse().unwrap();
+        let public_key = near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519);
+        let transaction_cost = estimate_transaction_cost(
+            &fees_config,
+            near_token::NearToken::from_yoctonear(100_000_000),
+            &signer_id,
+            &receiver_id,
+            &[
+                near_primitives::transaction::Action::CreateAccount(
+                    near_primitives::transaction::CreateAccountAction {},
+                ),
+                near_primitives::transaction::Action::Transfer(
+                    near_primitives::transaction::TransferAction {
+                        deposit: near_token::NearToken::from_near(1),
+                    },
+                ),
+                near_primitives::transaction::Action::AddKey(Box::new(
+                    near_primitives::transaction::AddKeyAction {
+                        public_key,
+                        access_key: near_primitives::account::AccessKey::full_access(),
+                    },
+                )),
+                near_primitives::transaction::Action::DeployContract(
+                    near_primitives::transaction::DeployContractAction { code: vec![0; 100] },
+                ),
+            ],
+        )
+        .unwrap();
+        // The full access key takes a nonce and a permission tag, the ED25519 key 33 bytes
+        let storage_usage = storage_usage_config.num_bytes_account
+            + storage_usage_config.num_extra_bytes_record
+            + 33
+            + 9
+            + 100;
+        assert_eq!(transaction_cost.storage_usage(), storage_usage);
+        assert_eq!(
+            transaction_cost.storage_stake(),
+            storage_usage_config
+                .storage_amount_per_byte
+                .saturating_mul(u128::from(storage_usage))
+        );
+        // The new account stakes the storage with the transferred deposit
+        assert!(!transaction_cost.is_storage_staked_by_signer);
+        assert_eq!(
+            transaction_cost.total_cost(),
+            transaction_cost
+                .gas_cost()
+                .saturating_add(near_token::NearToken::from_near(1))
+        );
+
+        let summary = transaction_cost_summary(
+            &transaction_cost,
+            &receiver_id,
+            false,
+            near_token::NearToken::from_near(10),
+        );
+        assert!(summary.contains(&format!(
+            "{:<17} ~{}",
+            "total:",
+            transaction_cost.total_cost().exact_amount_display()
+        )));
+        assert!(summary.contains(&format!("{storage_usage} bytes locked on <sub.alice.near>")));
+    }
+
+    #[test]
+    fn estimates_the_deposits_of_a_delegate_action() {
+        let fees_config = RuntimeFeesConfig::test();
+        let relayer_id: near_primitives::types::AccountId = "relayer.near".parse().unwrap();
+        let sender_id: near_primitives::types::AccountId = "alice.near".parse().unwrap();
+        let receiver_id: near_primitives::types::AccountId = "bob.near".parse().unwrap();
+        let transfer = near_primitives::transaction::Action::Transfer(
+            near_primitives::transaction::TransferAction {
+                deposit: near_token::NearToken::from_near(1),
+            },
+        );
+        let delegate = near_primitives::transaction::Action::Delegate(Box::new(
+            near_primitives::action::delegate::SignedDelegateAction {
+                delegate_action: near_primitives::action::delegate::DelegateAction {
+                    sender_id: sender_id.clone(),
+                    receiver_id: receiver_id.clone(),
+                    actions: vec![
+                        near_primitives::action::delegate::NonDelegateAction::try_from(
+                            transfer.clone(),
+                        )
+                        .unwrap(),
+                    ],
+                    nonce: 1,
+                    max_block_height: 1000,
+                    public_key: near_crypto::PublicKey::empty(near_crypto::KeyType::ED25519),
+                },
+                signature: near_crypto::Signature::empty(near_crypto::KeyType::ED25519),
+            },
+        ));
+        // The relayer sends the delegate action and pays for the inner deposits
+        let relayer_cost = estimate_transaction_cost(
+            &fees_config,
+            near_token::NearToken::from_yoctonear(100_000_000),
+            &relayer_id,
+            &sender_id,
+            &[delegate],
+        )
+        .unwrap();
+        assert_eq!(relayer_cost.deposit(), near_token::NearToken::from_near(1));
+        assert_eq!(
+            relayer_cost.cost_to_signer(false),
+            relayer_cost
+                .gas_cost()
+                .saturating_add(near_token::NearToken::from_near(1))
+        );
+
+        // The sender of the delegate action pays for neither the gas nor the deposits
+        let sender_cost = estimate_transaction_cost(
+            &fees_config,
+            near_token::NearToken::from_yoctonear(100_000_000),
+            &sender_id,
+            &receiver_id,
+            &[transfer],
+        )
+        .unwrap();
+        let summary = transaction_cost_summary(
+            &sender_cost,
+            &receiver_id,
+            true,
+            near_token::NearToken::from_near(10),
+        );
+        assert!(summary.contains("(paid by the relayer)"), "{summary}");
+        assert!(summary.contains(&format!(
+            "{:<17} ~{}",
+            "total:",
+            near_token::NearToken::ZERO.exact_amount_display()
+        )));
+    }
+}
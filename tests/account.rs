@@ -53,6 +53,37 @@ async fn test_view_account_summary_nonexistent() -> Result<(), Box<dyn std::erro
     Ok(())
 }
 
+#[tokio::test]
+async fn test_view_account_summary_as_json() -> Result<(), Box<dyn std::error::Error>> {
+    let ctx = common::prepare_tests().await?;
+
+    let output = Command::new("target/debug/near")
+        .env("XDG_CONFIG_HOME", &ctx.config_home)
+        .env("HOME", &ctx.config_home)
+        .env("APPDATA", &ctx.config_home)
+        .args([
+            "--output",
+            "json",
+            "account",
+            "view-account-summary",
+            "test.near",
+            "network-config",
+            "sandbox",
+            "now",
+        ])
+        .output()?;
+
+    let account_summary: serde_json::Value = serde_json::from_slice(&output.stdout)?;
+
+    assert_eq!(account_summary["account_id"], "test.near");
+    assert!(account_summary["balance"].is_string());
+    assert!(account_summary["block_height"].is_u64());
+    assert_eq!(account_summary["contract"]["type"], "none");
+    assert!(account_summary["access_keys"].is_array());
+
+    Ok(())
+}
+
 /// Normalize output by replacing dynamic content with placeholders
 fn normalize_output(output: &str) -> String {
     use regex::Regex;
@@ -1,4 +1,4 @@
-use near_cli_rs::common::find_network_where_account_exist;
+use near_cli_rs::common::{OutputFormat, find_network_where_account_exist};
 use near_cli_rs::config::{Config, NetworkConfig};
 use near_cli_rs::{GlobalContext, Verbosity};
 
@@ -8,6 +8,8 @@ fn create_test_context_with_default() -> GlobalContext {
         config,
         offline: false,
         verbosity: Verbosity::Interactive,
+        output_format: OutputFormat::Plaintext,
+        decode_with_abi: false,
     }
 }
 
@@ -15,11 +17,14 @@ fn create_test_context_with_empty_network_connection() -> GlobalContext {
     let config = Config {
         network_connection: linked_hash_map::LinkedHashMap::new(),
         credentials_home_dir: std::env::home_dir().expect("Impossible to get your home dir!"),
+        default_signer_account_id: None,
     };
     GlobalContext {
         config,
         offline: false,
         verbosity: Verbosity::Interactive,
+        output_format: OutputFormat::Plaintext,
+        decode_with_abi: false,
     }
 }
 
@@ -45,6 +50,7 @@ fn create_test_context_with_failed_rpc_on_testnet() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
     network_connection.insert(
@@ -65,6 +71,7 @@ fn create_test_context_with_failed_rpc_on_testnet() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
     network_connection.insert(
@@ -85,6 +92,7 @@ fn create_test_context_with_failed_rpc_on_testnet() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
     network_connection.insert(
@@ -109,17 +117,21 @@ fn create_test_context_with_failed_rpc_on_testnet() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer-prod.testnet".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
 
     let config = Config {
         network_connection,
         credentials_home_dir: std::env::home_dir().expect("Impossible to get your home dir!"),
+        default_signer_account_id: None,
     };
     GlobalContext {
         config,
         offline: false,
         verbosity: Verbosity::Interactive,
+        output_format: OutputFormat::Plaintext,
+        decode_with_abi: false,
     }
 }
 
@@ -145,6 +157,7 @@ fn create_test_context_with_failed_rpc_on_mainnet() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
     network_connection.insert(
@@ -169,6 +182,7 @@ fn create_test_context_with_failed_rpc_on_mainnet() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer-prod.testnet".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
     network_connection.insert(
@@ -191,6 +205,7 @@ fn create_test_context_with_failed_rpc_on_mainnet() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer-prod.testnet".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
     network_connection.insert(
@@ -213,17 +228,21 @@ fn create_test_context_with_failed_rpc_on_mainnet() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer-prod.testnet".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
 
     let config = Config {
         network_connection,
         credentials_home_dir: std::env::home_dir().expect("Impossible to get your home dir!"),
+        default_signer_account_id: None,
     };
     GlobalContext {
         config,
         offline: false,
         verbosity: Verbosity::Interactive,
+        output_format: OutputFormat::Plaintext,
+        decode_with_abi: false,
     }
 }
 
@@ -249,6 +268,7 @@ fn create_test_context_with_failed_rpc() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
     network_connection.insert(
@@ -273,17 +293,21 @@ fn create_test_context_with_failed_rpc() -> GlobalContext {
             mpc_contract_account_id: Some("v1.signer-prod.testnet".parse().unwrap()),
             tx_wait_until: None,
             nearblocks_url: Some("https://api-testnet.nearblocks.io/".parse().unwrap()),
+            rpc_fallback_endpoints: Vec::new(),
         },
     );
 
     let config = Config {
         network_connection,
         credentials_home_dir: std::env::home_dir().expect("Impossible to get your home dir!"),
+        default_signer_account_id: None,
     };
     GlobalContext {
         config,
         offline: false,
         verbosity: Verbosity::Interactive,
+        output_format: OutputFormat::Plaintext,
+        decode_with_abi: false,
     }
 }
 